@@ -49,4 +49,22 @@ try {
     console.log(e);
 }
 
+// 3. Invalid input must reject with a structured error, not a WASM trap
+console.log("\n[3] Testing Structured Errors:");
+try {
+    calculate_single_asset({
+        asset_type: "platinum",
+        config: { gold_price_per_gram: "85", silver_price_per_gram: "1" },
+        input: {}
+    });
+    console.error("FAILED: Unknown asset_type was accepted");
+    process.exit(1);
+} catch (e) {
+    if (e instanceof WebAssembly.RuntimeError || typeof e !== "object" || e.code !== "INVALID_INPUT" || e.field !== "asset_type") {
+        console.error("FAILED: Expected an INVALID_INPUT error object, got:", e);
+        process.exit(1);
+    }
+    console.log("SUCCESS: " + e.code + " - " + e.message);
+}
+
 console.log("\n=== specific verification complete ===");
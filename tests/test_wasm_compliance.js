@@ -105,6 +105,8 @@ async function runTests() {
                             gold_price_per_gram: testCase.config.gold_price_per_gram,
                             silver_price_per_gram: testCase.config.silver_price_per_gram,
                             madhab: testCase.config.madhab,
+                            nisab_gold_grams: testCase.config.nisab_gold_grams,
+                            nisab_silver_grams: testCase.config.nisab_silver_grams,
                         },
                         input: {
                             cash_on_hand: testCase.input.cash_on_hand || '0',
@@ -122,6 +124,8 @@ async function runTests() {
                             gold_price_per_gram: testCase.config.gold_price_per_gram,
                             silver_price_per_gram: testCase.config.silver_price_per_gram,
                             madhab: testCase.config.madhab,
+                            nisab_gold_grams: testCase.config.nisab_gold_grams,
+                            nisab_silver_grams: testCase.config.nisab_silver_grams,
                         },
                         input: {
                             weight_grams: testCase.input.weight_grams || '0',
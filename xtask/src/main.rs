@@ -563,7 +563,7 @@ fn build_wasm() -> Result<()> {
         "--scope", "islamic",
         "--out-dir", root.join("pkg").to_string_lossy().as_ref(),
         "--",
-        "--features", "wasm",
+        "--features", "wasm,panic-hook",
     ])?;
     
     println!("  📦 Restoring JSR configuration...");
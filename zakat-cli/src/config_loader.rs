@@ -1,13 +1,24 @@
 //! Persistent CLI Configuration Loader
 //!
 //! This module provides platform-aware configuration file loading for the Zakat CLI.
-//! Configuration is loaded from `~/.config/zakat/config.toml` on Linux/macOS
-//! or `%APPDATA%\zakat\config.toml` on Windows.
+//! The config file is the one given with `--config`, else `zakat.toml` in the
+//! working directory, else `~/.config/zakat/config.toml` on Linux/macOS or
+//! `%APPDATA%\zakat\config.toml` on Windows.
+//!
+//! Settings are layered with this precedence (highest first):
+//! 1. CLI flags (`--gold-price`, `--offline`, ...)
+//! 2. `ZAKAT_*` environment variables (`ZAKAT_GOLD_PRICE`, `ZAKAT_MADHAB`, ...)
+//! 3. The config file
+//! 4. Built-in defaults
 
 use serde::{Deserialize, Serialize};
 use rust_decimal::Decimal;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing::{debug, warn};
+use zakat_core::prelude::{Madhab, NisabStandard, ZakatConfig};
+
+/// Config file looked up in the working directory when `--config` is not given.
+pub const LOCAL_CONFIG_FILE: &str = "zakat.toml";
 
 /// CLI Configuration structure loaded from TOML file.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -60,26 +71,110 @@ impl CliConfig {
             return Self::default();
         }
 
-        match std::fs::read_to_string(&path) {
-            Ok(content) => {
-                match toml::from_str::<CliConfig>(&content) {
-                    Ok(config) => {
-                        debug!("Loaded configuration from {:?}", path);
-                        config
-                    }
-                    Err(e) => {
-                        warn!("Failed to parse config file {:?}: {}", path, e);
-                        Self::default()
-                    }
-                }
-            }
+        match Self::load_from(&path) {
+            Ok(config) => config,
             Err(e) => {
-                warn!("Failed to read config file {:?}: {}", path, e);
+                warn!("{}", e);
                 Self::default()
             }
         }
     }
 
+    /// Reads and parses the config file at `path`.
+    pub fn load_from(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read config file {:?}: {}", path, e))?;
+        let config = toml::from_str::<CliConfig>(&content)
+            .map_err(|e| format!("Failed to parse config file {:?}: {}", path, e))?;
+        debug!("Loaded configuration from {:?}", path);
+        Ok(config)
+    }
+
+    /// Loads the config file layer: `explicit` (from `--config`) if given, else
+    /// [`LOCAL_CONFIG_FILE`] in the working directory, else the platform file.
+    ///
+    /// A file that was asked for or found locally must parse; the platform file
+    /// falls back to defaults with a warning, as [`CliConfig::load`] does.
+    pub fn discover(explicit: Option<&Path>) -> Result<Self, String> {
+        if let Some(path) = explicit {
+            return Self::load_from(path);
+        }
+        let local = Path::new(LOCAL_CONFIG_FILE);
+        if local.exists() {
+            return Self::load_from(local);
+        }
+        Ok(Self::load())
+    }
+
+    /// Reads the environment layer through `var`, e.g. `|key| std::env::var(key).ok()`.
+    pub fn from_env_with(var: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        let decimal = |key: &str| {
+            var(key)
+                .map(|value| value.trim().parse::<Decimal>()
+                    .map_err(|_| format!("{} must be a number, got '{}'", key, value)))
+                .transpose()
+        };
+        let flag = |key: &str| {
+            var(key)
+                .map(|value| match value.trim().to_lowercase().as_str() {
+                    "1" | "true" | "yes" => Ok(true),
+                    "0" | "false" | "no" => Ok(false),
+                    _ => Err(format!("{} must be true or false, got '{}'", key, value)),
+                })
+                .transpose()
+        };
+
+        Ok(Self {
+            gold_price: decimal("ZAKAT_GOLD_PRICE")?,
+            silver_price: decimal("ZAKAT_SILVER_PRICE")?,
+            locale: var("ZAKAT_LOCALE"),
+            currency: var("ZAKAT_CURRENCY"),
+            madhab: var("ZAKAT_MADHAB"),
+            nisab_standard: var("ZAKAT_NISAB_STANDARD"),
+            enable_logging: flag("ZAKAT_LOG")?,
+            offline: flag("ZAKAT_OFFLINE")?,
+        })
+    }
+
+    /// Layers `top` over `self`: every setting `top` has wins.
+    pub fn overlay(self, top: CliConfig) -> Self {
+        Self {
+            gold_price: top.gold_price.or(self.gold_price),
+            silver_price: top.silver_price.or(self.silver_price),
+            locale: top.locale.or(self.locale),
+            currency: top.currency.or(self.currency),
+            madhab: top.madhab.or(self.madhab),
+            nisab_standard: top.nisab_standard.or(self.nisab_standard),
+            enable_logging: top.enable_logging.or(self.enable_logging),
+            offline: top.offline.or(self.offline),
+        }
+    }
+
+    /// Resolves the final settings: flags over environment over file.
+    pub fn layered(file: CliConfig, env: CliConfig, flags: CliConfig) -> Self {
+        file.overlay(env).overlay(flags)
+    }
+
+    /// Applies the Madhab, Nisab standard, locale and currency to `config`.
+    ///
+    /// Prices are not set here; they feed the live/fallback price pipeline.
+    pub fn apply_to(&self, mut config: ZakatConfig) -> Result<ZakatConfig, String> {
+        // The Madhab resets the Nisab standard, so apply it first.
+        if let Some(madhab) = &self.madhab {
+            config = config.with_madhab(madhab.parse::<Madhab>().map_err(|e| e.to_string())?);
+        }
+        if let Some(standard) = &self.nisab_standard {
+            config = config.with_nisab_standard(parse_nisab_standard(standard)?);
+        }
+        if let Some(locale) = &self.locale {
+            config = config.with_locale_code(locale);
+        }
+        if let Some(currency) = &self.currency {
+            config = config.with_currency_code(currency);
+        }
+        Ok(config)
+    }
+
     /// Saves the current configuration to the default config file location.
     pub fn save(&self) -> Result<(), std::io::Error> {
         let path = Self::config_path()
@@ -118,6 +213,15 @@ impl CliConfig {
     }
 }
 
+fn parse_nisab_standard(value: &str) -> Result<NisabStandard, String> {
+    match value.to_lowercase().as_str() {
+        "gold" => Ok(NisabStandard::Gold),
+        "silver" => Ok(NisabStandard::Silver),
+        "lower-of-two" | "lower" => Ok(NisabStandard::LowerOfTwo),
+        _ => Err(format!("Unknown nisab standard '{}': use gold, silver or lower-of-two", value)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,4 +244,35 @@ mod tests {
         assert!(toml_str.contains("gold-price"));
         assert!(toml_str.contains("USD"));
     }
+
+    #[test]
+    fn test_flags_override_env_override_file() {
+        let path = std::env::temp_dir().join(format!("zakat_config_{}.toml", std::process::id()));
+        std::fs::write(&path, "gold-price = 50\nsilver-price = 1\nmadhab = \"shafi\"\ncurrency = \"SAR\"\n").unwrap();
+        let file = CliConfig::discover(Some(&path)).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let env = CliConfig::from_env_with(|key| match key {
+            "ZAKAT_GOLD_PRICE" => Some("70".to_string()),
+            "ZAKAT_MADHAB" => Some("hanbali".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        let flags = CliConfig { gold_price: Some(Decimal::from(90)), ..Default::default() };
+
+        let settings = CliConfig::layered(file, env, flags);
+        assert_eq!(settings.gold_price, Some(Decimal::from(90)), "the flag beats env and file");
+        assert_eq!(settings.silver_price, Some(Decimal::from(1)), "file values survive when nothing overrides them");
+        assert_eq!(settings.madhab.as_deref(), Some("hanbali"), "env beats the file");
+
+        let config = settings.apply_to(ZakatConfig::new()).unwrap();
+        assert_eq!(config.madhab, Madhab::Hanbali);
+        assert_eq!(config.currency_code, "SAR");
+    }
+
+    #[test]
+    fn test_invalid_env_value_is_rejected() {
+        let err = CliConfig::from_env_with(|key| (key == "ZAKAT_GOLD_PRICE").then(|| "cheap".to_string())).unwrap_err();
+        assert!(err.contains("ZAKAT_GOLD_PRICE"));
+    }
 }
@@ -0,0 +1,71 @@
+//! Doctor Findings
+//!
+//! Pure checks used by `zakat-cli doctor`. They are kept separate from the
+//! printing code in `main.rs` so they can be tested without a terminal or network.
+
+use std::path::Path;
+use zakat_core::prelude::*;
+
+/// Loads a portfolio JSON file for inspection.
+pub fn load_portfolio(path: &Path) -> Result<ZakatPortfolio, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+    parse_portfolio(path, &content).map_err(|e| format!("Could not parse {}: {}", path.display(), e))
+}
+
+/// Parses a portfolio as CSV when the file has a `.csv` extension, JSON otherwise.
+pub fn parse_portfolio(path: &Path, content: &str) -> Result<ZakatPortfolio, String> {
+    let is_csv = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+    if is_csv {
+        ZakatPortfolio::from_csv(content.as_bytes()).map_err(|e| e.report())
+    } else {
+        serde_json::from_str(content).map_err(|e| e.to_string())
+    }
+}
+
+/// Collects actionable findings for the resolved config and an optional portfolio.
+///
+/// Returns an empty vector when nothing needs attention.
+pub fn collect_findings(config: &ZakatConfig, portfolio: Option<&ZakatPortfolio>) -> Vec<String> {
+    let mut findings = Vec::new();
+
+    if let Err(e) = config.validate() {
+        findings.push(format!("Config: {}", e.report()));
+    }
+    if let Some(warning) = config.silver_price_warning() {
+        findings.push(format!("Config: {}", warning));
+    }
+    for warning in config.sanity_check() {
+        findings.push(format!("Config: {}", warning));
+    }
+
+    if let Some(portfolio) = portfolio {
+        for (id, error) in portfolio.validate_all() {
+            let name = portfolio
+                .get(id)
+                .and_then(CalculateZakat::get_label)
+                .unwrap_or_else(|| id.to_string());
+            findings.push(format!("Asset '{}': {}", name, error.report()));
+        }
+    }
+
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_doctor_reports_invalid_fixture_asset() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/invalid_portfolio.json");
+        let portfolio = load_portfolio(&path).expect("fixture should parse");
+        let config = ZakatConfig::test_default();
+
+        let findings = collect_findings(&config, Some(&portfolio));
+
+        assert_eq!(findings.len(), 1, "Only the broken asset should be reported: {:?}", findings);
+        assert!(findings[0].contains("Shop Till"), "Finding should name the asset: {}", findings[0]);
+        assert!(findings[0].contains("cash_on_hand"), "Finding should name the field: {}", findings[0]);
+    }
+}
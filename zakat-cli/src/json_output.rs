@@ -0,0 +1,102 @@
+//! Localized JSON Output
+//!
+//! Builds the document printed by `zakat-cli --json --lang <code>`. The raw
+//! `PortfolioResult` is kept as-is; each successful item gains a `localized`
+//! object where every translated string sits next to its stable i18n key, so
+//! apps can match on the key and display the text.
+
+use serde_json::{json, Value};
+use zakat_core::portfolio::PortfolioResult;
+use zakat_core::prelude::*;
+use zakat_i18n::{Translator, ZakatLocale};
+
+/// Serializes `result` and adds the translated status, status reason,
+/// recommendation and warnings.
+pub fn localized_result(
+    result: &PortfolioResult,
+    translator: &Translator,
+    locale: ZakatLocale,
+) -> Result<Value, serde_json::Error> {
+    let mut value = serde_json::to_value(result)?;
+    value["locale"] = json!(locale.as_str());
+
+    if let Some(successes) = value["successes"].as_array_mut() {
+        for (entry, details) in successes.iter_mut().zip(&result.successes) {
+            entry["localized"] = localize_details(details, translator, locale);
+        }
+    }
+    Ok(value)
+}
+
+fn localize_details(details: &ZakatDetails, translator: &Translator, locale: ZakatLocale) -> Value {
+    let status_key = if details.is_payable { "status-payable" } else { "status-exempt" };
+    let recommendation_key = details.recommendation.translation_key();
+    // Reasons set outside the library have no key; keep their text as-is.
+    let status_reason = details.status_reason.as_ref().map_or(Value::Null, |reason| {
+        match details.status_reason_key() {
+            Some(key) => json!({ "key": key, "text": translator.translate(locale, key, None) }),
+            None => json!({ "key": Value::Null, "text": reason }),
+        }
+    });
+
+    let warnings: Vec<Value> = details
+        .structured_warnings
+        .iter()
+        .map(|warning| {
+            let key = warning.code.translation_key();
+            let mut text = translator.translate_map(locale, key, warning.details.as_ref());
+            // Custom codes have no catalog entry; keep the calculator's own message.
+            if text.starts_with("MISSING:") {
+                text = warning.message.clone();
+            }
+            json!({ "key": key, "text": text })
+        })
+        .collect();
+
+    json!({
+        "status": { "key": status_key, "text": translator.translate(locale, status_key, None) },
+        "statusReason": status_reason,
+        "recommendation": {
+            "key": recommendation_key,
+            "text": translator.translate(locale, recommendation_key, None),
+        },
+        "warnings": warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indonesian_strings_sit_next_to_keys() {
+        // Keep the small wallet exempt instead of joining the shop's monetary pool.
+        let config = ZakatConfig::test_default().with_monetary_aggregation(false);
+        let portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(10_000).hawl(true).label("Toko"))
+            .add(BusinessZakat::cash_only(100).hawl(true).label("Dompet"))
+            .add(BusinessZakat::cash_only(20_000).hawl(false).label("Tabungan baru"));
+        let result = portfolio.calculate_total(&config);
+
+        let value = localized_result(&result, &zakat_i18n::default_translator(), ZakatLocale::IdID).unwrap();
+
+        assert_eq!(value["locale"], "id-ID");
+        let payable = &value["successes"][0]["localized"];
+        assert_eq!(payable["status"]["key"], "status-payable");
+        assert_eq!(payable["status"]["text"], "WAJIB ZAKAT");
+        assert_eq!(payable["recommendation"]["key"], "recommendation-obligatory");
+        assert_eq!(payable["recommendation"]["text"], "Zakat wajib dibayarkan");
+
+        let exempt = &value["successes"][1]["localized"];
+        assert_eq!(exempt["status"]["text"], "TIDAK WAJIB");
+        assert_eq!(exempt["recommendation"]["text"], "Tidak ada zakat yang wajib");
+        assert!(exempt["statusReason"].is_null());
+
+        let too_new = &value["successes"][2]["localized"];
+        assert_eq!(too_new["statusReason"]["key"], "reason-hawl-not-met");
+        assert_eq!(too_new["statusReason"]["text"], "Haul (satu tahun hijriah) belum terpenuhi");
+
+        // The untranslated result is still there for existing consumers.
+        assert_eq!(value["successes"][0]["isPayable"], true);
+    }
+}
@@ -16,18 +16,44 @@
 //! # With offline mode (skip price fetching)
 //! zakat-cli --offline
 //!
+//! # Pick a color theme (dark, light, high-contrast)
+//! zakat-cli --theme light
+//!
 //! # Load existing portfolio
 //! zakat-cli --load portfolio.json
 //!
-//! # Run system diagnostics
+//! # Debug logging for a non-interactive run (-vv for trace)
+//! zakat-cli -v doctor
+//!
+//! # Calculate a saved portfolio as JSON, with Indonesian strings next to the keys
+//! zakat-cli --load portfolio.json --json --lang id
+//!
+//! # Convert a saved portfolio to CSV (and load .csv files with --load)
+//! zakat-cli --load portfolio.json --csv > portfolio.csv
+//!
+//! # Run system diagnostics (offline; add --check-network to contact the price provider)
 //! zakat-cli doctor
+//! zakat-cli doctor --check-network
+//!
+//! # Diagnose a saved portfolio
+//! zakat-cli --load portfolio.json doctor
+//!
+//! # Check an exported snapshot for tampering
+//! zakat-cli verify zakat_report.json
+//!
+//! # Compare two exported snapshots
+//! zakat-cli diff last_year.json this_year.json
+//!
+//! # Export ledger events for a spreadsheet
+//! zakat-cli ledger export savings_ledger.json --csv > events.csv
 //! ```
 
 use clap::{Parser, Subcommand};
 use rust_decimal::Decimal;
-use rust_decimal_macros::dec;
 use std::env;
 use std::io;
+use std::sync::mpsc;
+use std::time::Duration;
 use tracing::warn;
 
 use zakat_providers::{BestEffortPriceProvider, FileSystemPriceCache, PriceProvider, Prices};
@@ -36,10 +62,22 @@ use zakat_providers::{BestEffortPriceProvider, FileSystemPriceCache, PriceProvid
 use zakat_providers::BinancePriceProvider;
 
 mod config_loader;
+
+use config_loader::CliConfig;
+mod doctor;
+mod json_output;
+mod snapshot_diff;
 mod tui;
 
+use tui::theme::ThemeKind;
 use tui::{handle_events, ui, App};
 
+/// How often the TUI re-fetches live prices after the initial load.
+const PRICE_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// How long fetched prices are served from the file cache.
+const PRICE_CACHE_TTL: Duration = Duration::from_secs(3600);
+
 /// Interactive Zakat Calculator CLI
 #[derive(Parser, Debug)]
 #[command(name = "zakat-cli")]
@@ -51,6 +89,10 @@ struct Args {
     #[arg(long, default_value = "false")]
     log: bool,
 
+    /// Increase log detail: -v for debug, -vv for trace (TUI mode logs only with --log)
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+
     /// Use static prices instead of fetching live
     #[arg(long, default_value = "false")]
     offline: bool,
@@ -67,23 +109,85 @@ struct Args {
     #[arg(long)]
     load: Option<std::path::PathBuf>,
 
-    /// Output results as JSON (non-interactive mode)
+    /// Output results as JSON (non-interactive mode, requires --load)
     #[arg(long, default_value = "false")]
     json: bool,
 
+    /// Print the --load'ed portfolio as CSV, one row per asset (non-interactive, requires --load)
+    #[arg(long, default_value = "false", conflicts_with = "json")]
+    csv: bool,
+
+    /// Locale (en, id, ar) for translated strings added to --json output
+    #[arg(long)]
+    lang: Option<String>,
+
+    /// Seconds the TUI waits for live prices before continuing with fallback prices
+    #[arg(long, default_value_t = 10)]
+    price_timeout: u64,
+
+    /// TUI color theme
+    #[arg(long, value_enum, default_value_t = ThemeKind::Dark)]
+    theme: ThemeKind,
+
+    /// Decimal separator for amounts typed in the TUI ('.' or ','); the other is read as thousands grouping
+    #[arg(long, default_value_t = '.', value_parser = parse_decimal_separator)]
+    decimal_separator: char,
+
+    /// Config file to use instead of ./zakat.toml (flags override ZAKAT_* env vars, which override the file)
+    #[arg(long)]
+    config: Option<std::path::PathBuf>,
+
+    /// Settings resolved from flags, environment and config file
+    #[arg(skip)]
+    settings: CliConfig,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
 
 #[derive(Subcommand, Debug)]
 enum Commands {
-    /// Run diagnostics to check system health and connectivity
-    Doctor,
+    /// Run diagnostics: connectivity, resolved config, and `--load`ed portfolio validation
+    Doctor {
+        /// Contact the live price provider; without it doctor makes no network calls
+        #[arg(long, default_value = "false")]
+        check_network: bool,
+    },
+    /// Check a saved snapshot's content hash for tampering or corruption
+    Verify {
+        /// Snapshot JSON file to check
+        snapshot: std::path::PathBuf,
+    },
+    /// Compare two saved snapshots: totals and assets added, removed or changed
+    Diff {
+        /// Earlier snapshot JSON file
+        old: std::path::PathBuf,
+        /// Later snapshot JSON file
+        new: std::path::PathBuf,
+    },
+    /// Work with ledger (event-sourced) assets
+    Ledger {
+        #[command(subcommand)]
+        command: LedgerCommands,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum LedgerCommands {
+    /// Print the event timeline of ledger assets (JSON by default)
+    Export {
+        /// JSON file with one ledger asset or an array of them
+        file: std::path::PathBuf,
+        /// Output CSV (date, asset_id, event_type, amount, description) for spreadsheets
+        #[arg(long, default_value = "false")]
+        csv: bool,
+    },
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args = Args::parse();
+    let mut args = Args::parse();
+    args.resolve_settings()?;
 
     // Initialize tracing with optional file logging
     // NOTE: In TUI mode, we only log to file (no console) to avoid corrupting the UI
@@ -101,7 +205,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         _file_guard = Some(guard);
 
         let env_filter = tracing_subscriber::EnvFilter::from_default_env()
-            .add_directive("zakat=debug".parse().unwrap());
+            .add_directive(log_directive(args.verbose, true).parse().unwrap());
 
         // Only add console layer if NOT in TUI mode
         if is_tui_mode {
@@ -136,7 +240,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             tracing_subscriber::fmt()
                 .with_env_filter(
                     tracing_subscriber::EnvFilter::from_default_env()
-                        .add_directive("zakat=info".parse().unwrap()),
+                        .add_directive(log_directive(args.verbose, false).parse().unwrap()),
                 )
                 .init();
         }
@@ -144,18 +248,89 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Handle Subcommands (run outside TUI)
-    if let Some(Commands::Doctor) = args.command {
-        return run_doctor().await;
+    match &args.command {
+        Some(Commands::Doctor { check_network }) => return run_doctor(&args, *check_network).await,
+        Some(Commands::Verify { snapshot }) => return run_verify(snapshot),
+        Some(Commands::Diff { old, new }) => return run_diff(old, new),
+        Some(Commands::Ledger { command: LedgerCommands::Export { file, csv } }) => {
+            return run_ledger_export(file, *csv);
+        }
+        None => {}
+    }
+
+    if args.json {
+        return run_json(&args).await;
+    }
+    if args.csv {
+        return run_csv(&args);
     }
 
     // Run TUI
     run_tui(args).await
 }
 
+impl Args {
+    /// Settings given as flags, the top layer of the config precedence.
+    fn flag_settings(&self) -> CliConfig {
+        CliConfig {
+            gold_price: self.gold_price,
+            silver_price: self.silver_price,
+            enable_logging: self.log.then_some(true),
+            offline: self.offline.then_some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Layers flags over `ZAKAT_*` env vars over the config file into `settings`.
+    fn resolve_settings(&mut self) -> Result<(), String> {
+        let file = CliConfig::discover(self.config.as_deref())?;
+        let env = CliConfig::from_env_with(|key| std::env::var(key).ok())?;
+        self.settings = CliConfig::layered(file, env, self.flag_settings());
+        self.log = self.settings.enable_logging.unwrap_or(false);
+        self.offline = self.settings.offline.unwrap_or(false);
+        Ok(())
+    }
+
+    /// Currency the calculation runs in, and the one fallback prices are quoted in.
+    fn currency(&self) -> &str {
+        self.settings.currency.as_deref().unwrap_or("USD")
+    }
+
+    /// Creates the app with the resolved Madhab, Nisab standard, locale and currency.
+    fn new_app(&self) -> Result<App, String> {
+        let mut app = App::new(self.offline);
+        app.config = self.settings.apply_to(app.config)?;
+        Ok(app)
+    }
+}
+
+/// Maps the `-v` count to the tracing directive for the zakat crates.
+///
+/// File logging already defaults to debug, so a single `-v` only changes console runs.
+fn log_directive(verbose: u8, log_to_file: bool) -> &'static str {
+    match (verbose, log_to_file) {
+        (0, false) => "zakat=info",
+        (0, true) | (1, _) => "zakat=debug",
+        _ => "zakat=trace",
+    }
+}
+
+fn parse_decimal_separator(value: &str) -> Result<char, String> {
+    match value {
+        "." => Ok('.'),
+        "," => Ok(','),
+        _ => Err(format!("expected '.' or ',', got '{}'", value)),
+    }
+}
+
 /// Run the TUI application
 async fn run_tui(args: Args) -> Result<(), Box<dyn std::error::Error>> {
     // Create app state
-    let mut app = App::new(args.offline);
+    let mut app = args.new_app()?;
+    app.set_theme(args.theme);
+    app.set_decimal_separator(args.decimal_separator);
+    app.fallback_prices = fallback_prices(&args);
+    app.price_timeout = Duration::from_secs(args.price_timeout);
 
     // Load portfolio if specified
     if let Some(path) = &args.load
@@ -163,15 +338,19 @@ async fn run_tui(args: Args) -> Result<(), Box<dyn std::error::Error>> {
             eprintln!("Warning: Could not load portfolio: {}", e);
         }
 
-    // Fetch prices before starting TUI (async operation)
-    let prices = get_prices(&args).await;
-    app.set_prices(prices);
+    // The last cached prices are the baseline for the header's "since last fetch" delta
+    if !args.offline {
+        app.previous_prices = zakat_providers::last_cached_prices();
+    }
+
+    // Fetch prices in the background; the loading screen shows until the first batch arrives
+    let price_updates = spawn_price_updates(args);
 
     // Initialize terminal
     let mut terminal = ratatui::init();
 
     // Main loop
-    let result = run_app(&mut terminal, &mut app);
+    let result = run_app(&mut terminal, &mut app, &price_updates);
 
     // Restore terminal
     ratatui::restore();
@@ -183,8 +362,15 @@ async fn run_tui(args: Args) -> Result<(), Box<dyn std::error::Error>> {
 fn run_app(
     terminal: &mut ratatui::DefaultTerminal,
     app: &mut App,
+    price_updates: &mpsc::Receiver<Prices>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     loop {
+        // Apply any prices that arrived since the last frame
+        while let Ok(prices) = price_updates.try_recv() {
+            app.set_prices(prices);
+        }
+        app.tick();
+
         // Draw the UI
         terminal.draw(|frame| ui(frame, app))?;
 
@@ -197,14 +383,39 @@ fn run_app(
     Ok(())
 }
 
+/// Spawns a task that sends fresh prices to the TUI, then keeps refreshing them
+/// every [`PRICE_REFRESH_INTERVAL`] unless running offline.
+fn spawn_price_updates(args: Args) -> mpsc::Receiver<Prices> {
+    let (tx, rx) = mpsc::channel();
+    tokio::spawn(async move {
+        loop {
+            let prices = get_prices(&args).await;
+            if tx.send(prices).is_err() || args.offline {
+                break;
+            }
+            tokio::time::sleep(PRICE_REFRESH_INTERVAL).await;
+        }
+    });
+    rx
+}
+
+/// Demo prices, overridable with `--gold-price` / `--silver-price`, env vars or the config file
+///
+/// They are taken to be in the configured currency, so a `--currency SAR` user
+/// enters SAR prices.
+fn fallback_prices(args: &Args) -> Prices {
+    let demo = Prices::demo();
+    Prices::new(
+        args.settings.gold_price.unwrap_or(demo.gold_per_gram),
+        args.settings.silver_price.unwrap_or(demo.silver_per_gram),
+    )
+    .unwrap()
+    .with_currency(args.currency())
+}
+
 /// Fetches prices using BestEffortPriceProvider
 async fn get_prices(args: &Args) -> Prices {
-    // Default fallback prices
-    let fallback = Prices::new(
-        args.gold_price.unwrap_or(dec!(85)),
-        args.silver_price.unwrap_or(dec!(1)),
-    )
-    .unwrap();
+    let fallback = fallback_prices(args);
 
     if args.offline {
         return fallback;
@@ -216,7 +427,7 @@ async fn get_prices(args: &Args) -> Prices {
 
         #[cfg(not(target_arch = "wasm32"))]
         let primary_provider =
-            FileSystemPriceCache::new(binance, std::time::Duration::from_secs(3600));
+            FileSystemPriceCache::new(binance, PRICE_CACHE_TTL);
 
         #[cfg(target_arch = "wasm32")]
         let primary_provider = binance;
@@ -224,6 +435,12 @@ async fn get_prices(args: &Args) -> Prices {
         let provider = BestEffortPriceProvider::new(primary_provider, fallback.clone());
 
         match provider.get_prices().await {
+            Ok(prices) if prices.currency != fallback.currency => {
+                warn!(
+                    "Live prices are quoted in {} but the configured currency is {}, using fallback",
+                    prices.currency, fallback.currency
+                );
+            }
             Ok(prices) => {
                 if prices.gold_per_gram > Decimal::ZERO {
                     // If silver price is zero, use fallback
@@ -233,7 +450,9 @@ async fn get_prices(args: &Args) -> Prices {
                     } else {
                         prices.silver_per_gram
                     };
-                    return Prices::new(prices.gold_per_gram, final_silver).unwrap();
+                    return Prices::new(prices.gold_per_gram, final_silver)
+                        .unwrap()
+                        .with_currency(prices.currency);
                 }
             }
             Err(e) => {
@@ -245,8 +464,100 @@ async fn get_prices(args: &Args) -> Prices {
     fallback
 }
 
+/// Verify a snapshot file's integrity hash (outside TUI)
+fn run_verify(path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+    let content = std::fs::read_to_string(path)?;
+    let snapshot = zakat_core::portfolio::PortfolioSnapshot::from_json(&content)?;
+
+    if snapshot.content_hash.is_none() {
+        println!("⚠ {} has no content hash (written by an older version); cannot verify.", path.display());
+    } else if snapshot.verify_integrity() {
+        println!("✓ {} is intact.", path.display());
+    } else {
+        println!("✗ WARNING: {} does not match its content hash. It was edited or corrupted.", path.display());
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Export ledger asset events as a timeline (outside TUI)
+fn run_ledger_export(path: &std::path::Path, csv: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let content = std::fs::read_to_string(path)?;
+    let value: serde_json::Value = serde_json::from_str(&content)?;
+    let assets: Vec<zakat_ledger::LedgerAsset> = if value.is_array() {
+        serde_json::from_value(value)?
+    } else {
+        vec![serde_json::from_value(value)?]
+    };
+
+    let timeline = assets
+        .iter()
+        .fold(zakat_ledger::Timeline::new(), |timeline, asset| timeline.with_asset(asset));
+
+    if csv {
+        print!("{}", timeline.to_csv());
+    } else {
+        println!("{}", serde_json::to_string_pretty(&timeline)?);
+    }
+    Ok(())
+}
+
+/// Calculate the `--load`ed portfolio and print the result as JSON (outside TUI)
+async fn run_json(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let path = args.load.as_ref().ok_or("--json needs a portfolio file: pass --load <file>")?;
+    let locale = args
+        .lang
+        .as_deref()
+        .map(str::parse::<zakat_i18n::ZakatLocale>)
+        .transpose()?;
+    let portfolio = doctor::load_portfolio(path)?;
+
+    let mut app = args.new_app()?;
+    app.set_prices(get_prices(args).await);
+    let result = portfolio.calculate_total(&app.config);
+
+    let output = match locale {
+        Some(locale) => serde_json::to_string_pretty(&json_output::localized_result(&result, &app.translator, locale)?)?,
+        None => result.to_json()?,
+    };
+    println!("{}", output);
+    Ok(())
+}
+
+/// Print the `--load`ed portfolio as CSV for spreadsheets (outside TUI)
+fn run_csv(args: &Args) -> Result<(), Box<dyn std::error::Error>> {
+    let path = args.load.as_ref().ok_or("--csv needs a portfolio file: pass --load <file>")?;
+    let portfolio = doctor::load_portfolio(path)?;
+    print!("{}", portfolio.to_csv()?);
+    Ok(())
+}
+
+/// Prices doctor resolves the config with: live only when `check_network` is set.
+async fn doctor_prices(args: &Args, check_network: bool) -> Prices {
+    if check_network {
+        get_prices(args).await
+    } else {
+        fallback_prices(args)
+    }
+}
+
+/// Compare two snapshot files (outside TUI)
+fn run_diff(old_path: &std::path::Path, new_path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+    let old = zakat_core::portfolio::PortfolioSnapshot::from_json(&std::fs::read_to_string(old_path)?)?;
+    let new = zakat_core::portfolio::PortfolioSnapshot::from_json(&std::fs::read_to_string(new_path)?)?;
+
+    let diff = snapshot_diff::diff_snapshots(&old, &new);
+    for warning in &diff.warnings {
+        println!("⚠ {}", warning);
+    }
+    for change in &diff.changes {
+        println!("• {}", change);
+    }
+    Ok(())
+}
+
 /// Run doctor diagnostics (outside TUI)
-async fn run_doctor() -> Result<(), Box<dyn std::error::Error>> {
+async fn run_doctor(args: &Args, check_network: bool) -> Result<(), Box<dyn std::error::Error>> {
     println!("\n🚑 Zakat CLI Doctor - Diagnostics Tool");
     println!("═══════════════════════════════════════════════\n");
 
@@ -268,7 +579,13 @@ async fn run_doctor() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n2. Network & Pricing:");
 
     #[cfg(feature = "live-pricing")]
-    {
+    if args.offline {
+        println!("   Live Pricing Feature: Enabled");
+        println!("   Connectivity check skipped (--offline)");
+    } else if !check_network {
+        println!("   Live Pricing Feature: Enabled");
+        println!("   Connectivity check skipped (pass --check-network to contact the price provider)");
+    } else {
         println!("   Live Pricing Feature: Enabled");
         print!("   Connecting to Binance API... ");
         use std::io::Write;
@@ -309,7 +626,106 @@ async fn run_doctor() -> Result<(), Box<dyn std::error::Error>> {
             "No"
         }
     );
+    let price_cache = FileSystemPriceCache::new((), PRICE_CACHE_TTL);
+    match price_cache.cached_at() {
+        Some(fetched) => println!(
+            "   Price Cache: prices as of {} ({})",
+            chrono::DateTime::<chrono::Local>::from(fetched).format("%Y-%m-%d %H:%M"),
+            if price_cache.is_stale() { "stale" } else { "fresh" }
+        ),
+        None => println!("   Price Cache: empty"),
+    }
+
+    // 4. Resolved Configuration
+    println!("\n4. Resolved Configuration:");
+    let mut app = args.new_app()?;
+    app.set_prices(doctor_prices(args, check_network).await);
+    for line in app.config.describe().lines() {
+        println!("   {}", line);
+    }
+
+    // 5. Portfolio
+    println!("\n5. Portfolio:");
+    let portfolio = match &args.load {
+        Some(path) => match doctor::load_portfolio(path) {
+            Ok(portfolio) => {
+                println!("   Loaded {} asset(s) from {:?}", portfolio.get_items().len(), path);
+                Some(portfolio)
+            }
+            Err(e) => {
+                println!("   ✗ {}", e);
+                None
+            }
+        },
+        None => {
+            println!("   No portfolio given (use --load <file> to validate one)");
+            None
+        }
+    };
+
+    // 6. Findings
+    println!("\n6. Findings:");
+    let findings = doctor::collect_findings(&app.config, portfolio.as_ref());
+    if findings.is_empty() {
+        println!("   ✓ No problems found");
+    } else {
+        for finding in &findings {
+            println!("   ✗ {}", finding);
+        }
+    }
 
     println!("\nDiagnostics Complete.\n");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity_maps_to_filter_directive() {
+        assert_eq!(log_directive(0, false), "zakat=info");
+        assert_eq!(log_directive(1, false), "zakat=debug");
+        assert_eq!(log_directive(2, false), "zakat=trace");
+        assert_eq!(log_directive(5, false), "zakat=trace");
+        assert_eq!(log_directive(0, true), "zakat=debug");
+        assert_eq!(log_directive(2, true), "zakat=trace");
+
+        let args = Args::parse_from(["zakat-cli", "-vv", "doctor"]);
+        assert_eq!(args.verbose, 2);
+    }
+
+    #[tokio::test]
+    async fn test_doctor_uses_fallback_prices_without_check_network() {
+        let args = Args::parse_from(["zakat-cli", "doctor"]);
+        assert!(matches!(args.command, Some(Commands::Doctor { check_network: false })));
+        assert!(!args.offline);
+
+        // Not offline, yet no provider is contacted: the prices are the fallback ones.
+        assert_eq!(doctor_prices(&args, false).await, fallback_prices(&args));
+
+        let args = Args::parse_from(["zakat-cli", "doctor", "--check-network"]);
+        assert!(matches!(args.command, Some(Commands::Doctor { check_network: true })));
+    }
+
+    #[tokio::test]
+    async fn test_offline_prices_use_the_configured_currency() {
+        let mut args = Args::parse_from(["zakat-cli", "--offline", "--gold-price", "300", "--silver-price", "3"]);
+        args.settings = CliConfig::layered(
+            CliConfig { currency: Some("SAR".to_string()), ..Default::default() },
+            CliConfig::default(),
+            args.flag_settings(),
+        );
+
+        let mut app = args.new_app().unwrap();
+        app.set_prices(get_prices(&args).await);
+        assert_eq!(app.config.currency_code, "SAR");
+        assert_eq!(app.config.price_currency.as_deref(), Some("SAR"));
+
+        let mut portfolio = zakat_core::portfolio::ZakatPortfolio::new();
+        portfolio = portfolio.add(zakat_core::maal::business::BusinessZakat::new().cash(100_000));
+        let result = portfolio.calculate_total(&app.config);
+        assert!(result.failures().is_empty(), "{:?}", result.failures());
+        assert!(result.total_zakat_due > Decimal::ZERO);
+    }
+}
@@ -0,0 +1,156 @@
+//! Snapshot Diff
+//!
+//! Pure comparison used by `zakat-cli diff`. It is kept separate from the
+//! printing code in `main.rs` so it can be tested without files.
+
+use std::collections::BTreeMap;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use zakat_core::portfolio::PortfolioSnapshot;
+use zakat_core::prelude::*;
+
+/// Changes between two snapshots, and warnings about comparing them at all.
+#[derive(Debug, Default)]
+pub struct SnapshotDiff {
+    /// Reasons the comparison is unreliable, or that there is nothing to compare.
+    pub warnings: Vec<String>,
+    /// One line per change from the old snapshot to the new one.
+    pub changes: Vec<String>,
+}
+
+/// Compares two snapshots: totals, and assets added, removed or owing a different amount.
+///
+/// Warns when either snapshot fails its content hash, when the snapshots are in
+/// different currencies or formats, and when nothing changed.
+pub fn diff_snapshots(old: &PortfolioSnapshot, new: &PortfolioSnapshot) -> SnapshotDiff {
+    let mut diff = SnapshotDiff::default();
+
+    for (name, snapshot) in [("Old", old), ("New", new)] {
+        if snapshot.content_hash.is_some() && !snapshot.verify_integrity() {
+            diff.warnings.push(format!(
+                "{} snapshot does not match its content hash. It was edited or corrupted.",
+                name
+            ));
+        }
+    }
+
+    let old_currency = &old.config_snapshot.currency_code;
+    let new_currency = &new.config_snapshot.currency_code;
+    if old_currency != new_currency {
+        diff.warnings.push(format!(
+            "Snapshots are in different currencies ({} and {}); amounts are not comparable.",
+            old_currency, new_currency
+        ));
+    }
+    if old.version != new.version {
+        diff.warnings.push(format!(
+            "Snapshots use different formats (version {} and {}).",
+            old.version, new.version
+        ));
+    }
+
+    if old.total_assets() != new.total_assets() {
+        diff.changes.push(format!("Total assets: {} -> {}", old.total_assets(), new.total_assets()));
+    }
+    if old.total_zakat_due() != new.total_zakat_due() {
+        diff.changes.push(format!("Total Zakat due: {} -> {}", old.total_zakat_due(), new.total_zakat_due()));
+    }
+
+    let old_assets = assets_by_id(old);
+    let new_assets = assets_by_id(new);
+    for (id, (label, due)) in &new_assets {
+        match old_assets.get(id) {
+            None => diff.changes.push(format!("Added '{}'", label)),
+            Some((_, old_due)) if old_due != due => {
+                diff.changes.push(format!("'{}' Zakat due: {} -> {}", label, old_due, due));
+            }
+            Some(_) => {}
+        }
+    }
+    for (id, (label, _)) in &old_assets {
+        if !new_assets.contains_key(id) {
+            diff.changes.push(format!("Removed '{}'", label));
+        }
+    }
+
+    if diff.changes.is_empty() {
+        diff.warnings.push("No differences: both snapshots have the same assets and totals.".to_string());
+    }
+
+    diff
+}
+
+/// Label and Zakat due of each input asset, keyed by asset id.
+fn assets_by_id(snapshot: &PortfolioSnapshot) -> BTreeMap<Uuid, (String, Decimal)> {
+    snapshot
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let id = CalculateZakat::get_id(item);
+            let due = snapshot
+                .result
+                .successes()
+                .iter()
+                .find(|details| details.asset_id == Some(id))
+                .map_or(Decimal::ZERO, |details| details.zakat_due);
+            (id, (CalculateZakat::label_or_indexed(item, i), due))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot_of(portfolio: &ZakatPortfolio, config: &ZakatConfig) -> PortfolioSnapshot {
+        let result = portfolio.calculate_total(config);
+        portfolio.snapshot(config, &result)
+    }
+
+    #[test]
+    fn test_identical_snapshots_warn_that_nothing_changed() {
+        let config = ZakatConfig::test_default();
+        let portfolio = ZakatPortfolio::new().add(BusinessZakat::cash_only(100_000).label("Shop"));
+        let snapshot = snapshot_of(&portfolio, &config);
+
+        let diff = diff_snapshots(&snapshot, &snapshot);
+
+        assert!(diff.changes.is_empty(), "{:?}", diff.changes);
+        assert_eq!(diff.warnings.len(), 1, "{:?}", diff.warnings);
+        assert!(diff.warnings[0].starts_with("No differences"), "{}", diff.warnings[0]);
+    }
+
+    #[test]
+    fn test_changes_and_incompatible_inputs_are_reported() {
+        let config = ZakatConfig::test_default();
+        let shop = BusinessZakat::cash_only(100_000).label("Shop");
+        let old = snapshot_of(&ZakatPortfolio::new().add(shop.clone()), &config);
+        let new = snapshot_of(
+            &ZakatPortfolio::new().add(shop).add(BusinessZakat::cash_only(50_000).label("Kiosk")),
+            &config.clone().with_currency_code("SAR"),
+        );
+
+        let diff = diff_snapshots(&old, &new);
+
+        assert!(diff.changes.iter().any(|c| c == "Added 'Kiosk'"), "{:?}", diff.changes);
+        assert!(diff.changes.iter().any(|c| c.starts_with("Total Zakat due:")), "{:?}", diff.changes);
+        assert_eq!(diff.warnings.len(), 1, "{:?}", diff.warnings);
+        assert!(diff.warnings[0].contains("different currencies"), "{}", diff.warnings[0]);
+    }
+
+    #[test]
+    fn test_tampered_snapshot_is_flagged() {
+        let config = ZakatConfig::test_default();
+        let portfolio = ZakatPortfolio::new().add(BusinessZakat::cash_only(100_000).label("Shop"));
+        let old = PortfolioSnapshot::from_json(&snapshot_of(&portfolio, &config).to_json().unwrap()).unwrap();
+        let mut new = old.clone();
+        new.result.total_zakat_due = Decimal::ZERO;
+
+        let diff = diff_snapshots(&old, &new);
+
+        assert!(diff.warnings.iter().any(|w| w.starts_with("New snapshot does not match")), "{:?}", diff.warnings);
+        assert!(diff.warnings.iter().all(|w| !w.starts_with("Old snapshot")), "{:?}", diff.warnings);
+    }
+}
@@ -1,12 +1,16 @@
 //! Application state and screen management.
 
 use rust_decimal::Decimal;
+use std::time::{Duration, Instant};
 
 use tui_input::Input;
 use zakat_core::assets::PortfolioItem;
 use zakat_core::prelude::*;
+use zakat_core::inputs::parse_decimal_with_separators;
 use zakat_core::traits::CalculateZakat;
-use zakat_providers::Prices;
+use zakat_providers::{PriceDelta, Prices};
+
+use crate::tui::theme::{Theme, ThemeKind};
 
 /// Current screen/view in the application
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -62,6 +66,8 @@ pub struct App {
     pub portfolio: ZakatPortfolio,
     /// Current metal prices
     pub prices: Option<Prices>,
+    /// Prices before the latest fetch (the cached ones at startup), for the header delta
+    pub previous_prices: Option<Prices>,
     /// Zakat configuration
     pub config: ZakatConfig,
     /// Calculation results
@@ -80,6 +86,43 @@ pub struct App {
     pub form_data: FormData,
     /// Index of asset being edited (if any)
     pub editing_asset_index: Option<usize>,
+    /// When prices last changed after the initial load (drives the "updated" flash)
+    pub prices_updated_at: Option<Instant>,
+    /// Selected color scheme
+    pub theme_kind: ThemeKind,
+    /// Resolved theme used by all rendering
+    pub theme: Theme,
+    /// Translator for i18n keys in results (e.g. failure suggestions)
+    pub translator: zakat_i18n::Translator,
+    /// Prices used when the live fetch times out or is skipped
+    pub fallback_prices: Prices,
+    /// How long the loading screen waits for live prices
+    pub price_timeout: Duration,
+    /// When the loading screen was entered
+    pub loading_started_at: Instant,
+    /// Decimal separator accepted in numeric input fields
+    pub decimal_separator: char,
+    /// Thousands separator accepted (and ignored) in numeric input fields
+    pub grouping_separator: char,
+    /// Snapshot prepared by `start_export`, written as-is so it matches the suggested filename
+    pub pending_export: Option<PortfolioSnapshot>,
+}
+
+/// How long the "prices updated" flash stays in the status bar
+pub const PRICE_FLASH_DURATION: Duration = Duration::from_secs(3);
+
+/// Default wait for live prices before falling back
+pub const DEFAULT_PRICE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Dashboard figures derived from the portfolio and current prices
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DashboardStats {
+    /// Estimated value of all assets
+    pub total_value: Decimal,
+    /// Monetary Nisab threshold under the current config
+    pub nisab_threshold: Decimal,
+    /// Whether the estimated total reaches the Nisab
+    pub above_nisab: bool,
 }
 
 /// Type of status message
@@ -100,6 +143,8 @@ pub struct FormData {
     pub inventory: Decimal,
     pub receivables: Decimal,
     pub liabilities: Decimal,
+    /// Dated or long-term debts the form cannot show; written back unchanged on save
+    pub kept_liabilities: Vec<zakat_core::types::Liability>,
     pub is_gold: bool,
     pub is_irrigated: bool,
 }
@@ -112,6 +157,7 @@ impl App {
             screen: Screen::Loading,
             portfolio: ZakatPortfolio::new(),
             prices: None,
+            previous_prices: None,
             config: ZakatConfig::new()
                 .with_madhab(Madhab::Hanafi)
                 .with_nisab_standard(NisabStandard::Gold)
@@ -124,19 +170,143 @@ impl App {
             message: None,
             form_data: FormData::default(),
             editing_asset_index: None,
+            prices_updated_at: None,
+            theme_kind: ThemeKind::default(),
+            theme: Theme::default(),
+            translator: zakat_i18n::default_translator(),
+            fallback_prices: Prices::demo(),
+            price_timeout: DEFAULT_PRICE_TIMEOUT,
+            loading_started_at: Instant::now(),
+            decimal_separator: '.',
+            grouping_separator: ',',
+            pending_export: None,
         }
     }
 
+    /// Locale for translated text, from the config's locale code (English if unsupported)
+    pub fn locale(&self) -> zakat_i18n::ZakatLocale {
+        self.config.locale_code.parse().unwrap_or_default()
+    }
+
+    /// Accept `separator` as the decimal point; the other of `.`/`,` becomes grouping
+    pub fn set_decimal_separator(&mut self, separator: char) {
+        self.decimal_separator = separator;
+        self.grouping_separator = if separator == ',' { '.' } else { ',' };
+    }
+
+    /// Parse a typed amount using the configured separators
+    pub fn parse_number(&self, value: &str) -> Option<Decimal> {
+        parse_decimal_with_separators(value, self.decimal_separator, self.grouping_separator).ok()
+    }
+
+    /// Switch to the given color scheme
+    pub fn set_theme(&mut self, kind: ThemeKind) {
+        self.theme_kind = kind;
+        self.theme = Theme::from(kind);
+    }
+
+    /// Cycle to the next color scheme (dashboard `t` key)
+    pub fn cycle_theme(&mut self) {
+        self.set_theme(self.theme_kind.next());
+        self.message = Some((
+            format!("Theme: {}", self.theme_kind.name()),
+            MessageType::Info,
+        ));
+    }
+
     /// Set prices and update config
+    ///
+    /// The first call leaves the loading screen. Later calls with different prices
+    /// recompute any shown results and flash an "updated" notice in the status bar.
     pub fn set_prices(&mut self, prices: Prices) {
-        self.config = self.config.clone()
-            .with_gold_price(prices.gold_per_gram)
-            .with_silver_price(prices.silver_per_gram);
-        self.prices = Some(prices);
-        self.screen = Screen::Main;
+        if self.prices.as_ref() == Some(&prices) {
+            return;
+        }
+        let is_update = self.prices.is_some();
+        self.config = prices.apply_to(self.config.clone());
+        if let Some(old) = self.prices.replace(prices) {
+            self.previous_prices = Some(old);
+        }
+
+        if is_update {
+            if self.results.is_some() {
+                self.results = Some(self.portfolio.calculate_total(&self.config));
+            }
+            self.prices_updated_at = Some(Instant::now());
+        }
+        if let Some(warning) = self.config.sanity_check().into_iter().next() {
+            self.message = Some((format!("⚠ {}", warning), MessageType::Warning));
+        }
+        if self.screen == Screen::Loading {
+            self.screen = Screen::Main;
+        }
     }
 
+    /// Percentage change of the current prices since the previous ones, if both are
+    /// known and quoted in the same currency
+    pub fn price_delta(&self) -> Option<PriceDelta> {
+        self.prices.as_ref()?.delta(self.previous_prices.as_ref()?)
+    }
 
+    /// Leave the loading screen with the fallback prices once the fetch has
+    /// taken longer than [`App::price_timeout`]. Called every frame.
+    pub fn tick(&mut self) {
+        if self.screen == Screen::Loading && self.loading_started_at.elapsed() >= self.price_timeout {
+            self.use_fallback_prices(&format!(
+                "Live prices timed out after {}s",
+                self.price_timeout.as_secs()
+            ));
+        }
+    }
+
+    /// Skip the live fetch (Esc on the loading screen) and continue offline
+    pub fn skip_loading(&mut self) {
+        if self.screen == Screen::Loading {
+            self.use_fallback_prices("Live prices skipped");
+        }
+    }
+
+    /// Apply the fallback prices and warn that they are not live.
+    ///
+    /// Live prices arriving later still replace them via [`App::set_prices`].
+    fn use_fallback_prices(&mut self, reason: &str) {
+        let fallback = self.fallback_prices.clone();
+        self.set_prices(fallback.clone());
+        self.prices_updated_at = None;
+        self.message = Some((
+            format!(
+                "⚠ {}; using fallback prices (gold {}/g, silver {}/g)",
+                reason, fallback.gold_per_gram, fallback.silver_per_gram
+            ),
+            MessageType::Warning,
+        ));
+    }
+
+    /// Returns true while the "prices updated" flash should be visible
+    pub fn prices_recently_updated(&self) -> bool {
+        self.prices_updated_at
+            .is_some_and(|at| at.elapsed() < PRICE_FLASH_DURATION)
+    }
+
+    /// Compute the dashboard totals and Nisab status from the current prices
+    pub fn dashboard_stats(&self) -> DashboardStats {
+        let total_value: Decimal = self
+            .portfolio
+            .get_items()
+            .iter()
+            .map(|item| item.estimated_value(&self.config).unwrap_or(Decimal::MAX))
+            .fold(Decimal::ZERO, Decimal::saturating_add);
+        // Same threshold the core applies, so the card never disagrees with a calculation.
+        let nisab_threshold = self
+            .prices
+            .as_ref()
+            .and_then(|_| self.config.nisab_threshold(self.config.cash_nisab_standard).ok());
+        DashboardStats {
+            total_value,
+            nisab_threshold: nisab_threshold.unwrap_or(Decimal::ZERO),
+            above_nisab: nisab_threshold.is_some_and(|threshold| total_value > Decimal::ZERO && total_value >= threshold),
+        }
+    }
 
     /// Get menu items for main screen
     pub fn main_menu_items(&self) -> Vec<&'static str> {
@@ -276,7 +446,13 @@ impl App {
                 self.form_data.amount = b.cash_on_hand;
                 self.form_data.inventory = b.inventory_value;
                 self.form_data.receivables = b.receivables;
-                self.form_data.liabilities = b.total_liabilities();
+                // Only undated immediate debts fit the single liabilities field
+                let (simple, kept): (Vec<_>, Vec<_>) = b.named_liabilities.iter().cloned()
+                    .partition(|l| l.kind == zakat_core::types::LiabilityType::Immediate && l.due_date.is_none());
+                #[allow(deprecated)] // Legacy `liabilities_due_now` is folded into the field
+                let legacy = b.liabilities_due_now;
+                self.form_data.liabilities = legacy + simple.iter().map(|l| l.amount).sum::<Decimal>();
+                self.form_data.kept_liabilities = kept;
                 
                 self.screen = Screen::AddAsset(AssetTypeSelection::Business);
                 self.input_field = InputField::Label;
@@ -334,53 +510,55 @@ impl App {
 
     /// Handle asset type selection
     pub fn select_asset_type(&mut self) {
+        let selection = match self.menu_index {
+            0 => AssetTypeSelection::Business,
+            1 => AssetTypeSelection::Gold,
+            2 => AssetTypeSelection::Silver,
+            3 => AssetTypeSelection::Cash,
+            4 => AssetTypeSelection::Investment,
+            5 => AssetTypeSelection::Agriculture,
+            6 => {
+                // Back
+                self.go_back();
+                self.menu_index = 0;
+                return;
+            }
+            _ => {
+                self.menu_index = 0;
+                return;
+            }
+        };
+        self.open_asset_form(selection);
+    }
+
+    /// Open a fresh add-asset form for the given type, starting at the label field.
+    ///
+    /// Used by the asset picker and by the dashboard quick-add shortcuts.
+    pub fn open_asset_form(&mut self, selection: AssetTypeSelection) {
         self.editing_asset_index = None; // Reset editing state when selecting new asset type manually
         self.form_data = FormData::default();
-        match self.menu_index {
-            0 => {
-                // Business
-                self.screen = Screen::AddAsset(AssetTypeSelection::Business);
-                self.input_field = InputField::Label;
-                self.input = Input::default().with_value("Business".to_string());
-            }
-            1 => {
-                // Gold
+        let default_label = match selection {
+            AssetTypeSelection::Business => "Business",
+            AssetTypeSelection::Gold => {
                 self.form_data.is_gold = true;
-                self.screen = Screen::AddAsset(AssetTypeSelection::Gold);
-                self.input_field = InputField::Label;
-                self.input = Input::default().with_value("Gold".to_string());
+                "Gold"
             }
-            2 => {
-                // Silver
+            AssetTypeSelection::Silver => {
                 self.form_data.is_gold = false;
-                self.screen = Screen::AddAsset(AssetTypeSelection::Silver);
-                self.input_field = InputField::Label;
-                self.input = Input::default().with_value("Silver".to_string());
-            }
-            3 => {
-                // Cash
-                self.screen = Screen::AddAsset(AssetTypeSelection::Cash);
-                self.input_field = InputField::Label;
-                self.input = Input::default().with_value("Savings".to_string());
+                "Silver"
             }
-            4 => {
-                // Investment
-                self.screen = Screen::AddAsset(AssetTypeSelection::Investment);
-                self.input_field = InputField::Label;
-                self.input = Input::default().with_value("Investments".to_string());
-            }
-            5 => {
-                // Agriculture
-                self.screen = Screen::AddAsset(AssetTypeSelection::Agriculture);
-                self.input_field = InputField::Label;
-                self.input = Input::default().with_value("Harvest".to_string());
-            }
-            6 => {
-                // Back
-                self.go_back();
+            AssetTypeSelection::Cash => "Savings",
+            AssetTypeSelection::Investment => "Investments",
+            AssetTypeSelection::Agriculture => "Harvest",
+            AssetTypeSelection::Menu => {
+                self.screen = Screen::AddAsset(AssetTypeSelection::Menu);
+                self.menu_index = 0;
+                return;
             }
-            _ => {}
-        }
+        };
+        self.screen = Screen::AddAsset(selection);
+        self.input_field = InputField::Label;
+        self.input = Input::default().with_value(default_label.to_string());
         self.menu_index = 0;
     }
 
@@ -407,12 +585,13 @@ impl App {
     pub fn add_current_asset(&mut self) {
         let new_asset = match &self.screen {
             Screen::AddAsset(AssetTypeSelection::Business) => {
-                let asset = BusinessZakat::new()
+                let mut asset = BusinessZakat::new()
                     .label(&self.form_data.label)
                     .cash(self.form_data.amount)
                     .inventory(self.form_data.inventory)
                     .receivables(self.form_data.receivables)
                     .add_liability("Liabilities", self.form_data.liabilities);
+                asset.named_liabilities.extend(self.form_data.kept_liabilities.iter().cloned());
                 Some(PortfolioItem::Business(asset))
             }
             Screen::AddAsset(AssetTypeSelection::Gold) => {
@@ -453,6 +632,12 @@ impl App {
             _ => None,
         };
         
+        // Reject bad input or missing prices here, keeping the form open
+        if let Some(Err(e)) = new_asset.as_ref().map(|asset| asset.preflight(&self.config)) {
+            self.message = Some((format!("✗ {}", e), MessageType::Error));
+            return;
+        }
+
         if let Some(asset) = new_asset {
             if let Some(index) = self.editing_asset_index {
                 // Replace existing asset at index
@@ -493,10 +678,52 @@ impl App {
         Ok(())
     }
 
+    /// Prompt for a filename to export the current results to
+    pub fn start_export(&mut self) {
+        let Some(result) = &self.results else {
+            self.message = Some((
+                "No results to export. Calculate first.".to_string(),
+                MessageType::Warning,
+            ));
+            return;
+        };
+        let snapshot = self.portfolio.snapshot(&self.config, result);
+        self.input = Input::default().with_value(snapshot.suggested_filename());
+        self.pending_export = Some(snapshot);
+        self.input_field = InputField::Filename;
+        self.message = Some((
+            "Enter filename and press Enter to export".to_string(),
+            MessageType::Info,
+        ));
+    }
+
+    /// Export the current results as a JSON snapshot (config, inputs and result)
+    pub fn export_results(&mut self, filename: &str) -> std::io::Result<()> {
+        let Some(result) = &self.results else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "no calculation results to export",
+            ));
+        };
+        let snapshot = match self.pending_export.take() {
+            Some(snapshot) => snapshot,
+            None => self.portfolio.snapshot(&self.config, result),
+        };
+        let json = snapshot.to_json()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(filename, json)?;
+        self.message = Some((
+            format!("✓ Exported to {}", filename),
+            MessageType::Success,
+        ));
+        self.input_field = InputField::None;
+        Ok(())
+    }
+
     /// Load portfolio from file
     pub fn load_portfolio(&mut self, filename: &str) -> std::io::Result<()> {
         let content = std::fs::read_to_string(filename)?;
-        let portfolio: ZakatPortfolio = serde_json::from_str(&content)
+        let portfolio = crate::doctor::parse_portfolio(std::path::Path::new(filename), &content)
             .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
         self.portfolio = portfolio;
         self.message = Some((
@@ -10,7 +10,7 @@ use ratatui::{
     Frame,
 };
 
-use super::super::theme::theme;
+use super::super::theme::Theme;
 
 /// An asset type selection card with icon, title, and description.
 pub struct AssetCard<'a> {
@@ -22,16 +22,19 @@ pub struct AssetCard<'a> {
     description: &'a str,
     /// Whether this card is selected/focused
     selected: bool,
+    /// Theme used for borders, background and text colors
+    theme: &'a Theme,
 }
 
 impl<'a> AssetCard<'a> {
-    /// Create a new asset card.
-    pub fn new(icon: &'a str, title: &'a str, description: &'a str) -> Self {
+    /// Create a new asset card drawn in `theme`.
+    pub fn new(icon: &'a str, title: &'a str, description: &'a str, theme: &'a Theme) -> Self {
         Self {
             icon,
             title,
             description,
             selected: false,
+            theme,
         }
     }
 
@@ -43,7 +46,7 @@ impl<'a> AssetCard<'a> {
 
     /// Render the asset card to the frame.
     pub fn render(self, frame: &mut Frame, area: Rect) {
-        let t = theme();
+        let t = self.theme;
 
         let border_style = if self.selected {
             Style::default().fg(t.gold).add_modifier(Modifier::BOLD)
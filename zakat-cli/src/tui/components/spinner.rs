@@ -12,7 +12,7 @@ use ratatui::{
     Frame,
 };
 
-use super::super::theme::{icons, theme};
+use super::super::theme::{icons, Theme};
 
 /// A loading spinner with optional message and progress.
 pub struct LoadingSpinner<'a> {
@@ -22,15 +22,18 @@ pub struct LoadingSpinner<'a> {
     progress: Option<u16>,
     /// Frame index for animation
     frame: usize,
+    /// Theme used for colors
+    theme: &'a Theme,
 }
 
 impl<'a> LoadingSpinner<'a> {
-    /// Create a new loading spinner.
-    pub fn new(message: &'a str) -> Self {
+    /// Create a new loading spinner drawn in `theme`.
+    pub fn new(message: &'a str, theme: &'a Theme) -> Self {
         Self {
             message,
             progress: None,
             frame: 0,
+            theme,
         }
     }
 
@@ -48,7 +51,7 @@ impl<'a> LoadingSpinner<'a> {
 
     /// Render the loading spinner.
     pub fn render(self, frame: &mut Frame, area: Rect) {
-        let t = theme();
+        let t = self.theme;
 
         // Center the content
         let v_layout = Layout::default()
@@ -125,7 +128,6 @@ impl ProgressBar {
 
     /// Generate the progress bar string.
     pub fn to_string(&self) -> String {
-        let _t = theme();
         let pct = if self.max > 0 {
             (self.value as f32 / self.max as f32).min(1.0)
         } else {
@@ -141,9 +143,8 @@ impl ProgressBar {
         )
     }
 
-    /// Render as a Line with styling.
-    pub fn to_line(&self) -> Line<'static> {
-        let t = theme();
+    /// Render as a Line styled with `t`.
+    pub fn to_line(&self, t: &Theme) -> Line<'static> {
         let pct = if self.max > 0 {
             (self.value as f32 / self.max as f32).min(1.0)
         } else {
@@ -185,9 +186,8 @@ impl StepIndicator {
         }
     }
 
-    /// Render the step indicator as a Line.
-    pub fn to_line(&self) -> Line<'static> {
-        let t = theme();
+    /// Render the step indicator as a Line styled with `t`.
+    pub fn to_line(&self, t: &Theme) -> Line<'static> {
         let mut spans = Vec::new();
 
         for (i, label) in self.labels.iter().enumerate() {
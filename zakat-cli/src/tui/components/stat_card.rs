@@ -12,7 +12,7 @@ use ratatui::{
     Frame,
 };
 
-use super::super::theme::theme;
+use super::super::theme::{theme, Theme};
 
 /// A stat card displaying a title and value with optional styling.
 pub struct StatCard<'a> {
@@ -20,8 +20,10 @@ pub struct StatCard<'a> {
     title: &'a str,
     /// Card value to display
     value: &'a str,
-    /// Color for the value text
-    value_color: Color,
+    /// Theme used for borders, background and default text colors
+    theme: &'a Theme,
+    /// Color for the value text (defaults to the theme's primary text)
+    value_color: Option<Color>,
     /// Whether this card is highlighted/focused
     highlighted: bool,
     /// Optional subtitle or additional info
@@ -34,15 +36,22 @@ impl<'a> StatCard<'a> {
         Self {
             title,
             value,
-            value_color: theme().text_primary,
+            theme: theme(),
+            value_color: None,
             highlighted: false,
             subtitle: None,
         }
     }
 
+    /// Render with the given theme instead of the default one.
+    pub fn theme(mut self, theme: &'a Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
     /// Set the value color.
     pub fn value_color(mut self, color: Color) -> Self {
-        self.value_color = color;
+        self.value_color = Some(color);
         self
     }
 
@@ -60,7 +69,8 @@ impl<'a> StatCard<'a> {
 
     /// Render the stat card to the frame.
     pub fn render(self, frame: &mut Frame, area: Rect) {
-        let t = theme();
+        let t = self.theme;
+        let value_color = self.value_color.unwrap_or(t.text_primary);
 
         let border_style = if self.highlighted {
             t.border_active()
@@ -109,7 +119,7 @@ impl<'a> StatCard<'a> {
         // Value
         frame.render_widget(
             Paragraph::new(self.value)
-                .style(Style::default().fg(self.value_color).add_modifier(Modifier::BOLD))
+                .style(Style::default().fg(value_color).add_modifier(Modifier::BOLD))
                 .alignment(Alignment::Left),
             content[1],
         );
@@ -164,3 +174,36 @@ impl<'a> InlineStat<'a> {
         ])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::theme::ThemeKind;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn render_with(theme: &Theme) -> (Option<Color>, Option<Color>) {
+        let mut terminal = Terminal::new(TestBackend::new(30, 6)).unwrap();
+        terminal
+            .draw(|f| StatCard::new("Nisab", "$7225.00").theme(theme).render(f, f.area()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let border = buffer.cell((0, 0)).map(|c| c.style().bg).unwrap_or_default();
+        let value = buffer.cell((2, 3)).map(|c| c.style().fg).unwrap_or_default();
+        (border, value)
+    }
+
+    #[test]
+    fn test_stat_card_follows_theme() {
+        let dark = Theme::from(ThemeKind::Dark);
+        let light = Theme::from(ThemeKind::Light);
+
+        let (dark_bg, dark_fg) = render_with(&dark);
+        let (light_bg, light_fg) = render_with(&light);
+
+        assert_eq!(dark_bg, Some(dark.slate));
+        assert_eq!(light_bg, Some(light.slate));
+        assert_ne!(dark_bg, light_bg);
+        assert_eq!(dark_fg, Some(dark.text_primary));
+        assert_ne!(dark_fg, light_fg);
+    }
+}
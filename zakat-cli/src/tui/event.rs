@@ -5,7 +5,6 @@ use rust_decimal::Decimal;
 use std::io;
 use std::time::Duration;
 use tui_input::backend::crossterm::EventHandler;
-use zakat_core::prelude::ZakatPortfolio;
 
 use crate::tui::app::{App, AssetTypeSelection, InputField, MessageType, Screen};
 
@@ -38,7 +37,9 @@ pub fn handle_events(app: &mut App) -> io::Result<bool> {
             // Handle keys based on current screen
             match &app.screen {
                 Screen::Loading => {
-                    // No interaction during loading
+                    if key.code == KeyCode::Esc {
+                        app.skip_loading();
+                    }
                 }
                 Screen::Main => handle_main_screen(app, key.code),
                 Screen::AddAsset(AssetTypeSelection::Menu) => handle_asset_menu(app, key.code),
@@ -93,7 +94,7 @@ fn handle_input_mode(app: &mut App, key: event::KeyEvent) -> io::Result<bool> {
                     }
                 }
                 InputField::Amount => {
-                    if let Ok(amount) = value.parse::<Decimal>() {
+                    if let Some(amount) = app.parse_number(&value) {
                         app.form_data.amount = amount;
                         match &app.screen {
                             Screen::AddAsset(AssetTypeSelection::Business) => {
@@ -112,7 +113,7 @@ fn handle_input_mode(app: &mut App, key: event::KeyEvent) -> io::Result<bool> {
                     }
                 }
                 InputField::Weight => {
-                    if let Ok(weight) = value.parse::<Decimal>() {
+                    if let Some(weight) = app.parse_number(&value) {
                         app.form_data.weight = weight;
                         match &app.screen {
                             Screen::AddAsset(AssetTypeSelection::Gold | AssetTypeSelection::Silver) => {
@@ -134,7 +135,7 @@ fn handle_input_mode(app: &mut App, key: event::KeyEvent) -> io::Result<bool> {
                     }
                 }
                 InputField::Inventory => {
-                    if let Ok(inv) = value.parse::<Decimal>() {
+                    if let Some(inv) = app.parse_number(&value) {
                         app.form_data.inventory = inv;
                         app.input_field = InputField::Receivables;
                         app.input = tui_input::Input::default();
@@ -144,7 +145,7 @@ fn handle_input_mode(app: &mut App, key: event::KeyEvent) -> io::Result<bool> {
                     }
                 }
                 InputField::Receivables => {
-                    if let Ok(rec) = value.parse::<Decimal>() {
+                    if let Some(rec) = app.parse_number(&value) {
                         app.form_data.receivables = rec;
                         app.input_field = InputField::Liabilities;
                         app.input = tui_input::Input::default();
@@ -154,7 +155,7 @@ fn handle_input_mode(app: &mut App, key: event::KeyEvent) -> io::Result<bool> {
                     }
                 }
                 InputField::Liabilities => {
-                    if let Ok(liab) = value.parse::<Decimal>() {
+                    if let Some(liab) = app.parse_number(&value) {
                         app.form_data.liabilities = liab;
                         // Done - add business asset
                         app.add_current_asset();
@@ -163,8 +164,12 @@ fn handle_input_mode(app: &mut App, key: event::KeyEvent) -> io::Result<bool> {
                     }
                 }
                 InputField::Filename => {
-                    // Determine if save or load based on menu index
-                    if app.menu_index == 2 {
+                    // Export from the results screen; otherwise save or load based on menu index
+                    if app.screen == Screen::Results {
+                        if let Err(e) = app.export_results(&value) {
+                            app.message = Some((format!("Export error: {}", e), MessageType::Error));
+                        }
+                    } else if app.menu_index == 2 {
                         // Save
                         if let Err(e) = app.save_portfolio(&value) {
                             app.message = Some((format!("Save error: {}", e), MessageType::Error));
@@ -183,6 +188,7 @@ fn handle_input_mode(app: &mut App, key: event::KeyEvent) -> io::Result<bool> {
             // Cancel current input and go back to field navigation
             app.input_field = InputField::None;
             app.input = tui_input::Input::default();
+            app.pending_export = None;
         }
         KeyCode::Tab | KeyCode::Down => {
             // Save current value and advance to next field
@@ -212,27 +218,27 @@ fn save_current_field_value(app: &mut App) {
             app.form_data.label = value;
         }
         InputField::Amount => {
-            if let Ok(amount) = value.parse::<Decimal>() {
+            if let Some(amount) = app.parse_number(&value) {
                 app.form_data.amount = amount;
             }
         }
         InputField::Weight => {
-            if let Ok(weight) = value.parse::<Decimal>() {
+            if let Some(weight) = app.parse_number(&value) {
                 app.form_data.weight = weight;
             }
         }
         InputField::Inventory => {
-            if let Ok(inv) = value.parse::<Decimal>() {
+            if let Some(inv) = app.parse_number(&value) {
                 app.form_data.inventory = inv;
             }
         }
         InputField::Receivables => {
-            if let Ok(rec) = value.parse::<Decimal>() {
+            if let Some(rec) = app.parse_number(&value) {
                 app.form_data.receivables = rec;
             }
         }
         InputField::Liabilities => {
-            if let Ok(liab) = value.parse::<Decimal>() {
+            if let Some(liab) = app.parse_number(&value) {
                 app.form_data.liabilities = liab;
             }
         }
@@ -248,6 +254,12 @@ fn handle_main_screen(app: &mut App, key: KeyCode) {
         KeyCode::Enter => app.select_main_menu(),
         KeyCode::Char('q') => app.running = false,
         KeyCode::Char('?') => app.screen = Screen::Help,
+        // Quick-add shortcuts: jump straight to the asset form
+        KeyCode::Char('g') => app.open_asset_form(AssetTypeSelection::Gold),
+        KeyCode::Char('s') => app.open_asset_form(AssetTypeSelection::Silver),
+        KeyCode::Char('c') => app.open_asset_form(AssetTypeSelection::Cash),
+        KeyCode::Char('b') => app.open_asset_form(AssetTypeSelection::Business),
+        KeyCode::Char('t') => app.cycle_theme(),
         _ => {}
     }
 }
@@ -452,23 +464,15 @@ fn handle_edit_asset(app: &mut App, key: KeyCode) {
         }
         KeyCode::Char('d') | KeyCode::Delete => {
             // Delete selected asset
-            let items = app.portfolio.get_items();
-            if !items.is_empty() && app.asset_index < items.len() {
-                // Note: ZakatPortfolio doesn't have a remove method, so we rebuild
-                let mut new_portfolio = ZakatPortfolio::new();
-                for (i, item) in items.iter().enumerate() {
-                    if i != app.asset_index {
-                        new_portfolio = new_portfolio.add(item.clone());
-                    }
-                }
-                app.portfolio = new_portfolio;
-                app.message = Some(("Asset deleted".to_string(), MessageType::Success));
-                if app.asset_index > 0 {
-                    app.asset_index -= 1;
-                }
-                if app.portfolio.get_items().is_empty() {
-                    app.go_back();
-                }
+            if app.portfolio.remove_at(app.asset_index).is_err() {
+                return;
+            }
+            app.message = Some(("Asset deleted".to_string(), MessageType::Success));
+            if app.asset_index > 0 {
+                app.asset_index -= 1;
+            }
+            if app.portfolio.get_items().is_empty() {
+                app.go_back();
             }
         }
         _ => {}
@@ -481,6 +485,7 @@ fn handle_results(app: &mut App, key: KeyCode) {
         KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.go_back(),
         KeyCode::Up | KeyCode::Char('k') => app.menu_up(),
         KeyCode::Down | KeyCode::Char('j') => app.menu_down(),
+        KeyCode::Char('e') => app.start_export(),
         _ => {}
     }
 }
@@ -494,3 +499,154 @@ fn handle_help(app: &mut App, key: KeyCode) {
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+    use zakat_core::assets::PortfolioItem;
+    use zakat_core::prelude::{BusinessZakat, ZakatPortfolio};
+
+    #[test]
+    fn test_quick_add_gold_opens_gold_form() {
+        let mut app = App::new(true);
+        app.screen = Screen::Main;
+
+        handle_main_screen(&mut app, KeyCode::Char('g'));
+
+        assert_eq!(app.screen, Screen::AddAsset(AssetTypeSelection::Gold));
+        assert_eq!(app.input_field, InputField::Label);
+        assert!(app.form_data.is_gold);
+        assert_eq!(app.input.value(), "Gold");
+    }
+
+    #[test]
+    fn test_results_export_writes_entered_filename() {
+        let mut app = App::new(true);
+        app.portfolio = ZakatPortfolio::new().add_cash(10_000, "Savings");
+        app.results = Some(app.portfolio.calculate_total(&app.config));
+        app.screen = Screen::Results;
+
+        handle_results(&mut app, KeyCode::Char('e'));
+        assert_eq!(app.input_field, InputField::Filename);
+        let suggested = app.input.value().to_string();
+        assert!(suggested.starts_with("zakat_snapshot_") && suggested.ends_with(".json"), "{}", suggested);
+
+        let path = std::env::temp_dir().join(format!("zakat_export_{}.json", std::process::id()));
+        app.input = tui_input::Input::default().with_value(path.to_string_lossy().to_string());
+        handle_input_mode(&mut app, event::KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)).unwrap();
+
+        let written = std::fs::read_to_string(&path).expect("export should write the entered filename");
+        std::fs::remove_file(&path).ok();
+        assert!(written.contains("\"result\""));
+        let exported = zakat_core::prelude::PortfolioSnapshot::from_json(&written).unwrap();
+        assert_eq!(exported.suggested_filename(), suggested, "The file must be the snapshot the name was built from");
+        assert_eq!(app.input_field, InputField::None);
+        assert_eq!(app.screen, Screen::Results);
+    }
+
+    #[test]
+    fn test_comma_decimal_amount_is_accepted() {
+        let mut app = App::new(true);
+        app.set_decimal_separator(',');
+        app.screen = Screen::AddAsset(AssetTypeSelection::Business);
+        app.input_field = InputField::Amount;
+        app.input = tui_input::Input::default().with_value("1.000,50".to_string());
+
+        handle_input_mode(&mut app, event::KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)).unwrap();
+
+        assert_eq!(app.form_data.amount, Decimal::new(100050, 2));
+        assert_eq!(app.input_field, InputField::Inventory);
+    }
+
+    #[test]
+    fn test_price_update_after_load_refreshes_nisab_stat() {
+        let mut app = App::new(false);
+        app.set_prices(zakat_providers::Prices::new(85, 1).unwrap());
+        assert_eq!(app.screen, Screen::Main);
+        assert_eq!(app.dashboard_stats().nisab_threshold, Decimal::from(7225));
+        assert!(!app.prices_recently_updated());
+
+        app.set_prices(zakat_providers::Prices::new(100, 1).unwrap());
+
+        assert_eq!(app.dashboard_stats().nisab_threshold, Decimal::from(8500));
+        assert!(app.prices_recently_updated());
+    }
+
+    #[test]
+    fn test_slow_price_fetch_falls_back_after_timeout() {
+        let mut app = App::new(false);
+        app.price_timeout = Duration::from_secs(10);
+
+        // The provider has not answered yet: stay on the loading screen.
+        app.loading_started_at = Instant::now();
+        app.tick();
+        assert_eq!(app.screen, Screen::Loading);
+
+        // Backdate the start instead of sleeping past the timeout.
+        app.loading_started_at = Instant::now() - Duration::from_secs(11);
+        app.tick();
+
+        assert_eq!(app.screen, Screen::Main);
+        assert_eq!(app.prices.as_ref(), Some(&app.fallback_prices));
+        let (message, kind) = app.message.clone().expect("fallback warning");
+        assert_eq!(kind, MessageType::Warning);
+        assert!(message.contains("fallback prices"), "{}", message);
+    }
+
+    #[test]
+    fn test_esc_skips_loading_to_offline_mode() {
+        let mut app = App::new(false);
+        app.screen = Screen::Loading;
+
+        app.skip_loading();
+
+        assert_eq!(app.screen, Screen::Main);
+        assert!(app.prices.is_some());
+        assert!(matches!(app.message, Some((_, MessageType::Warning))));
+    }
+
+    #[test]
+    fn test_adding_asset_without_prices_keeps_form_open() {
+        let mut app = App::new(false);
+        app.screen = Screen::AddAsset(AssetTypeSelection::Gold);
+        app.form_data.label = "Bar".to_string();
+        app.form_data.weight = Decimal::from(100);
+
+        app.add_current_asset();
+
+        assert_eq!(app.screen, Screen::AddAsset(AssetTypeSelection::Gold));
+        assert!(app.portfolio.get_items().is_empty());
+        assert!(matches!(app.message, Some((_, MessageType::Error))));
+
+        app.set_prices(zakat_providers::Prices::new(85, 1).unwrap());
+        app.add_current_asset();
+        assert_eq!(app.portfolio.get_items().len(), 1);
+        assert_eq!(app.screen, Screen::Main);
+    }
+
+    #[test]
+    fn test_editing_business_keeps_dated_debts() {
+        let mut app = App::new(false);
+        app.set_prices(zakat_providers::Prices::new(85, 1).unwrap());
+        let loan_due = chrono::Local::now().date_naive() + chrono::Duration::days(400);
+        app.portfolio = ZakatPortfolio::new().add(
+            BusinessZakat::cash_only(20_000)
+                .label("Shop")
+                .add_liability("Supplier", 1_000)
+                .add_liability_due("Bank Loan", 5_000, loan_due),
+        );
+
+        app.start_editing();
+        assert_eq!(app.form_data.liabilities, Decimal::from(1_000), "Only the undated debt is editable");
+        app.add_current_asset();
+
+        let PortfolioItem::Business(saved) = &app.portfolio.get_items()[0] else {
+            panic!("expected a business");
+        };
+        let loan = saved.named_liabilities.iter().find(|l| l.description == "Bank Loan").expect("the dated loan survives the edit");
+        assert_eq!(loan.due_date, Some(loan_due));
+        let result = app.portfolio.calculate_total(&app.config);
+        assert_eq!(result.successes()[0].net_assets, Decimal::from(19_000), "The loan is still deferred after saving");
+    }
+}
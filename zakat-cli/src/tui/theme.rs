@@ -33,8 +33,63 @@ pub struct Theme {
     pub accent: Color,
 }
 
+/// Built-in color schemes, selectable with `--theme` or the `t` key on the dashboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ThemeKind {
+    /// Gold/Emerald on dark slate (the original look)
+    #[default]
+    Dark,
+    /// Dark text on a light background for bright terminals
+    Light,
+    /// Pure black background with bright ANSI colors
+    HighContrast,
+}
+
+impl ThemeKind {
+    /// Returns the next theme in the cycle (used by the toggle key).
+    pub fn next(self) -> Self {
+        match self {
+            ThemeKind::Dark => ThemeKind::Light,
+            ThemeKind::Light => ThemeKind::HighContrast,
+            ThemeKind::HighContrast => ThemeKind::Dark,
+        }
+    }
+
+    /// Human-readable name for status messages.
+    pub fn name(self) -> &'static str {
+        match self {
+            ThemeKind::Dark => "Dark",
+            ThemeKind::Light => "Light",
+            ThemeKind::HighContrast => "High Contrast",
+        }
+    }
+}
+
 impl Default for Theme {
     fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl From<ThemeKind> for Theme {
+    fn from(kind: ThemeKind) -> Self {
+        match kind {
+            ThemeKind::Dark => Self::dark(),
+            ThemeKind::Light => Self::light(),
+            ThemeKind::HighContrast => Self::high_contrast(),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl Theme {
+    /// Creates a new theme with default colors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gold/Emerald palette on a dark slate background.
+    pub fn dark() -> Self {
         Self {
             gold: Color::Rgb(212, 175, 55),
             emerald: Color::Rgb(16, 185, 129),
@@ -48,13 +103,37 @@ impl Default for Theme {
             accent: Color::Cyan,
         }
     }
-}
 
-#[allow(dead_code)]
-impl Theme {
-    /// Creates a new theme with default colors.
-    pub fn new() -> Self {
-        Self::default()
+    /// Deeper gold and emerald on a near-white background.
+    pub fn light() -> Self {
+        Self {
+            gold: Color::Rgb(161, 98, 7),
+            emerald: Color::Rgb(4, 120, 87),
+            slate: Color::Rgb(248, 250, 252),
+            slate_light: Color::Rgb(226, 232, 240),
+            text_primary: Color::Rgb(15, 23, 42),
+            text_muted: Color::Rgb(71, 85, 105),
+            error: Color::Rgb(185, 28, 28),
+            warning: Color::Rgb(161, 98, 7),
+            success: Color::Rgb(4, 120, 87),
+            accent: Color::Rgb(14, 116, 144),
+        }
+    }
+
+    /// Bright ANSI colors on black, for low-vision users and limited terminals.
+    pub fn high_contrast() -> Self {
+        Self {
+            gold: Color::Yellow,
+            emerald: Color::LightGreen,
+            slate: Color::Black,
+            slate_light: Color::DarkGray,
+            text_primary: Color::White,
+            text_muted: Color::Gray,
+            error: Color::LightRed,
+            warning: Color::Yellow,
+            success: Color::LightGreen,
+            accent: Color::LightCyan,
+        }
     }
 
     // ─────────────────────────────────────────────────────────────
@@ -191,8 +270,9 @@ impl Theme {
     }
 }
 
-/// Global theme instance for convenience.
-/// In a more complex app, this could be configurable.
+/// Global default (dark) theme instance.
+/// Screens render with `App::theme`; this is only the fallback for components
+/// that are not given a theme explicitly.
 pub static THEME: std::sync::LazyLock<Theme> = std::sync::LazyLock::new(Theme::default);
 
 /// Convenience function to get the default theme.
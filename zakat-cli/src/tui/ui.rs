@@ -6,7 +6,7 @@
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
-    text::{Line, Span},
+    text::{Line, Span, Text},
     widgets::{
         Block, BorderType, Borders, Cell, Clear, List, ListItem, 
         Padding, Paragraph, Row, Table, Wrap,
@@ -16,13 +16,13 @@ use ratatui::{
 
 use crate::tui::app::{App, AssetTypeSelection, InputField, MessageType, Screen};
 use crate::tui::components::{LoadingSpinner, StatCard};
-use crate::tui::theme::{icons, theme};
+use crate::tui::theme::{icons, Theme};
 
 use zakat_core::assets::PortfolioItem;
-use zakat_core::prelude::{PortfolioItemResult, WealthType};
+use zakat_core::prelude::{wealth_type_meta, PortfolioItemResult, WealthCategory, WealthType};
+use zakat_core::types::{PaymentPayload, ZakatDetails};
 use zakat_core::traits::CalculateZakat;
 use rust_decimal::Decimal;
-use rust_decimal_macros::dec;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // MAIN UI ENTRY POINT
@@ -30,7 +30,7 @@ use rust_decimal_macros::dec;
 
 /// Main UI rendering function - entry point for all screen rendering.
 pub fn ui(frame: &mut Frame, app: &App) {
-    let t = theme();
+    let t = &app.theme;
 
     // Clear the entire frame first to prevent visual artifacts from popups
     frame.render_widget(Clear, frame.area());
@@ -69,7 +69,7 @@ pub fn ui(frame: &mut Frame, app: &App) {
 // ═══════════════════════════════════════════════════════════════════════════
 
 fn render_header(frame: &mut Frame, area: Rect, app: &App) {
-    let t = theme();
+    let t = &app.theme;
 
     let header_block = Block::default()
         .borders(Borders::BOTTOM)
@@ -99,18 +99,26 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
 
     // Right: Price Ticker
     let prices_line = if let Some(prices) = &app.prices {
-        Line::from(vec![
+        let delta = app.price_delta();
+        let mut spans = vec![
             Span::styled("Gold: ", Style::default().fg(t.gold)),
             Span::styled(format!("${:.2}/g", prices.gold_per_gram), Style::default().fg(t.text_primary)),
+        ];
+        spans.extend(delta_span(delta.and_then(|d| d.gold_pct), t));
+        spans.extend([
             Span::raw("  "),
             Span::styled(icons::SEPARATOR, Style::default().fg(t.slate_light)),
             Span::raw("  "),
             Span::styled("Silver: ", Style::default().fg(t.text_muted)),
             Span::styled(format!("${:.2}/g", prices.silver_per_gram), Style::default().fg(t.text_primary)),
+        ]);
+        spans.extend(delta_span(delta.and_then(|d| d.silver_pct), t));
+        spans.extend([
             Span::raw("  "),
             Span::styled(icons::CHECK, Style::default().fg(t.success)),
             Span::styled(" Live", Style::default().fg(t.success)),
-        ])
+        ]);
+        Line::from(spans)
     } else {
         Line::from(vec![
             Span::styled("Fetching Market Data...", Style::default().fg(t.text_muted).add_modifier(Modifier::ITALIC)),
@@ -123,6 +131,13 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
     );
 }
 
+/// " ▲2.00%" / " ▼1.25%" after a price; nothing when unknown or unchanged
+fn delta_span(pct: Option<Decimal>, t: &Theme) -> Option<Span<'static>> {
+    let pct = pct.filter(|p| !p.round_dp(2).is_zero())?;
+    let (arrow, color) = if pct > Decimal::ZERO { ("▲", t.success) } else { ("▼", t.error) };
+    Some(Span::styled(format!(" {}{:.2}%", arrow, pct.abs()), Style::default().fg(color)))
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // MAIN CONTENT
 // ═══════════════════════════════════════════════════════════════════════════
@@ -160,7 +175,7 @@ fn render_content(frame: &mut Frame, area: Rect, app: &App) {
         Screen::Results => render_results_report(frame, chunks[1], app),
         Screen::Help => {
             render_dashboard(frame, chunks[1], app);
-            render_help(frame, frame.area());
+            render_help(frame, frame.area(), &app.theme);
         }
     }
 }
@@ -170,7 +185,7 @@ fn render_content(frame: &mut Frame, area: Rect, app: &App) {
 // ═══════════════════════════════════════════════════════════════════════════
 
 fn render_sidebar(frame: &mut Frame, area: Rect, app: &App) {
-    let t = theme();
+    let t = &app.theme;
 
     // Menu items with icons
     let menu_data: [(& str, &str); 7] = [
@@ -229,7 +244,7 @@ fn render_sidebar(frame: &mut Frame, area: Rect, app: &App) {
 // ═══════════════════════════════════════════════════════════════════════════
 
 fn render_dashboard(frame: &mut Frame, area: Rect, app: &App) {
-    let t = theme();
+    let t = &app.theme;
 
     // Clear and fill the entire dashboard area to prevent artifacts from popups
     frame.render_widget(Clear, area);
@@ -256,39 +271,33 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App) {
         .split(layout[0]);
 
     // Calculate totals for display
-    let total_value: Decimal = app
-        .portfolio
-        .get_items()
-        .iter()
-        .map(|item| calculate_item_value(item, app))
-        .sum();
-
-    let nisab_threshold = if let Some(prices) = &app.prices {
-        prices.gold_per_gram * dec!(85.0)
-    } else {
-        Decimal::ZERO
-    };
+    let stats = app.dashboard_stats();
+    let total_value = stats.total_value;
+    let nisab_threshold = stats.nisab_threshold;
 
     // Stat Card 1: Total Assets
     StatCard::new("Est. Total Assets", &format!("${:.2}", total_value))
+        .theme(t)
         .value_color(t.text_primary)
         .render(frame, stats_layout[0]);
 
     // Stat Card 2: Nisab Threshold
     if app.prices.is_some() {
         StatCard::new("Nisab Threshold", &format!("${:.2}", nisab_threshold))
+            .theme(t)
             .value_color(t.gold)
             .subtitle("Based on gold")
             .render(frame, stats_layout[1]);
     } else {
         StatCard::new("Nisab Threshold", "Loading...")
+            .theme(t)
             .value_color(t.text_muted)
             .render(frame, stats_layout[1]);
     }
 
     // Stat Card 3: Status
     if app.prices.is_some() {
-        let (status, color) = if total_value >= nisab_threshold && total_value > Decimal::ZERO {
+        let (status, color) = if stats.above_nisab {
             ("Likely Payable", t.success)
         } else if total_value > Decimal::ZERO {
             ("Below Nisab", t.text_muted)
@@ -296,10 +305,12 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App) {
             ("No Assets", t.text_muted)
         };
         StatCard::new("Nisab Status", status)
+            .theme(t)
             .value_color(color)
             .render(frame, stats_layout[2]);
     } else {
         StatCard::new("Status", "Waiting...")
+            .theme(t)
             .value_color(t.text_muted)
             .render(frame, stats_layout[2]);
     }
@@ -309,26 +320,12 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App) {
 }
 
 fn calculate_item_value(item: &PortfolioItem, app: &App) -> Decimal {
-    match item {
-        PortfolioItem::Business(b) => {
-            b.cash_on_hand + b.inventory_value + b.receivables - b.total_liabilities()
-        }
-        PortfolioItem::PreciousMetals(pm) => {
-            let price = if pm.metal_type == Some(WealthType::Gold) {
-                app.prices.as_ref().map(|p| p.gold_per_gram).unwrap_or_default()
-            } else {
-                app.prices.as_ref().map(|p| p.silver_per_gram).unwrap_or_default()
-            };
-            pm.weight_grams * price
-        }
-        PortfolioItem::Investment(inv) => inv.value,
-        PortfolioItem::Income(inc) => inc.income,
-        _ => Decimal::ZERO,
-    }
+    // Only an absurdly large entry overflows; show it capped rather than as zero.
+    item.estimated_value(&app.config).unwrap_or(Decimal::MAX)
 }
 
 fn render_portfolio_table(frame: &mut Frame, area: Rect, app: &App) {
-    let t = theme();
+    let t = &app.theme;
     let items = app.portfolio.get_items();
 
     // Empty state
@@ -364,8 +361,8 @@ fn render_portfolio_table(frame: &mut Frame, area: Rect, app: &App) {
         .iter()
         .enumerate()
         .map(|(i, item)| {
-            let label = CalculateZakat::get_label(item).unwrap_or_else(|| format!("Item #{}", i + 1));
-            let (icon, type_color) = get_asset_icon_and_color(item);
+            let label = CalculateZakat::label_or_indexed(item, i);
+            let (icon, type_color) = get_asset_icon_and_color(item, t);
             let value = calculate_item_value(item, app);
 
             let is_selected = matches!(app.screen, Screen::EditAsset(_)) && app.asset_index == i;
@@ -377,7 +374,10 @@ fn render_portfolio_table(frame: &mut Frame, area: Rect, app: &App) {
 
             Row::new(vec![
                 Cell::from(format!(" {} ", icon)),
-                Cell::from(label).style(Style::default().fg(type_color).add_modifier(Modifier::BOLD)),
+                Cell::from(Text::from(vec![
+                    Line::from(Span::styled(label, Style::default().fg(type_color).add_modifier(Modifier::BOLD))),
+                    Line::from(Span::styled(wealth_type_name(item, app), Style::default().fg(t.text_muted))),
+                ])),
                 Cell::from(format!("${:.2}", value)).style(Style::default().fg(t.text_primary)),
             ])
             .style(row_style)
@@ -409,22 +409,37 @@ fn render_portfolio_table(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(table, area);
 }
 
-fn get_asset_icon_and_color(item: &PortfolioItem) -> (&'static str, ratatui::style::Color) {
-    let t = theme();
+/// Wealth type the TUI shows for `item`. Metals without a metal type have no
+/// `WealthType` of their own and are shown as silver.
+fn display_wealth_type(item: &PortfolioItem) -> WealthType {
     match item {
-        PortfolioItem::Business(_) => (icons::BUILDING, t.accent),
-        PortfolioItem::PreciousMetals(pm) => {
-            if pm.metal_type == Some(WealthType::Gold) {
-                (icons::GOLD, t.gold)
-            } else {
-                (icons::SILVER, t.asset_silver())
-            }
-        }
-        PortfolioItem::Investment(_) => (icons::CHART, t.success),
-        PortfolioItem::Agriculture(_) => (icons::GRAIN, t.asset_agriculture()),
-        PortfolioItem::Livestock(_) => (icons::LIVESTOCK, t.warning),
-        PortfolioItem::Income(_) => (icons::CASH, t.accent),
-        _ => (icons::PACKAGE, t.text_muted),
+        PortfolioItem::PreciousMetals(pm) if pm.metal_type.is_none() => WealthType::Silver,
+        _ => item.wealth_type(),
+    }
+}
+
+fn get_asset_icon_and_color(item: &PortfolioItem, t: &Theme) -> (&'static str, ratatui::style::Color) {
+    let wealth_type = display_wealth_type(item);
+    match wealth_type {
+        WealthType::Business => (icons::BUILDING, t.accent),
+        WealthType::Gold => (icons::GOLD, t.gold),
+        WealthType::Silver => (icons::SILVER, t.asset_silver()),
+        WealthType::Investment => (icons::CHART, t.success),
+        WealthType::Income => (icons::CASH, t.accent),
+        _ => match wealth_type_meta(&wealth_type).category {
+            WealthCategory::Agricultural => (icons::GRAIN, t.asset_agriculture()),
+            WealthCategory::Livestock => (icons::LIVESTOCK, t.warning),
+            _ => (icons::PACKAGE, t.text_muted),
+        },
+    }
+}
+
+/// Localized name of the asset's wealth type; named custom types keep their own name.
+fn wealth_type_name(item: &PortfolioItem, app: &App) -> String {
+    let meta = wealth_type_meta(&display_wealth_type(item));
+    match item {
+        PortfolioItem::Custom(c) if !c.wealth_type_name.trim().is_empty() => meta.display_name,
+        _ => app.translator.translate(app.locale(), &meta.display_name_key, None),
     }
 }
 
@@ -432,8 +447,8 @@ fn get_asset_icon_and_color(item: &PortfolioItem) -> (&'static str, ratatui::sty
 // LOADING SCREEN
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn render_loading(frame: &mut Frame, area: Rect, _app: &App) {
-    let t = theme();
+fn render_loading(frame: &mut Frame, area: Rect, app: &App) {
+    let t = &app.theme;
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -449,17 +464,42 @@ fn render_loading(frame: &mut Frame, area: Rect, _app: &App) {
         .as_millis()
         / 200) as usize;
 
-    LoadingSpinner::new("Fetching live market prices...")
+    LoadingSpinner::new("Fetching live market prices... (Esc to continue offline)", t)
         .frame(frame_idx)
         .render(frame, block.inner(area));
 }
 
+/// Due column text: the cash amount, or what to hand over for in-kind Zakat
+fn due_text(details: &ZakatDetails) -> String {
+    if !details.is_in_kind() || !details.is_payable {
+        return format!("${:.2}", details.zakat_due);
+    }
+    match &details.payload {
+        PaymentPayload::Livestock { .. } => details
+            .payload
+            .livestock_description()
+            .unwrap_or_else(|| format!("${:.2}", details.zakat_due)),
+        PaymentPayload::Agriculture { .. } => {
+            let due_kg = details
+                .calculation_breakdown
+                .iter()
+                .find(|step| step.key == "step-crop-due-kg")
+                .and_then(|step| step.amount);
+            match due_kg {
+                Some(kg) => format!("{:.2} kg (${:.2})", kg, details.zakat_due),
+                None => format!("${:.2}", details.zakat_due),
+            }
+        }
+        PaymentPayload::Monetary(amount) => format!("${:.2}", amount),
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // ASSET PICKER (POPUP)
 // ═══════════════════════════════════════════════════════════════════════════
 
 fn render_asset_picker(frame: &mut Frame, area: Rect, app: &App) {
-    let t = theme();
+    let t = &app.theme;
 
     // Compact centered popup
     let popup_area = centered_rect(50, 70, area);
@@ -555,7 +595,7 @@ fn render_asset_picker(frame: &mut Frame, area: Rect, app: &App) {
 // ═══════════════════════════════════════════════════════════════════════════
 
 fn render_asset_form(frame: &mut Frame, area: Rect, app: &App) {
-    let t = theme();
+    let t = &app.theme;
 
     let asset_type_name = match &app.screen {
         Screen::AddAsset(AssetTypeSelection::Business) => "Business Asset",
@@ -744,7 +784,7 @@ fn render_form_field(
     unit: &str,
     is_active: bool,
     input_value: Option<&str>,
-    t: &crate::tui::theme::Theme,
+    t: &Theme,
 ) {
     let label_style = if is_active {
         Style::default().fg(t.gold).add_modifier(Modifier::BOLD)
@@ -799,7 +839,7 @@ fn render_form_field(
 // ═══════════════════════════════════════════════════════════════════════════
 
 fn render_results_report(frame: &mut Frame, area: Rect, app: &App) {
-    let t = theme();
+    let t = &app.theme;
 
     let Some(results) = &app.results else {
         return;
@@ -817,13 +857,17 @@ fn render_results_report(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(block.clone(), area);
     let inner = block.inner(area);
 
+    let warnings = results.all_warnings();
+    let warnings_height = if warnings.is_empty() { 0 } else { warnings.len().min(5) as u16 + 2 };
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
         .constraints([
-            Constraint::Length(5), // Summary Header
-            Constraint::Length(1), // Divider
-            Constraint::Min(0),    // Details Table
+            Constraint::Length(5),               // Summary Header
+            Constraint::Length(1),               // Divider
+            Constraint::Min(0),                  // Details Table
+            Constraint::Length(warnings_height), // Warnings Panel
         ])
         .split(inner);
 
@@ -840,11 +884,13 @@ fn render_results_report(frame: &mut Frame, area: Rect, app: &App) {
     };
 
     StatCard::new("ZAKAT DUE", &format!("${:.2}", results.total_zakat_due))
+        .theme(t)
         .value_color(total_style)
         .subtitle("2.5% of zakatable wealth")
         .render(frame, summary_layout[0]);
 
     StatCard::new("TOTAL WEALTH", &format!("${:.2}", results.total_assets))
+        .theme(t)
         .value_color(t.text_primary)
         .subtitle("All tracked assets")
         .render(frame, summary_layout[1]);
@@ -861,16 +907,28 @@ fn render_results_report(frame: &mut Frame, area: Rect, app: &App) {
                 Row::new(vec![
                     Cell::from(details.label.clone().unwrap_or_else(|| "Unknown".to_string())),
                     Cell::from(status).style(Style::default().fg(color)),
-                    Cell::from(format!("${:.2}", details.zakat_due))
+                    Cell::from(due_text(&details))
                         .style(Style::default().fg(color).add_modifier(Modifier::BOLD)),
                 ])
                 .bottom_margin(1)
             }
-            PortfolioItemResult::Failure { source, .. } => Row::new(vec![
-                Cell::from(source),
-                Cell::from("ERROR").style(Style::default().fg(t.error)),
-                Cell::from("-"),
-            ]),
+            PortfolioItemResult::Failure { source, suggestion, .. } => {
+                let mut asset_lines = vec![Line::from(source)];
+                if let Some(key) = suggestion {
+                    asset_lines.push(Line::from(Span::styled(
+                        format!("↳ {}", app.translator.translate(app.locale(), &key, None)),
+                        Style::default().fg(t.text_muted).add_modifier(Modifier::ITALIC),
+                    )));
+                }
+                let height = asset_lines.len() as u16;
+                Row::new(vec![
+                    Cell::from(Text::from(asset_lines)),
+                    Cell::from("ERROR").style(Style::default().fg(t.error)),
+                    Cell::from("-"),
+                ])
+                .height(height)
+                .bottom_margin(1)
+            }
         })
         .collect();
 
@@ -894,6 +952,26 @@ fn render_results_report(frame: &mut Frame, area: Rect, app: &App) {
     );
 
     frame.render_widget(table, chunks[2]);
+
+    if !warnings.is_empty() {
+        let lines: Vec<Line> = warnings
+            .iter()
+            .map(|(label, message)| {
+                Line::from(vec![
+                    Span::styled(format!("⚠ {}: ", label), Style::default().fg(t.warning)),
+                    Span::styled(message.clone(), Style::default().fg(t.text_primary)),
+                ])
+            })
+            .collect();
+        let panel = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(t.warning))
+                .title(format!(" Warnings ({}) ", warnings.len()))
+                .title_style(Style::default().fg(t.warning)),
+        );
+        frame.render_widget(panel, chunks[3]);
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -901,7 +979,7 @@ fn render_results_report(frame: &mut Frame, area: Rect, app: &App) {
 // ═══════════════════════════════════════════════════════════════════════════
 
 fn render_input_popup(frame: &mut Frame, app: &App) {
-    let t = theme();
+    let t = &app.theme;
 
     let area = centered_rect(50, 20, frame.area());
 
@@ -959,10 +1037,9 @@ fn render_input_popup(frame: &mut Frame, app: &App) {
 // HELP OVERLAY
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn render_help(frame: &mut Frame, area: Rect) {
-    let t = theme();
+fn render_help(frame: &mut Frame, area: Rect, t: &Theme) {
 
-    let popup_area = centered_rect(65, 70, area);
+    let popup_area = centered_rect(65, 80, area);
     frame.render_widget(Clear, popup_area);
 
     let block = Block::default()
@@ -1009,6 +1086,21 @@ fn render_help(frame: &mut Frame, area: Rect) {
             Span::styled("  d/Delete   ", t.accent_style()),
             Span::raw("Delete selected asset"),
         ]),
+        Line::from(vec![
+            Span::styled("  t          ", t.accent_style()),
+            Span::raw("Cycle color theme"),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("QUICK ADD (DASHBOARD)", Style::default().fg(t.gold).add_modifier(Modifier::BOLD | Modifier::UNDERLINED))),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  g / s      ", t.accent_style()),
+            Span::raw("Add gold / silver"),
+        ]),
+        Line::from(vec![
+            Span::styled("  c / b      ", t.accent_style()),
+            Span::raw("Add cash / business"),
+        ]),
         Line::from(""),
         Line::from(Span::styled("SUPPORTED ASSETS", Style::default().fg(t.gold).add_modifier(Modifier::BOLD | Modifier::UNDERLINED))),
         Line::from(""),
@@ -1044,7 +1136,7 @@ fn render_help(frame: &mut Frame, area: Rect) {
 // ═══════════════════════════════════════════════════════════════════════════
 
 fn render_edit_overlay(frame: &mut Frame, area: Rect, app: &App) {
-    let t = theme();
+    let t = &app.theme;
 
     let items = app.portfolio.get_items();
     if items.is_empty() {
@@ -1072,8 +1164,8 @@ fn render_edit_overlay(frame: &mut Frame, area: Rect, app: &App) {
         .iter()
         .enumerate()
         .map(|(i, item)| {
-            let label = CalculateZakat::get_label(item).unwrap_or_else(|| format!("Item #{}", i + 1));
-            let (icon, _type_color) = get_asset_icon_and_color(item);
+            let label = CalculateZakat::label_or_indexed(item, i);
+            let (icon, _type_color) = get_asset_icon_and_color(item, t);
             let is_selected = app.asset_index == i;
 
             let style = if is_selected {
@@ -1120,7 +1212,7 @@ fn render_edit_overlay(frame: &mut Frame, area: Rect, app: &App) {
 // ═══════════════════════════════════════════════════════════════════════════
 
 fn render_status_bar(frame: &mut Frame, area: Rect, app: &App) {
-    let t = theme();
+    let t = &app.theme;
 
     let mode = match app.screen {
         Screen::Main => "DASHBOARD",
@@ -1149,12 +1241,22 @@ fn render_status_bar(frame: &mut Frame, area: Rect, app: &App) {
     };
 
     // Keys hint
-    let keys = Span::styled(
-        " [↑↓] Navigate  [Enter] Select  [?] Help  [Q] Quit ",
-        Style::default().fg(t.text_muted),
-    );
-
-    let bar = Line::from(vec![status, Span::raw(" "), keys]);
+    let keys_hint = match app.screen {
+        Screen::Results => " [↑↓] Navigate  [E] Export  [Esc] Back ",
+        _ => " [↑↓] Navigate  [Enter] Select  [?] Help  [Q] Quit ",
+    };
+    let keys = Span::styled(keys_hint, Style::default().fg(t.text_muted));
+
+    let mut spans = vec![status, Span::raw(" ")];
+    if app.prices_recently_updated() {
+        spans.push(Span::styled(
+            " ⟳ Prices updated ",
+            Style::default().bg(t.gold).fg(t.slate),
+        ));
+        spans.push(Span::raw(" "));
+    }
+    spans.push(keys);
+    let bar = Line::from(spans);
 
     frame.render_widget(
         Paragraph::new(bar).style(t.bg()),
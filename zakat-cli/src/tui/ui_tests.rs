@@ -81,4 +81,121 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_dashboard_values_agriculture_harvest() {
+        use rust_decimal_macros::dec;
+        use zakat_core::prelude::*;
+
+        let mut app = App::new(false);
+        app.portfolio.push(
+            AgricultureAssets::new()
+                .harvest_weight(1000)
+                .price(dec!(2.5))
+                .label("Wheat"),
+        );
+
+        assert_eq!(app.dashboard_stats().total_value, dec!(2500));
+    }
+
+    #[test]
+    fn test_dashboard_values_livestock_by_head() {
+        use rust_decimal_macros::dec;
+        use zakat_core::prelude::*;
+
+        let mut app = App::new(false);
+        app.portfolio.push(
+            LivestockAssets::new()
+                .animal_type(LivestockType::Sheep)
+                .count(40)
+                .prices(LivestockPrices::new().sheep_price(dec!(150)))
+                .label("Flock"),
+        );
+
+        assert_eq!(app.dashboard_stats().total_value, dec!(40) * dec!(150));
+    }
+
+    #[test]
+    fn test_portfolio_table_shows_untyped_metal_as_silver() {
+        use zakat_core::prelude::*;
+
+        let mut app = App::new(false);
+        app.screen = Screen::Main;
+        app.portfolio.push(PreciousMetals::new().weight(100).label("Heirloom"));
+        app.portfolio.push(PreciousMetals::new().weight(100).metal_type(WealthType::Gold).label("Bars"));
+
+        let backend = TestBackend::new(120, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.draw(|f| ui(f, &app)).unwrap();
+
+        let screen: String = terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect();
+        assert_eq!(screen.matches("🥈").count(), 1, "untyped metal should use the silver icon");
+        assert!(screen.contains("Silver"));
+        assert!(screen.contains("Gold"));
+    }
+
+    #[test]
+    fn test_portfolio_table_names_types_in_the_configured_locale() {
+        use zakat_core::prelude::*;
+
+        let mut app = App::new(false);
+        app.screen = Screen::Main;
+        app.config = app.config.clone().with_locale_code("id-ID");
+        app.portfolio.push(PreciousMetals::new().weight(100).label("Heirloom"));
+
+        let backend = TestBackend::new(120, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.draw(|f| ui(f, &app)).unwrap();
+
+        let screen: String = terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect();
+        assert!(screen.contains("Perak"), "type name should follow the id-ID locale");
+    }
+
+    #[test]
+    fn test_results_report_shows_warnings_panel() {
+        use zakat_core::maal::income::IncomeCalculationMethod;
+        use zakat_core::prelude::*;
+
+        let mut app = App::new(false);
+        app.portfolio.push(
+            IncomeZakatCalculator::new()
+                .income(20_000)
+                .expenses(5_000)
+                .method(IncomeCalculationMethod::Gross)
+                .label("Salary")
+                .hawl(true),
+        );
+        let config = ZakatConfig::test_default();
+        app.results = Some(app.portfolio.calculate_total(&config));
+        app.screen = Screen::Results;
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal.draw(|f| ui(f, &app)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let screen: String = (0..40)
+            .map(|y| (0..120).filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string())).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(screen.contains("Warnings (1)"));
+        assert!(screen.contains("Salary: Expenses are ignored"));
+    }
+
+    #[test]
+    fn test_header_shows_price_delta_since_previous_fetch() {
+        use rust_decimal_macros::dec;
+        use zakat_providers::Prices;
+
+        let mut app = App::new(false);
+        app.set_prices(Prices::new(dec!(100), dec!(1)).unwrap());
+        app.set_prices(Prices::new(dec!(102), dec!(1)).unwrap());
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 24)).unwrap();
+        terminal.draw(|f| ui(f, &app)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let header: String = (0..120).filter_map(|x| buffer.cell((x, 0)).map(|c| c.symbol().to_string())).collect();
+        assert!(header.contains("▲2.00%"), "{header}");
+        assert!(!header.contains("▼"), "unchanged silver shows no delta");
+    }
 }
@@ -0,0 +1,65 @@
+//! Calculation benchmarks.
+//!
+//! Run with `cargo bench -p zakat-core --bench calculation`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use zakat_core::assets::PortfolioItem;
+use zakat_core::prelude::*;
+
+const MIXED_ASSET_COUNT: usize = 1_000;
+
+fn mixed_portfolio() -> ZakatPortfolio {
+    let items: Vec<PortfolioItem> = (0..MIXED_ASSET_COUNT)
+        .map(|i| match i % 4 {
+            0 => BusinessZakat::cash_only(1_000 + i as u64).hawl(true).into(),
+            1 => PreciousMetals::gold(10 + (i % 90) as u64).hawl(true).into(),
+            2 => LivestockAssets::new()
+                .count(40 + i as u32)
+                .animal_type(LivestockType::Sheep)
+                .prices(LivestockPrices::new().sheep_price(100))
+                .hawl(true)
+                .into(),
+            _ => AgricultureAssets::new()
+                .harvest_weight(1_000)
+                .price(2)
+                .irrigation(IrrigationMethod::Irrigated)
+                .into(),
+        })
+        .collect();
+    let mut portfolio = ZakatPortfolio::with_capacity(items.len());
+    portfolio.add_many(items);
+    portfolio
+}
+
+fn bench_mixed_portfolio(c: &mut Criterion) {
+    let portfolio = mixed_portfolio();
+    let config = ZakatConfig::test_default();
+
+    c.bench_function("calculate_total_mixed_1k", |b| {
+        b.iter(|| black_box(portfolio.calculate_total(black_box(&config))))
+    });
+}
+
+fn bench_livestock_partition(c: &mut Criterion) {
+    let config = ZakatConfig::default();
+    let prices = LivestockPrices::new().camel_price(1_000).cow_price(500).sheep_price(100);
+    let mut group = c.benchmark_group("livestock_huge_counts");
+
+    for animal in [LivestockType::Camel, LivestockType::Cow, LivestockType::Sheep] {
+        for count in [1_000u32, 1_000_000, 1_000_000_000] {
+            let herd = LivestockAssets::new()
+                .count(count)
+                .animal_type(animal)
+                .prices(prices)
+                .hawl(true);
+            group.bench_with_input(BenchmarkId::new(format!("{:?}", animal), count), &herd, |b, herd| {
+                b.iter(|| black_box(herd.calculate_zakat(&config)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_mixed_portfolio, bench_livestock_partition);
+criterion_main!(benches);
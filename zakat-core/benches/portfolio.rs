@@ -0,0 +1,50 @@
+//! Portfolio construction benchmarks.
+//!
+//! Run with `cargo bench -p zakat-core`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use zakat_core::assets::PortfolioItem;
+use zakat_core::prelude::*;
+
+const ASSET_COUNT: usize = 10_000;
+
+fn cash_assets() -> Vec<PortfolioItem> {
+    (0..ASSET_COUNT)
+        .map(|i| BusinessZakat::cash_only(i as u64).into())
+        .collect()
+}
+
+fn bench_bulk_add(c: &mut Criterion) {
+    let mut group = c.benchmark_group("portfolio_add_10k");
+
+    group.bench_function("builder_chain", |b| {
+        b.iter_batched(
+            cash_assets,
+            |assets| {
+                let mut portfolio = ZakatPortfolio::new();
+                for asset in assets {
+                    portfolio = portfolio.add(asset);
+                }
+                black_box(portfolio)
+            },
+            criterion::BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("add_many", |b| {
+        b.iter_batched(
+            cash_assets,
+            |assets| {
+                let mut portfolio = ZakatPortfolio::with_capacity(assets.len());
+                portfolio.add_many(assets);
+                black_box(portfolio)
+            },
+            criterion::BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_bulk_add);
+criterion_main!(benches);
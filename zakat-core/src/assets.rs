@@ -5,7 +5,7 @@
 
 use serde::{Serialize, Deserialize};
 use crate::types::{ZakatDetails, ZakatError};
-use crate::traits::{CalculateZakat, ZakatConfigArgument};
+use crate::traits::{CalculateZakat, DynCalculateZakat, ZakatConfigArgument};
 
 use crate::maal::business::BusinessZakat;
 use crate::maal::income::IncomeZakatCalculator;
@@ -80,7 +80,8 @@ impl CalculateZakat for CustomAsset {
                 self.nisab_threshold, 
                 wealth_type, 
                 "Hawl (1 lunar year) not met"
-            ).with_label(self.label.clone()));
+            ).with_status_reason_key("reason-hawl-not-met")
+            .with_label(self.label.clone()));
         }
 
         // Custom logic: simple Value * Rate check against Threshold
@@ -106,6 +107,10 @@ impl CalculateZakat for CustomAsset {
     fn get_id(&self) -> Uuid {
         self.id
     }
+
+    fn zakat_rate(&self, _config: &crate::config::ZakatConfig) -> Decimal {
+        self.rate
+    }
 }
 
 /// A wrapper enum for all zakatable asset types.
@@ -167,6 +172,34 @@ impl CalculateZakat for PortfolioItem {
         }
     }
 
+    fn preflight(&self, config: &crate::config::ZakatConfig) -> Result<(), ZakatError> {
+        match self {
+            PortfolioItem::Business(asset) => asset.preflight(config),
+            PortfolioItem::Income(asset) => asset.preflight(config),
+            PortfolioItem::Livestock(asset) => asset.preflight(config),
+            PortfolioItem::Agriculture(asset) => asset.preflight(config),
+            PortfolioItem::Investment(asset) => asset.preflight(config),
+            PortfolioItem::Mining(asset) => asset.preflight(config),
+            PortfolioItem::PreciousMetals(asset) => asset.preflight(config),
+            PortfolioItem::Fitrah(asset) => asset.preflight(config),
+            PortfolioItem::Custom(asset) => asset.preflight(config),
+        }
+    }
+
+    fn validate_input(&self) -> Result<(), ZakatError> {
+        match self {
+            PortfolioItem::Business(asset) => asset.validate_input(),
+            PortfolioItem::Income(asset) => asset.validate_input(),
+            PortfolioItem::Livestock(asset) => asset.validate_input(),
+            PortfolioItem::Agriculture(asset) => asset.validate_input(),
+            PortfolioItem::Investment(asset) => asset.validate_input(),
+            PortfolioItem::Mining(asset) => asset.validate_input(),
+            PortfolioItem::PreciousMetals(asset) => asset.validate_input(),
+            PortfolioItem::Fitrah(asset) => asset.validate_input(),
+            PortfolioItem::Custom(asset) => asset.validate_input(),
+        }
+    }
+
     fn get_label(&self) -> Option<String> {
         match self {
             PortfolioItem::Business(asset) => asset.get_label(),
@@ -194,6 +227,287 @@ impl CalculateZakat for PortfolioItem {
             PortfolioItem::Custom(asset) => asset.get_id(),
         }
     }
+
+    fn requires_prices(&self) -> bool {
+        match self {
+            PortfolioItem::Business(asset) => asset.requires_prices(),
+            PortfolioItem::Income(asset) => asset.requires_prices(),
+            PortfolioItem::Livestock(asset) => asset.requires_prices(),
+            PortfolioItem::Agriculture(asset) => asset.requires_prices(),
+            PortfolioItem::Investment(asset) => asset.requires_prices(),
+            PortfolioItem::Mining(asset) => asset.requires_prices(),
+            PortfolioItem::PreciousMetals(asset) => asset.requires_prices(),
+            PortfolioItem::Fitrah(asset) => asset.requires_prices(),
+            PortfolioItem::Custom(asset) => asset.requires_prices(),
+        }
+    }
+
+    fn zakat_rate(&self, config: &crate::config::ZakatConfig) -> Decimal {
+        match self {
+            PortfolioItem::Business(asset) => asset.zakat_rate(config),
+            PortfolioItem::Income(asset) => asset.zakat_rate(config),
+            PortfolioItem::Livestock(asset) => asset.zakat_rate(config),
+            PortfolioItem::Agriculture(asset) => asset.zakat_rate(config),
+            PortfolioItem::Investment(asset) => asset.zakat_rate(config),
+            PortfolioItem::Mining(asset) => asset.zakat_rate(config),
+            PortfolioItem::PreciousMetals(asset) => asset.zakat_rate(config),
+            PortfolioItem::Fitrah(asset) => asset.zakat_rate(config),
+            PortfolioItem::Custom(asset) => asset.zakat_rate(config),
+        }
+    }
+}
+
+impl PortfolioItem {
+    /// Returns a rough market value for display and sorting purposes.
+    ///
+    /// This is not a Zakat calculation: it skips Hawl, Nisab, and purity rules.
+    /// Business debts are the ones a calculation would deduct under the config's
+    /// debt policy, so the business value matches its calculated net assets.
+    /// Metals are valued at the config's spot prices and livestock at count × the
+    /// per-head price of its animal type; variants without a currency value
+    /// (e.g. Fitrah) report zero.
+    ///
+    /// # Errors
+    /// Returns `ZakatError::Overflow` if a value exceeds the `Decimal` range.
+    #[allow(deprecated)] // Includes deprecated `receivables` for backward compat
+    pub fn estimated_value(&self, config: &crate::config::ZakatConfig) -> Result<Decimal, ZakatError> {
+        use crate::math::ZakatDecimal;
+        let product = |a: Decimal, b: Decimal| {
+            ZakatDecimal::new(a)
+                .with_context("PortfolioItem::estimated_value")
+                .checked_mul(b)
+                .map(Decimal::from)
+        };
+        match self {
+            PortfolioItem::Business(b) => ZakatDecimal::new(b.cash_on_hand)
+                .with_context("PortfolioItem::estimated_value")
+                .checked_add(b.inventory_value)?
+                .checked_add(b.receivables)?
+                .checked_sub(b.checked_liabilities_under(config.debt_policy, config.as_of_date())?)
+                .map(Decimal::from),
+            PortfolioItem::PreciousMetals(pm) => {
+                let price = if pm.metal_type == Some(crate::types::WealthType::Gold) {
+                    config.gold_price_per_gram
+                } else {
+                    config.silver_price_per_gram
+                };
+                product(pm.weight_grams, price)
+            }
+            PortfolioItem::Investment(inv) => ZakatDecimal::new(inv.value)
+                .with_context("PortfolioItem::estimated_value")
+                .checked_add(inv.dividends_received)
+                .map(Decimal::from),
+            PortfolioItem::Income(inc) => Ok(inc.income),
+            PortfolioItem::Mining(m) => Ok(m.value),
+            PortfolioItem::Agriculture(a) => product(a.harvest_weight_kg, a.price_per_kg),
+            PortfolioItem::Livestock(l) => l.animal_type
+                .map_or(Ok(Decimal::ZERO), |animal| product(Decimal::from(l.count), l.prices.price_of(animal))),
+            PortfolioItem::Custom(c) => Ok(c.value),
+            _ => Ok(Decimal::ZERO),
+        }
+    }
+
+    /// Returns a copy with all amounts, weights and counts zeroed.
+    ///
+    /// The id, label, variant and non-financial settings (purity, irrigation,
+    /// calculation method, custom rates, ...) are kept so the item can be refilled.
+    #[allow(deprecated)] // Clears deprecated `receivables`/`liabilities_due_now` too
+    pub fn to_template(&self) -> PortfolioItem {
+        let mut item = self.clone();
+        match &mut item {
+            PortfolioItem::Business(b) => {
+                b.cash_on_hand = Decimal::ZERO;
+                b.inventory_value = Decimal::ZERO;
+                b.receivables = Decimal::ZERO;
+                b.receivables_list.clear();
+                b.liabilities_due_now = Decimal::ZERO;
+                b.named_liabilities.clear();
+            }
+            PortfolioItem::Income(inc) => {
+                inc.income = Decimal::ZERO;
+                inc.expenses = Decimal::ZERO;
+                inc.liabilities_due_now = Decimal::ZERO;
+                inc.named_liabilities.clear();
+            }
+            PortfolioItem::Livestock(l) => {
+                l.count = 0;
+                l.prices = Default::default();
+                l.liabilities_due_now = Decimal::ZERO;
+            }
+            PortfolioItem::Agriculture(a) => {
+                a.harvest_weight_kg = Decimal::ZERO;
+                a.price_per_kg = Decimal::ZERO;
+                a.cultivation_costs = Decimal::ZERO;
+                a.liabilities_due_now = Decimal::ZERO;
+            }
+            PortfolioItem::Investment(inv) => {
+                inv.value = Decimal::ZERO;
+                inv.dividends_received = Decimal::ZERO;
+                inv.liabilities_due_now = Decimal::ZERO;
+                inv.named_liabilities.clear();
+            }
+            PortfolioItem::Mining(m) => {
+                m.value = Decimal::ZERO;
+                m.liabilities_due_now = Decimal::ZERO;
+                m.named_liabilities.clear();
+            }
+            PortfolioItem::PreciousMetals(pm) => {
+                pm.weight_grams = Decimal::ZERO;
+                pm.stone_weight_grams = Decimal::ZERO;
+                pm.numismatic_value = None;
+                pm.liabilities_due_now = Decimal::ZERO;
+                pm.named_liabilities.clear();
+            }
+            PortfolioItem::Fitrah(f) => {
+                f.price_per_unit = Decimal::ZERO;
+            }
+            PortfolioItem::Custom(c) => {
+                c.value = Decimal::ZERO;
+            }
+        }
+        item
+    }
+
+    /// The wealth type this asset is calculated as, for display via
+    /// [`crate::types::wealth_type_meta`]. Metals without a metal type report
+    /// `Other("Precious Metals")`.
+    pub fn wealth_type(&self) -> crate::types::WealthType {
+        use crate::types::WealthType;
+        match self {
+            PortfolioItem::Business(_) => WealthType::Business,
+            PortfolioItem::Income(_) => WealthType::Income,
+            PortfolioItem::Livestock(_) => WealthType::Livestock,
+            PortfolioItem::Agriculture(_) => WealthType::Agriculture,
+            PortfolioItem::Investment(_) => WealthType::Investment,
+            PortfolioItem::Mining(m) => match m.mining_type {
+                crate::maal::mining::MiningType::Rikaz => WealthType::Rikaz,
+                crate::maal::mining::MiningType::Mines => WealthType::Mining,
+            },
+            PortfolioItem::PreciousMetals(pm) => pm.metal_type.clone()
+                .unwrap_or_else(|| WealthType::Other("Precious Metals".to_string())),
+            PortfolioItem::Fitrah(_) => WealthType::Fitrah,
+            PortfolioItem::Custom(c) => WealthType::Other(c.wealth_type_name.clone()),
+        }
+    }
+
+    /// Position of the variant in declaration order, used for grouping by type.
+    pub(crate) fn type_rank(&self) -> u8 {
+        match self {
+            PortfolioItem::Business(_) => 0,
+            PortfolioItem::Income(_) => 1,
+            PortfolioItem::Livestock(_) => 2,
+            PortfolioItem::Agriculture(_) => 3,
+            PortfolioItem::Investment(_) => 4,
+            PortfolioItem::Mining(_) => 5,
+            PortfolioItem::PreciousMetals(_) => 6,
+            PortfolioItem::Fitrah(_) => 7,
+            PortfolioItem::Custom(_) => 8,
+        }
+    }
+
+    /// Dispatches to the [`AssetVisitor`] method for this item's variant.
+    pub fn visit<V: AssetVisitor>(&self, visitor: &mut V) {
+        match self {
+            PortfolioItem::Business(asset) => visitor.visit_business(asset),
+            PortfolioItem::Income(asset) => visitor.visit_income(asset),
+            PortfolioItem::Livestock(asset) => visitor.visit_livestock(asset),
+            PortfolioItem::Agriculture(asset) => visitor.visit_agriculture(asset),
+            PortfolioItem::Investment(asset) => visitor.visit_investment(asset),
+            PortfolioItem::Mining(asset) => visitor.visit_mining(asset),
+            PortfolioItem::PreciousMetals(asset) => visitor.visit_precious_metals(asset),
+            PortfolioItem::Fitrah(asset) => visitor.visit_fitrah(asset),
+            PortfolioItem::Custom(asset) => visitor.visit_custom(asset),
+        }
+    }
+}
+
+/// Per-variant callbacks for traversing [`PortfolioItem`]s without matching the enum.
+///
+/// Every method defaults to [`AssetVisitor::visit_asset`], so a visitor only
+/// overrides the variants it cares about and keeps compiling as variants are added.
+pub trait AssetVisitor {
+    /// Fallback for any variant without a specific override.
+    fn visit_asset(&mut self, _asset: &dyn DynCalculateZakat) {}
+
+    fn visit_business(&mut self, asset: &BusinessZakat) { self.visit_asset(asset) }
+    fn visit_income(&mut self, asset: &IncomeZakatCalculator) { self.visit_asset(asset) }
+    fn visit_livestock(&mut self, asset: &LivestockAssets) { self.visit_asset(asset) }
+    fn visit_agriculture(&mut self, asset: &AgricultureAssets) { self.visit_asset(asset) }
+    fn visit_investment(&mut self, asset: &InvestmentAssets) { self.visit_asset(asset) }
+    fn visit_mining(&mut self, asset: &MiningAssets) { self.visit_asset(asset) }
+    fn visit_precious_metals(&mut self, asset: &PreciousMetals) { self.visit_asset(asset) }
+    fn visit_fitrah(&mut self, asset: &FitrahCalculator) { self.visit_asset(asset) }
+    fn visit_custom(&mut self, asset: &CustomAsset) { self.visit_asset(asset) }
+}
+
+/// Required `data` keys per `type` tag, checked before deserializing JSON and
+/// CSV rows alike.
+///
+/// Asset structs default missing fields (often to zero), so without this a
+/// typo'd key would silently calculate as an empty asset. Only fields with no
+/// meaningful default are listed: a business may hold just inventory, and a
+/// harvest is zakated in kind without a price.
+const REQUIRED_FIELDS: &[(&str, &[&str])] = &[
+    ("business", &[]),
+    ("income", &["income"]),
+    ("livestock", &["count", "animalType"]),
+    ("agriculture", &["harvestWeightKg"]),
+    ("investment", &["value"]),
+    ("mining", &["value"]),
+    ("preciousMetals", &["weightGrams", "metalType"]),
+    ("fitrah", &["personCount", "pricePerUnit"]),
+    ("custom", &["value", "rate", "nisabThreshold"]),
+];
+
+/// The `data` keys a `type` tag requires, or `None` for an unknown tag.
+pub(crate) fn required_fields(tag: &str) -> Option<&'static [&'static str]> {
+    REQUIRED_FIELDS.iter()
+        .find(|(name, _)| *name == tag)
+        .map(|(_, fields)| *fields)
+}
+
+fn json_input_error(field: &str, value: impl Into<String>, reason_key: &str, suggestion: String) -> ZakatError {
+    ZakatError::InvalidInput(Box::new(crate::types::InvalidInputDetails {
+        field: field.to_string(),
+        value: value.into(),
+        reason_key: reason_key.to_string(),
+        args: Some(std::collections::HashMap::from([("field".to_string(), field.to_string())])),
+        suggestion: Some(suggestion),
+        ..Default::default()
+    }))
+}
+
+impl TryFrom<&serde_json::Value> for PortfolioItem {
+    type Error = ZakatError;
+
+    /// Builds an item from `{"type": "...", "data": {...}}` JSON, naming the
+    /// offending field instead of returning a generic serde message.
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        let tag = value.get("type").and_then(|t| t.as_str()).ok_or_else(|| {
+            json_input_error("type", value.get("type").map(|t| t.to_string()).unwrap_or_default(),
+                "error-type-required",
+                "Add a \"type\" string such as \"preciousMetals\" or \"business\".".to_string())
+        })?;
+        let required = required_fields(tag)
+            .ok_or_else(|| {
+                let known: Vec<&str> = REQUIRED_FIELDS.iter().map(|(name, _)| *name).collect();
+                json_input_error("type", tag, "error-type-invalid",
+                    format!("Use one of: {}.", known.join(", ")))
+            })?;
+        let data = value.get("data").filter(|d| d.is_object()).ok_or_else(|| {
+            json_input_error("data", "", "error-field-missing",
+                "Put the asset fields in a \"data\" object.".to_string())
+        })?;
+        if let Some(missing) = required.iter().find(|field| data.get(**field).is_none_or(|v| v.is_null())) {
+            return Err(json_input_error(missing, "", "error-field-missing",
+                format!("Add \"{}\" to the {} data.", missing, tag)));
+        }
+
+        serde_json::from_value(value.clone()).map_err(|e| {
+            json_input_error("data", e.to_string(), "error-parse-json",
+                format!("Check the field types of the {} data.", tag))
+        })
+    }
 }
 
 // Implement From<T> for each variant to simplify API usage
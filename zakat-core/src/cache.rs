@@ -0,0 +1,177 @@
+//! # Result Cache
+//!
+//! Memoizes portfolio calculations for servers that recalculate the same inputs
+//! repeatedly. Results are keyed by [`ZakatPortfolio::checksum`],
+//! [`ZakatConfig::checksum`] and [`ZakatConfig::as_of_date`], so any edit to an
+//! asset, group, Madhab override or setting is a cache miss.
+//!
+//! Enabled with the `result-cache` feature.
+//!
+//! The date is part of the key because results depend on it: Hawl derived
+//! from an `acquisition_date`, and which dated debts fall due within the coming
+//! lunar year. Without a `calculation_date` that date is today, so a result is
+//! never served on a later day; entries from earlier days simply age out of the LRU.
+
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+use chrono::NaiveDate;
+use lru::LruCache;
+
+use crate::config::ZakatConfig;
+use crate::portfolio::{PortfolioResult, ZakatPortfolio};
+
+/// Cache capacity used by [`ZakatCalculator::default`].
+pub const DEFAULT_CACHE_CAPACITY: usize = 128;
+
+/// Portfolio checksum, config checksum and the date the calculation is made as of.
+type CacheKey = (u64, u64, NaiveDate);
+
+/// Calculates portfolios, serving repeated (portfolio, config) pairs from an LRU cache.
+///
+/// The cache sits behind a mutex, so one calculator can be shared across threads.
+#[derive(Debug)]
+pub struct ZakatCalculator {
+    cache: Mutex<LruCache<CacheKey, PortfolioResult>>,
+}
+
+impl ZakatCalculator {
+    /// Creates a calculator keeping up to `capacity` results (at least one).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            cache: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Returns the cached result for these inputs, calculating it on a miss.
+    pub fn calculate_total(&self, portfolio: &ZakatPortfolio, config: &ZakatConfig) -> PortfolioResult {
+        let key = Self::key(portfolio, config);
+        if let Some(hit) = self.lock().get(&key) {
+            return hit.clone();
+        }
+
+        // Calculate without holding the lock so other threads are not blocked.
+        let result = portfolio.calculate_total(config);
+        self.lock().put(key, result.clone());
+        result
+    }
+
+    /// Number of cached results.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether no results are cached.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Drops every cached result.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn key(portfolio: &ZakatPortfolio, config: &ZakatConfig) -> CacheKey {
+        (portfolio.checksum(), config.checksum(), config.as_of_date())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruCache<CacheKey, PortfolioResult>> {
+        // A panic mid-insert cannot leave the LRU in a broken state, so recover from poisoning.
+        self.cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for ZakatCalculator {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maal::business::BusinessZakat;
+    use crate::traits::CalculationObserver;
+    use crate::types::{CalculationStep, ZakatError};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default)]
+    struct StepCounter(AtomicUsize);
+
+    impl CalculationObserver for StepCounter {
+        fn on_step(&self, _step: &CalculationStep) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_error(&self, _error: &ZakatError) {}
+    }
+
+    #[test]
+    fn test_repeated_inputs_are_served_from_cache() {
+        let counter = Arc::new(StepCounter::default());
+        let config = ZakatConfig::test_default().with_observer(counter.clone());
+        let portfolio = ZakatPortfolio::new().add(BusinessZakat::cash_only(10_000).hawl(true));
+        let calculator = ZakatCalculator::new(4);
+
+        let first = calculator.calculate_total(&portfolio, &config);
+        let steps_after_first = counter.0.load(Ordering::SeqCst);
+        assert!(steps_after_first > 0, "The first call should calculate");
+
+        let second = calculator.calculate_total(&portfolio, &config);
+        assert_eq!(counter.0.load(Ordering::SeqCst), steps_after_first, "The second call should be a cache hit");
+        assert_eq!(second.total_zakat_due, first.total_zakat_due);
+        assert_eq!(calculator.len(), 1);
+
+        let edited = ZakatPortfolio::new().add(BusinessZakat::cash_only(20_000).hawl(true));
+        calculator.calculate_total(&edited, &config);
+        assert!(counter.0.load(Ordering::SeqCst) > steps_after_first, "Different inputs should miss");
+        assert_eq!(calculator.len(), 2);
+    }
+
+    #[test]
+    fn test_madhab_override_is_a_cache_miss() {
+        use crate::madhab::Madhab;
+        use crate::maal::precious_metals::{JewelryUsage, PreciousMetals};
+
+        let config = ZakatConfig::test_default().with_madhab(Madhab::Hanafi);
+        let mut portfolio = ZakatPortfolio::new();
+        let jewelry = portfolio.push(PreciousMetals::gold(100).usage(JewelryUsage::PersonalUse).hawl(true));
+        let calculator = ZakatCalculator::new(4);
+        assert_eq!(calculator.calculate_total(&portfolio, &config).total_zakat_due, rust_decimal_macros::dec!(212.5));
+
+        portfolio.set_madhab_override(jewelry, Madhab::Shafi).unwrap();
+        let cached = calculator.calculate_total(&portfolio, &config);
+        assert_eq!(cached.total_zakat_due, portfolio.calculate_total(&config).total_zakat_due);
+        assert!(cached.total_zakat_due.is_zero(), "The Shafi'i override exempts personal jewelry");
+        assert_eq!(calculator.len(), 2);
+    }
+
+    #[test]
+    fn test_results_are_keyed_by_calculation_date() {
+        let config = ZakatConfig::test_default();
+        let portfolio = ZakatPortfolio::new().add(BusinessZakat::cash_only(10_000).hawl(true));
+        let calculator = ZakatCalculator::new(4);
+        calculator.calculate_total(&portfolio, &config);
+
+        let today = chrono::Local::now().date_naive();
+        let (portfolio_sum, config_sum, date) = ZakatCalculator::key(&portfolio, &config);
+        assert_eq!(date, today, "An undated config is calculated as of today");
+        let cache = calculator.lock();
+        assert!(cache.contains(&(portfolio_sum, config_sum, today)));
+        assert!(
+            !cache.contains(&(portfolio_sum, config_sum, today + chrono::Duration::days(1))),
+            "Tomorrow's lookup must not reuse today's result"
+        );
+    }
+
+    #[test]
+    fn test_dated_config_is_keyed_by_its_own_date() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let config = ZakatConfig::test_default().with_calculation_date(date);
+        let portfolio = ZakatPortfolio::new().add(BusinessZakat::cash_only(10_000).hawl(true));
+
+        assert_eq!(ZakatCalculator::key(&portfolio, &config).2, date);
+    }
+}
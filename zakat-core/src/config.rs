@@ -9,12 +9,17 @@ use serde::{Deserialize, Serialize};
 use std::env;
 use std::fs;
 use std::sync::Arc;
-use crate::types::{ZakatError, ErrorDetails};
+use crate::types::{ZakatError, ErrorDetails, CalculationStep, WealthType};
 use crate::inputs::IntoZakatDecimal;
 use tracing::{instrument, debug};
 
 use crate::madhab::{Madhab, NisabStandard, ZakatStrategy};
 
+/// Gold/silver price ratios outside this range are treated as likely data-entry
+/// errors by [`ZakatConfig::sanity_check`]. Modern markets have stayed roughly
+/// within 30:1 to 125:1; the bounds are deliberately loose.
+const PLAUSIBLE_GOLD_SILVER_RATIO: (Decimal, Decimal) = (dec!(10), dec!(200));
+
 /// Controls validation strictness for Fiqh compliance.
 ///
 /// This mode affects how the library handles missing data, edge cases,
@@ -36,12 +41,116 @@ pub enum ZakatMode {
     Permissive,
 }
 
+/// Common gold Nisab weights.
+///
+/// - `Standard85g`: 20 mithqal at ~4.25g each, as used by Yusuf al-Qaradawi
+///   (*Fiqh al-Zakah*) and AAOIFI. This is the library default.
+/// - `Tola87_48g`: 7.5 tola at 11.664g each, the customary reckoning in South Asia
+///   (e.g. Darul Uloom Deoband fatawa).
+/// - `Custom`: any other weight in grams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum NisabPreset {
+    #[default]
+    Standard85g,
+    Tola87_48g,
+    Custom(Decimal),
+}
+
+impl NisabPreset {
+    /// Returns the gold Nisab in grams for this preset.
+    pub fn gold_grams(&self) -> Decimal {
+        match self {
+            NisabPreset::Standard85g => dec!(85),
+            NisabPreset::Tola87_48g => dec!(87.48),
+            NisabPreset::Custom(grams) => *grams,
+        }
+    }
+}
+
+/// How a Zakat due is rounded at its midpoint or beyond the kept decimals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[typeshare::typeshare]
+#[serde(rename_all = "camelCase")]
+pub enum RoundingMode {
+    /// 0.125 -> 0.13 (commercial rounding).
+    #[default]
+    HalfUp,
+    /// 0.125 -> 0.12 (banker's rounding).
+    HalfEven,
+    /// Always away from zero, so the recipient is never short-changed.
+    Up,
+    /// Always towards zero.
+    Down,
+}
+
+/// Decimal places and mode used to round Zakat dues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[typeshare::typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct RoundingRule {
+    pub decimals: u32,
+    #[serde(default)]
+    pub mode: RoundingMode,
+}
+
+impl RoundingRule {
+    pub fn new(decimals: u32, mode: RoundingMode) -> Self {
+        Self { decimals, mode }
+    }
+
+    /// Rounds `amount` to this rule's decimal places.
+    pub fn apply(&self, amount: Decimal) -> Decimal {
+        use rust_decimal::RoundingStrategy;
+        let strategy = match self.mode {
+            RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+            RoundingMode::Up => RoundingStrategy::AwayFromZero,
+            RoundingMode::Down => RoundingStrategy::ToZero,
+        };
+        amount.round_dp_with_strategy(self.decimals, strategy)
+    }
+}
+
+/// A rounding rule that applies to one wealth type only.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[typeshare::typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct RoundingOverride {
+    pub wealth_type: crate::types::WealthType,
+    pub rule: RoundingRule,
+}
+
 /// Default strategy for serde deserialization.
 fn default_strategy() -> Arc<dyn ZakatStrategy> {
     Arc::new(Madhab::default())
 }
 
 
+/// Builder values recorded so [`ZakatConfig::overlay`] can tell "unset" from "default".
+///
+/// Only fields without their own `Option` need tracking here. A `None` means no
+/// builder set the field; `overlay` then still honours a value that differs from
+/// the default, e.g. one deserialized or assigned directly.
+///
+/// Public only so `ZakatConfig { .., ..Default::default() }` keeps compiling
+/// outside this crate; its contents are set through the `with_*` builders.
+#[doc(hidden)]
+#[derive(Debug, Clone, Default)]
+pub struct ExplicitFields {
+    madhab: Option<Madhab>,
+    strategy: Option<Arc<dyn ZakatStrategy>>,
+    cash_nisab_standard: Option<NisabStandard>,
+    locale_code: Option<String>,
+    currency_code: Option<String>,
+    mode: Option<ZakatMode>,
+    include_income_in_combination: Option<bool>,
+    combine_monetary_assets: Option<bool>,
+    debt_policy: Option<crate::types::DebtDeductionPolicy>,
+}
+
 /// Networking configuration for external API calls
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -115,6 +224,57 @@ pub struct ZakatConfig {
     #[typeshare(serialized_as = "Option<string>")]
     pub nisab_agriculture_kg: Option<Decimal>,
 
+    /// Gold:silver price ratio used to estimate a missing silver price.
+    /// `Some` means `silver_price_per_gram` was derived rather than quoted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[typeshare(serialized_as = "Option<string>")]
+    pub gold_silver_ratio: Option<Decimal>,
+
+    /// Metal weights (grams) below this are flagged as a likely data-entry error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[typeshare(serialized_as = "Option<string>")]
+    pub min_metal_weight_grams: Option<Decimal>,
+
+    /// Personal jewelry above this weight (grams) is zakated even where the
+    /// Madhab exempts personal-use jewelry. Only the excess is zakatable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[typeshare(serialized_as = "Option<string>")]
+    pub jewelry_luxury_threshold_grams: Option<Decimal>,
+
+    /// Whether `Income` results join the combined monetary pool (Dam' al-Amwal).
+    /// Off by default: income already sitting in a cash account would be counted twice.
+    #[serde(default)]
+    pub include_income_in_combination: bool,
+
+    /// Whether monetary results are pooled against one Nisab (Dam' al-Amwal).
+    /// On by default; in-kind wealth (livestock, crops) never joins the pool.
+    #[serde(default = "default_combine_monetary_assets")]
+    pub combine_monetary_assets: bool,
+
+    /// How much of a long-term debt is deducted (see [`crate::types::DebtDeductionPolicy`]).
+    #[serde(default)]
+    pub debt_policy: crate::types::DebtDeductionPolicy,
+
+    /// Positive dues below this amount are rounded up to it (e.g. 1.00).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[typeshare(serialized_as = "Option<string>")]
+    pub minimum_payment: Option<Decimal>,
+
+    /// Rounding applied to monetary dues. `None` keeps full precision.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rounding: Option<RoundingRule>,
+
+    /// Date the calculation is made as of; `None` means today.
+    /// Dated debts are deducted only if due within one lunar year of it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[typeshare(serialized_as = "Option<string>")]
+    pub calculation_date: Option<chrono::NaiveDate>,
+
+    /// Per-wealth-type rounding that takes precedence over `rounding`.
+    /// Kept as a list because `WealthType::Other` cannot be a JSON object key.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rounding_overrides: Vec<RoundingOverride>,
+
     /// Locale code for output formatting (e.g., "en-US", "ar-SA").
     /// Use `zakat-i18n` crate for full i18n support.
     #[serde(default = "default_locale_code")]
@@ -124,6 +284,11 @@ pub struct ZakatConfig {
     #[serde(default = "default_currency_code")]
     pub currency_code: String,
 
+    /// Currency the gold and silver prices are quoted in, if not `currency_code`.
+    /// A mismatch fails validation until [`ZakatConfig::convert_prices`] is applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_currency: Option<String>,
+
     /// Network configuration for external API calls.
     #[serde(default)]
     pub networking: NetworkConfig,
@@ -137,6 +302,12 @@ pub struct ZakatConfig {
     #[serde(skip, default = "default_observer")]
     #[typeshare(skip)]
     pub observer: Arc<dyn crate::traits::CalculationObserver>,
+
+    /// Fields set through the builder, consulted by [`ZakatConfig::overlay`].
+    #[doc(hidden)]
+    #[serde(skip)]
+    #[typeshare(skip)]
+    pub explicit: ExplicitFields,
 }
 
 fn default_observer() -> Arc<dyn crate::traits::CalculationObserver> {
@@ -147,6 +318,10 @@ fn default_locale_code() -> String {
     "en-US".to_string()
 }
 
+fn default_combine_monetary_assets() -> bool {
+    true
+}
+
 fn default_currency_code() -> String {
     "USD".to_string()
 }
@@ -167,6 +342,16 @@ impl std::fmt::Debug for ZakatConfig {
     }
 }
 
+/// An unpriced baseline, meant to be filled in with builders or [`ZakatConfig::merge`].
+///
+/// - Madhab: Hanafi, with the Gold cash Nisab standard (85g). Use
+///   [`ZakatConfig::hanafi`] for the Hanafi `LowerOfTwo` preset.
+/// - Prices: gold and silver are **zero**, so `validate()` fails and every
+///   price-based Nisab is zero. Debug builds log a warning when a portfolio is
+///   calculated against such a config, or an asset is calculated with `()` as
+///   its config. Use [`ZakatConfig::test_default`] for demo prices.
+/// - Locale `en-US`, currency `USD`, `Strict` mode, monetary assets combined,
+///   income not combined, and no rounding, minimum payment or overrides.
 impl Default for ZakatConfig {
     fn default() -> Self {
         ZakatConfig {
@@ -180,11 +365,23 @@ impl Default for ZakatConfig {
             nisab_gold_grams: None,
             nisab_silver_grams: None,
             nisab_agriculture_kg: None,
+            gold_silver_ratio: None,
+            min_metal_weight_grams: None,
+            jewelry_luxury_threshold_grams: None,
+            minimum_payment: None,
+            rounding: None,
+            rounding_overrides: Vec::new(),
+            calculation_date: None,
+            include_income_in_combination: false,
+            combine_monetary_assets: true,
+            debt_policy: Default::default(),
             locale_code: default_locale_code(),
             currency_code: default_currency_code(),
+            price_currency: None,
             networking: NetworkConfig::default(),
             mode: ZakatMode::default(),
             observer: default_observer(),
+            explicit: ExplicitFields::default(),
         }
     }
 }
@@ -371,6 +568,14 @@ impl ZakatConfig {
         Ok(self)
     }
 
+    /// Returns a fingerprint of every setting that affects results, for cache keys.
+    ///
+    /// Custom strategies are not serialized, so the active strategy's rules are
+    /// hashed alongside the config. The observer is not part of the checksum.
+    pub fn checksum(&self) -> u64 {
+        crate::utils::stable_checksum(&(self, self.strategy.get_rules()))
+    }
+
     /// Validates the configuration for logical consistency and safety.
     #[instrument(skip(self))]
     pub fn validate(&self) -> Result<(), ZakatError> {
@@ -382,6 +587,14 @@ impl ZakatConfig {
                 ..Default::default()
             })));
         }
+        if self.gold_silver_ratio.is_some_and(|r| r <= Decimal::ZERO) {
+            return Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
+                code: crate::types::ZakatErrorCode::InvalidInput,
+                reason_key: "error-config-ratio-positive".to_string(),
+                suggestion: Some("The gold:silver ratio must be positive, e.g. 80.".to_string()),
+                ..Default::default()
+            })));
+        }
         if self.silver_price_per_gram <= Decimal::ZERO {
             return Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
                 code: crate::types::ZakatErrorCode::ConfigMissing,
@@ -463,6 +676,23 @@ impl ZakatConfig {
             }
         }
 
+        if let Some(from) = self.mismatched_price_currency() {
+            return Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
+                code: crate::types::ZakatErrorCode::ConfigError,
+                reason_key: "error-config-currency-mismatch".to_string(),
+                args: Some(std::collections::HashMap::from([
+                    ("from".to_string(), from.to_string()),
+                    ("to".to_string(), self.currency_code.clone()),
+                ])),
+                source_label: Some("ZakatConfig validation".to_string()),
+                suggestion: Some(format!(
+                    "Quote prices in {} or convert them with ZakatConfig::convert_prices.",
+                    self.currency_code
+                )),
+                ..Default::default()
+            })));
+        }
+
         Ok(())
     }
 
@@ -537,12 +767,15 @@ impl ZakatConfig {
     /// 
     /// Values in `self` take precedence if they are set (non-zero/Some).
     /// If `self` has missing/default values, `other`'s values are used.
+    /// Only prices and thresholds are filled in; to layer a whole override
+    /// (Madhab, currency, ...) onto a base config, use [`ZakatConfig::overlay`].
     pub fn merge(mut self, other: ZakatConfig) -> Self {
         if self.gold_price_per_gram == Decimal::ZERO {
             self.gold_price_per_gram = other.gold_price_per_gram;
         }
         if self.silver_price_per_gram == Decimal::ZERO {
             self.silver_price_per_gram = other.silver_price_per_gram;
+            self.gold_silver_ratio = other.gold_silver_ratio;
         }
         if self.rice_price_per_kg.is_none() {
             self.rice_price_per_kg = other.rice_price_per_kg;
@@ -559,15 +792,135 @@ impl ZakatConfig {
         if self.nisab_agriculture_kg.is_none() {
             self.nisab_agriculture_kg = other.nisab_agriculture_kg;
         }
+        if self.min_metal_weight_grams.is_none() {
+            self.min_metal_weight_grams = other.min_metal_weight_grams;
+        }
+        if self.minimum_payment.is_none() {
+            self.minimum_payment = other.minimum_payment;
+        }
+        if self.calculation_date.is_none() {
+            self.calculation_date = other.calculation_date;
+        }
         
         self
     }
 
+    /// Layers `top` over this config, field by field. Unlike [`ZakatConfig::merge`], `top` wins.
+    ///
+    /// Anything `top` actually set wins: non-zero prices, `Some` thresholds,
+    /// values passed to builder methods such as `with_madhab()` or
+    /// `with_currency_code()`, and fields that differ from [`ZakatConfig::default`]
+    /// (as loaded by `from_json` or `from_env`, or assigned directly). A field
+    /// that was never set and holds its default cannot be told apart from an
+    /// explicit reset to the default without the builder. Everything else,
+    /// including networking and the observer, comes from `base`.
+    pub fn overlay(self, top: ZakatConfig) -> ZakatConfig {
+        let mut merged = self;
+        let o = top;
+
+        if !o.gold_price_per_gram.is_zero() {
+            merged.gold_price_per_gram = o.gold_price_per_gram;
+        }
+        if let Some(ratio) = o.gold_silver_ratio.filter(|r| *r > Decimal::ZERO) {
+            // `top` only estimates silver from gold: re-derive it from the merged gold
+            // price, unless the base quoted a silver price, which always wins.
+            if merged.silver_price_per_gram.is_zero() || merged.gold_silver_ratio.is_some() {
+                merged.gold_silver_ratio = Some(ratio);
+                merged.silver_price_per_gram = merged.gold_price_per_gram / ratio;
+            }
+        } else if !o.silver_price_per_gram.is_zero() {
+            merged.silver_price_per_gram = o.silver_price_per_gram;
+            merged.gold_silver_ratio = None;
+        }
+        merged.rice_price_per_kg = o.rice_price_per_kg.or(merged.rice_price_per_kg);
+        merged.rice_price_per_liter = o.rice_price_per_liter.or(merged.rice_price_per_liter);
+        merged.nisab_gold_grams = o.nisab_gold_grams.or(merged.nisab_gold_grams);
+        merged.nisab_silver_grams = o.nisab_silver_grams.or(merged.nisab_silver_grams);
+        merged.nisab_agriculture_kg = o.nisab_agriculture_kg.or(merged.nisab_agriculture_kg);
+        merged.min_metal_weight_grams = o.min_metal_weight_grams.or(merged.min_metal_weight_grams);
+        merged.jewelry_luxury_threshold_grams = o.jewelry_luxury_threshold_grams.or(merged.jewelry_luxury_threshold_grams);
+        merged.minimum_payment = o.minimum_payment.or(merged.minimum_payment);
+        merged.rounding = o.rounding.or(merged.rounding);
+        merged.calculation_date = o.calculation_date.or(merged.calculation_date);
+        merged.price_currency = o.price_currency.or(merged.price_currency);
+        for RoundingOverride { wealth_type, rule } in o.rounding_overrides {
+            merged = merged.with_rounding_per_wealth_type(wealth_type, rule);
+        }
+
+        // Builder calls are recorded in `explicit`; anything else counts as set
+        // when it differs from the default.
+        let defaults = ZakatConfig::default();
+        let explicit = o.explicit;
+        let madhab = explicit.madhab.or((o.madhab != defaults.madhab).then_some(o.madhab));
+        // Madhab and strategy reset the nisab standard, so the override's
+        // standard only counts when it differs from the one they imply.
+        let implied_standard = match (&explicit.strategy, madhab) {
+            (Some(strategy), _) => strategy.get_rules().nisab_standard,
+            (None, Some(madhab)) => madhab.get_rules().nisab_standard,
+            (None, None) => defaults.cash_nisab_standard,
+        };
+        let cash_nisab_standard = explicit.cash_nisab_standard
+            .or((o.cash_nisab_standard != implied_standard).then_some(o.cash_nisab_standard));
+        let locale_code = explicit.locale_code
+            .or((o.locale_code != defaults.locale_code).then_some(o.locale_code));
+        let currency_code = explicit.currency_code
+            .or((o.currency_code != defaults.currency_code).then_some(o.currency_code));
+        let mode = explicit.mode.or((o.mode != defaults.mode).then_some(o.mode));
+        let include_income = explicit.include_income_in_combination.or(
+            (o.include_income_in_combination != defaults.include_income_in_combination)
+                .then_some(o.include_income_in_combination),
+        );
+        let combine_monetary = explicit.combine_monetary_assets.or(
+            (o.combine_monetary_assets != defaults.combine_monetary_assets)
+                .then_some(o.combine_monetary_assets),
+        );
+        let debt_policy = explicit.debt_policy
+            .or((o.debt_policy != defaults.debt_policy).then_some(o.debt_policy));
+
+        // Madhab and strategy reset the nisab standard, so apply them first.
+        if let Some(madhab) = madhab {
+            merged = merged.with_madhab(madhab);
+        }
+        if let Some(strategy) = explicit.strategy {
+            merged = merged.with_strategy(strategy);
+        }
+        if let Some(standard) = cash_nisab_standard {
+            merged = merged.with_nisab_standard(standard);
+        }
+        if let Some(locale) = locale_code {
+            merged = merged.with_locale_code(locale);
+        }
+        if let Some(currency) = currency_code {
+            merged = merged.with_currency_code(currency);
+        }
+        if let Some(mode) = mode {
+            merged = merged.with_mode(mode);
+        }
+        if let Some(include) = include_income {
+            merged = merged.with_income_in_combination(include);
+        }
+        if let Some(combine) = combine_monetary {
+            merged = merged.with_monetary_aggregation(combine);
+        }
+        if let Some(policy) = debt_policy {
+            merged = merged.with_debt_policy(policy);
+        }
+
+        merged
+    }
+
     // ========== Fluent Helper Methods ========== 
 
     pub fn with_gold_price(mut self, price: impl IntoZakatDecimal) -> Self {
         if let Ok(p) = price.into_zakat_decimal() {
             self.gold_price_per_gram = p;
+            if let Some(silver) = self
+                .gold_silver_ratio
+                .filter(|r| *r > Decimal::ZERO)
+                .and_then(|r| p.checked_div(r))
+            {
+                self.silver_price_per_gram = silver;
+            }
         }
         self
     }
@@ -575,10 +928,260 @@ impl ZakatConfig {
     pub fn with_silver_price(mut self, price: impl IntoZakatDecimal) -> Self {
         if let Ok(p) = price.into_zakat_decimal() {
              self.silver_price_per_gram = p;
+             self.gold_silver_ratio = None;
         }
         self
     }
 
+    /// Estimates the silver price from the gold price using a gold:silver ratio.
+    ///
+    /// Only applies when no silver price has been set (e.g. a gold-only price feed).
+    /// The silver price is re-derived if the gold price changes afterwards, and
+    /// calculations relying on it carry a warning that silver was estimated.
+    /// An explicit `with_silver_price()` always takes precedence.
+    pub fn with_gold_silver_ratio(mut self, ratio: impl IntoZakatDecimal) -> Self {
+        if let Ok(r) = ratio.into_zakat_decimal()
+            && r > Decimal::ZERO
+            && (self.silver_price_per_gram.is_zero() || self.gold_silver_ratio.is_some())
+        {
+            self.gold_silver_ratio = Some(r);
+            self.silver_price_per_gram = self.gold_price_per_gram / r;
+        }
+        self
+    }
+
+    /// Sets a plausibility floor for metal weights.
+    ///
+    /// Gold or silver entries lighter than this (e.g. `0.0001g`) are still
+    /// calculated, but the result carries a warning that the weight looks wrong.
+    pub fn with_min_metal_weight(mut self, grams: impl IntoZakatDecimal) -> Self {
+        if let Ok(g) = grams.into_zakat_decimal() {
+            self.min_metal_weight_grams = Some(g);
+        }
+        self
+    }
+
+    /// Treats personal jewelry beyond a customary weight as investment.
+    ///
+    /// Applies regardless of Madhab: when personal-use jewelry would be exempt,
+    /// the grams above `grams` are still zakated. `None` turns the rule off.
+    pub fn with_jewelry_luxury_threshold_grams(mut self, grams: Option<Decimal>) -> Self {
+        self.jewelry_luxury_threshold_grams = grams;
+        self
+    }
+
+    /// Controls whether professional income is combined with other monetary wealth.
+    ///
+    /// Income is commonly zakated on receipt (Zakat al-Mustafad). If the same money
+    /// is also entered as cash or savings, combining both double-counts it, so this
+    /// is `false` by default. Enable it only when income is not otherwise recorded.
+    pub fn with_income_in_combination(mut self, include: bool) -> Self {
+        self.include_income_in_combination = include;
+        self.explicit.include_income_in_combination = Some(include);
+        self
+    }
+
+    /// Controls whether monetary assets are combined against a single Nisab.
+    ///
+    /// When off, every asset is judged on its own Nisab. Livestock and crops are
+    /// never combined either way, since their Nisab is a count or a weight.
+    pub fn with_monetary_aggregation(mut self, combine: bool) -> Self {
+        self.combine_monetary_assets = combine;
+        self.explicit.combine_monetary_assets = Some(combine);
+        self
+    }
+
+    /// Pools monetary wealth against one Nisab (Dam' al-Amwal).
+    ///
+    /// Same as [`ZakatConfig::with_monetary_aggregation`].
+    pub fn with_pooling(self, pool: bool) -> Self {
+        self.with_monetary_aggregation(pool)
+    }
+
+    /// Sets how long-term debts are deducted, following the chosen school's view.
+    pub fn with_debt_policy(mut self, policy: crate::types::DebtDeductionPolicy) -> Self {
+        self.debt_policy = policy;
+        self.explicit.debt_policy = Some(policy);
+        self
+    }
+
+    /// Returns true if results of this wealth type join the combined monetary Nisab pool.
+    pub fn joins_monetary_pool(&self, wealth_type: &crate::types::WealthType) -> bool {
+        self.combine_monetary_assets
+            && wealth_type.is_monetary()
+            && (self.include_income_in_combination || *wealth_type != crate::types::WealthType::Income)
+    }
+
+    /// Rounds small monetary dues up to a practical minimum unit.
+    ///
+    /// A due that is positive but below `amount` (e.g. 0.37) is paid as `amount`;
+    /// the result notes the adjustment. Zero dues are never rounded up.
+    pub fn with_minimum_payment(mut self, amount: impl IntoZakatDecimal) -> Self {
+        if let Ok(a) = amount.into_zakat_decimal()
+            && a > Decimal::ZERO
+        {
+            self.minimum_payment = Some(a);
+        }
+        self
+    }
+
+    /// Calculates as of `date` instead of today, e.g. to reproduce a past report.
+    pub fn with_calculation_date(mut self, date: chrono::NaiveDate) -> Self {
+        self.calculation_date = Some(date);
+        self
+    }
+
+    /// Returns the date calculations are made as of: `calculation_date`, or today if unset.
+    pub fn as_of_date(&self) -> chrono::NaiveDate {
+        self.calculation_date.unwrap_or_else(|| chrono::Local::now().date_naive())
+    }
+
+    /// Declares the currency the metal prices are quoted in (e.g. "USD" from a live feed).
+    pub fn with_price_currency(mut self, code: impl Into<String>) -> Self {
+        self.price_currency = Some(code.into());
+        self
+    }
+
+    /// Returns the price currency if it differs from `currency_code`.
+    fn mismatched_price_currency(&self) -> Option<&str> {
+        self.price_currency
+            .as_deref()
+            .filter(|from| !from.eq_ignore_ascii_case(&self.currency_code))
+    }
+
+    /// Converts the gold and silver prices into `currency_code`.
+    ///
+    /// Returns the config unchanged when the prices are already in `currency_code`,
+    /// and a `ConfigurationError` when `converter` has no rate for the pair.
+    /// Rice prices are taken to be local already and are left as-is.
+    pub fn convert_prices(&self, converter: &dyn crate::traits::CurrencyConverter) -> Result<ZakatConfig, ZakatError> {
+        let Some(from) = self.mismatched_price_currency() else {
+            return Ok(self.clone());
+        };
+        let rate = converter
+            .rate(from, &self.currency_code)
+            .filter(|rate| *rate > Decimal::ZERO)
+            .ok_or_else(|| ZakatError::ConfigurationError(Box::new(ErrorDetails {
+                code: crate::types::ZakatErrorCode::ConfigError,
+                reason_key: "error-config-no-fx-rate".to_string(),
+                args: Some(std::collections::HashMap::from([
+                    ("from".to_string(), from.to_string()),
+                    ("to".to_string(), self.currency_code.clone()),
+                ])),
+                source_label: Some("ZakatConfig::convert_prices".to_string()),
+                suggestion: Some("Provide a converter that knows this currency pair.".to_string()),
+                ..Default::default()
+            })))?;
+
+        let convert = |price: Decimal| {
+            crate::math::ZakatDecimal::new(price)
+                .with_context("ZakatConfig::convert_prices")
+                .checked_mul(rate)
+                .map(|converted| *converted)
+        };
+        let mut converted = self.clone();
+        converted.gold_price_per_gram = convert(self.gold_price_per_gram)?;
+        converted.silver_price_per_gram = convert(self.silver_price_per_gram)?;
+        converted.price_currency = None;
+        Ok(converted)
+    }
+
+    /// Rounds monetary dues with `rule` unless a wealth type has its own override.
+    pub fn with_rounding(mut self, rule: RoundingRule) -> Self {
+        self.rounding = Some(rule);
+        self
+    }
+
+    /// Rounds dues of `wealth_type` with `rule`, replacing any earlier override for it.
+    ///
+    /// ```rust
+    /// # use zakat_core::config::{ZakatConfig, RoundingMode, RoundingRule};
+    /// # use zakat_core::types::WealthType;
+    /// let config = ZakatConfig::new()
+    ///     .with_rounding(RoundingRule::new(2, RoundingMode::HalfUp))
+    ///     .with_rounding_per_wealth_type(WealthType::Gold, RoundingRule::new(3, RoundingMode::HalfUp));
+    /// assert_eq!(config.rounding_for(&WealthType::Gold).unwrap().decimals, 3);
+    /// assert_eq!(config.rounding_for(&WealthType::Business).unwrap().decimals, 2);
+    /// ```
+    pub fn with_rounding_per_wealth_type(mut self, wealth_type: crate::types::WealthType, rule: RoundingRule) -> Self {
+        self.rounding_overrides.retain(|o| o.wealth_type != wealth_type);
+        self.rounding_overrides.push(RoundingOverride { wealth_type, rule });
+        self
+    }
+
+    /// Returns the rounding rule for `wealth_type`, falling back to the default rule.
+    pub fn rounding_for(&self, wealth_type: &crate::types::WealthType) -> Option<RoundingRule> {
+        self.rounding_overrides
+            .iter()
+            .find(|o| &o.wealth_type == wealth_type)
+            .map(|o| o.rule)
+            .or(self.rounding)
+    }
+
+    /// Returns a warning if the silver price was estimated from a gold:silver ratio.
+    pub fn silver_price_warning(&self) -> Option<String> {
+        self.gold_silver_ratio.map(|ratio| {
+            format!("Silver price estimated from gold price using a {}:1 gold/silver ratio.", ratio)
+        })
+    }
+
+    /// Flags implausible price relationships, such as a gold price typed as 8.5
+    /// instead of 85, which would put the gold Nisab below the silver Nisab.
+    ///
+    /// Unlike [`ZakatConfig::validate`] this never fails; an empty vector means
+    /// nothing looks suspicious. Unset (zero) prices are left to `validate()`.
+    pub fn sanity_check(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let (gold, silver) = (self.gold_price_per_gram, self.silver_price_per_gram);
+        if gold <= Decimal::ZERO || silver <= Decimal::ZERO {
+            return warnings;
+        }
+
+        if gold <= silver {
+            warnings.push(format!(
+                "Gold price ({}/g) is not above silver price ({}/g); the prices may be swapped.",
+                gold, silver
+            ));
+        }
+
+        let (gold_nisab, silver_nisab) = self.nisab_band();
+        if gold_nisab < silver_nisab {
+            warnings.push(format!(
+                "Gold Nisab ({}) is below silver Nisab ({}); check the gold price.",
+                gold_nisab.round_dp(2), silver_nisab.round_dp(2)
+            ));
+        }
+
+        let ratio = gold / silver;
+        let (min, max) = PLAUSIBLE_GOLD_SILVER_RATIO;
+        if ratio < min || ratio > max {
+            warnings.push(format!(
+                "Gold/silver price ratio {}:1 is outside the plausible range {}:1 to {}:1.",
+                ratio.round_dp(1), min, max
+            ));
+        }
+
+        warnings
+    }
+
+    /// Returns warnings for prices that feed into the monetary Nisab.
+    pub(crate) fn monetary_price_warnings(&self) -> Vec<String> {
+        if self.cash_nisab_standard == NisabStandard::Gold {
+            return Vec::new();
+        }
+        let (_, fallback) = self.monetary_nisab_basis();
+        fallback.into_iter().chain(self.silver_price_warning()).collect()
+    }
+
+    /// Sets the gold Nisab weight from a [`NisabPreset`].
+    pub fn with_nisab_preset(mut self, preset: NisabPreset) -> Self {
+        self.nisab_gold_grams = Some(preset.gold_grams());
+        self
+    }
+
+    /// Overrides the gold Nisab weight (85g when unset), e.g. 87.48g for
+    /// bodies that use 7.5 tola. Every gold-based Nisab, in metals and in cash
+    /// or business assets, uses it; read it back with [`Self::get_nisab_gold_grams`].
     pub fn with_gold_nisab(mut self, grams: impl IntoZakatDecimal) -> Self {
         if let Ok(p) = grams.into_zakat_decimal() {
             self.nisab_gold_grams = Some(p);
@@ -586,6 +1189,8 @@ impl ZakatConfig {
         self
     }
 
+    /// Overrides the silver Nisab weight (595g when unset), e.g. 612.36g for
+    /// 52.5 tola. Read it back with [`Self::get_nisab_silver_grams`].
     pub fn with_silver_nisab(mut self, grams: impl IntoZakatDecimal) -> Self {
         if let Ok(p) = grams.into_zakat_decimal() {
             self.nisab_silver_grams = Some(p);
@@ -602,11 +1207,13 @@ impl ZakatConfig {
 
     pub fn with_locale_code(mut self, locale: impl Into<String>) -> Self {
         self.locale_code = locale.into();
+        self.explicit.locale_code = Some(self.locale_code.clone());
         self
     }
 
     pub fn with_currency_code(mut self, code: impl Into<String>) -> Self {
         self.currency_code = code.into();
+        self.explicit.currency_code = Some(self.currency_code.clone());
         self
     }
 
@@ -630,18 +1237,32 @@ impl ZakatConfig {
         self.strategy = Arc::new(madhab);
         self.madhab = madhab;
         self.cash_nisab_standard = rules.nisab_standard;
+        self.explicit.madhab = Some(madhab);
+        self.explicit.strategy = None;
+        self.explicit.cash_nisab_standard = None;
         self
     }
 
-    /// Sets a custom Zakat strategy from an Arc.
+    /// Sets a custom Zakat strategy from an Arc, overriding the Madhab preset.
+    ///
+    /// A prebuilt [`ZakatRules`](crate::madhab::ZakatRules) also implements
+    /// `ZakatStrategy` and can be passed here as-is.
     pub fn with_strategy(mut self, strategy: Arc<dyn ZakatStrategy>) -> Self {
         self.cash_nisab_standard = strategy.get_rules().nisab_standard;
+        self.explicit.strategy = Some(strategy.clone());
+        self.explicit.cash_nisab_standard = None;
         self.strategy = strategy;
         self
     }
 
+    /// Returns the active strategy, whether set by Madhab or injected.
+    pub fn strategy(&self) -> &dyn ZakatStrategy {
+        self.strategy.as_ref()
+    }
+
     pub fn with_nisab_standard(mut self, standard: NisabStandard) -> Self {
         self.cash_nisab_standard = standard;
+        self.explicit.cash_nisab_standard = Some(standard);
         self
     }
 
@@ -651,6 +1272,7 @@ impl ZakatConfig {
     /// - `Permissive`: Allows approximations, uses safe defaults for missing data.
     pub fn with_mode(mut self, mode: ZakatMode) -> Self {
         self.mode = mode;
+        self.explicit.mode = Some(mode);
         self
     }
 
@@ -665,10 +1287,12 @@ impl ZakatConfig {
     }
 
     // Getters
+    /// Effective gold Nisab weight in grams: the override, else 85.
     pub fn get_nisab_gold_grams(&self) -> Decimal {
         self.nisab_gold_grams.unwrap_or(dec!(85))
     }
 
+    /// Effective silver Nisab weight in grams: the override, else 595.
     pub fn get_nisab_silver_grams(&self) -> Decimal {
         self.nisab_silver_grams.unwrap_or(dec!(595))
     }
@@ -678,14 +1302,172 @@ impl ZakatConfig {
     }
 
     pub fn get_monetary_nisab_threshold(&self) -> Decimal {
-        let gold_threshold = self.gold_price_per_gram * self.get_nisab_gold_grams();
-        let silver_threshold = self.silver_price_per_gram * self.get_nisab_silver_grams();
-        
+        let (gold_threshold, silver_threshold) = self.nisab_band();
+        match self.monetary_nisab_basis().0 {
+            WealthType::Silver => silver_threshold,
+            _ => gold_threshold,
+        }
+    }
+
+    /// Currency values of the gold and silver Nisab, zero for an unpriced metal.
+    ///
+    /// The one place a Nisab weight is priced; the other Nisab getters build on it.
+    /// Also feeds [`crate::madhab::recommendation_for`].
+    pub fn nisab_band(&self) -> (Decimal, Decimal) {
+        (
+            self.gold_price_per_gram * self.get_nisab_gold_grams(),
+            self.silver_price_per_gram * self.get_nisab_silver_grams(),
+        )
+    }
+
+    /// True when neither metal is priced, as with [`ZakatConfig::default`].
+    pub fn is_unpriced(&self) -> bool {
+        self.gold_price_per_gram <= Decimal::ZERO && self.silver_price_per_gram <= Decimal::ZERO
+    }
+
+    /// Debug builds only: flags calculations against an unpriced config, whose
+    /// zero Nisab makes every asset look zakatable.
+    ///
+    /// Called once at calculation entry, not from the Nisab getters, so that
+    /// `describe()` and other read-only accessors stay quiet.
+    pub(crate) fn warn_if_unpriced(&self) {
+        #[cfg(debug_assertions)]
+        if self.is_unpriced() {
+            tracing::warn!("Calculating with zero gold and silver prices (ZakatConfig::default()?); the Nisab is 0");
+        }
+    }
+
+    /// Currency value of the Nisab under `standard`, independent of any asset.
+    ///
+    /// Uses the configured gold/silver prices and Nisab weights (85g gold or 595g
+    /// silver by default). `LowerOfTwo` falls back to whichever metal is priced.
+    /// Returns [`ZakatError::MissingConfig`] when the needed price is zero.
+    pub fn nisab_threshold(&self, standard: NisabStandard) -> Result<Decimal, ZakatError> {
+        let (gold_threshold, silver_threshold) = self.nisab_band();
+        let gold = (self.gold_price_per_gram > Decimal::ZERO).then_some(gold_threshold);
+        let silver = (self.silver_price_per_gram > Decimal::ZERO).then_some(silver_threshold);
+        let missing = |field: &str| ZakatError::MissingConfig {
+            field: field.to_string(),
+            source_label: None,
+            asset_id: None,
+        };
+        match standard {
+            NisabStandard::Gold => gold.ok_or_else(|| missing("gold_price_per_gram")),
+            NisabStandard::Silver => silver.ok_or_else(|| missing("silver_price_per_gram")),
+            NisabStandard::LowerOfTwo => match (gold, silver) {
+                (Some(g), Some(s)) => Ok(g.min(s)),
+                (Some(threshold), None) | (None, Some(threshold)) => Ok(threshold),
+                (None, None) => Err(missing("gold_price_per_gram")),
+            },
+        }
+    }
+
+    /// Resolves which metal's price sets the monetary Nisab.
+    ///
+    /// `LowerOfTwo` picks whichever of the gold or silver Nisab is lower in currency.
+    /// If only one of the two prices is set it falls back to that metal and returns
+    /// a warning, instead of comparing against a zero threshold.
+    pub fn monetary_nisab_basis(&self) -> (WealthType, Option<String>) {
+        let has_gold = self.gold_price_per_gram > Decimal::ZERO;
+        let has_silver = self.silver_price_per_gram > Decimal::ZERO;
         match self.cash_nisab_standard {
-            NisabStandard::Gold => gold_threshold,
-            NisabStandard::Silver => silver_threshold,
-            NisabStandard::LowerOfTwo => gold_threshold.min(silver_threshold),
+            NisabStandard::Gold => (WealthType::Gold, None),
+            NisabStandard::Silver => (WealthType::Silver, None),
+            NisabStandard::LowerOfTwo => match (has_gold, has_silver) {
+                (true, false) => (
+                    WealthType::Gold,
+                    Some("Silver price is missing; the lower-of-two Nisab fell back to the gold Nisab.".to_string()),
+                ),
+                (false, true) => (
+                    WealthType::Silver,
+                    Some("Gold price is missing; the lower-of-two Nisab fell back to the silver Nisab.".to_string()),
+                ),
+                _ => {
+                    let (gold_threshold, silver_threshold) = self.nisab_band();
+                    let metal = if silver_threshold < gold_threshold { WealthType::Silver } else { WealthType::Gold };
+                    (metal, None)
+                }
+            },
+        }
+    }
+
+    /// Trace step recording which metal set the Nisab under `LowerOfTwo`.
+    pub(crate) fn monetary_nisab_step(&self) -> Option<CalculationStep> {
+        self.nisab_metal_step(&self.monetary_nisab_basis().0)
+    }
+
+    /// Trace step recording that `metal` set the Nisab, under `LowerOfTwo` only;
+    /// with a single-metal standard the basis is already in the config.
+    pub(crate) fn nisab_metal_step(&self, metal: &WealthType) -> Option<CalculationStep> {
+        if self.cash_nisab_standard != NisabStandard::LowerOfTwo {
+            return None;
         }
+        let grams = if *metal == WealthType::Silver { self.get_nisab_silver_grams() } else { self.get_nisab_gold_grams() };
+        Some(
+            CalculationStep::info("info-nisab-metal", format!("Nisab based on {:?} ({}g)", metal, grams))
+                .with_args(std::collections::HashMap::from([
+                    ("metal".to_string(), format!("{:?}", metal)),
+                    ("grams".to_string(), grams.to_string()),
+                ])),
+        )
+    }
+
+    /// Checks that the prices needed for the monetary Nisab are set.
+    pub(crate) fn require_monetary_prices(&self, label: Option<String>) -> Result<(), ZakatError> {
+        let missing_gold = self.gold_price_per_gram <= Decimal::ZERO;
+        let missing_silver = self.silver_price_per_gram <= Decimal::ZERO;
+        let (missing, reason_key, suggestion) = match self.cash_nisab_standard {
+            NisabStandard::Gold => (missing_gold, "error-gold-price-required", "Run with --gold-price X or set ZAKAT_GOLD_PRICE env var."),
+            NisabStandard::Silver => (missing_silver, "error-silver-price-required", "Run with --silver-price X or set ZAKAT_SILVER_PRICE env var."),
+            NisabStandard::LowerOfTwo => (
+                missing_gold && missing_silver,
+                "error-gold-price-required",
+                "Run with --gold-price X or --silver-price X (or set ZAKAT_GOLD_PRICE / ZAKAT_SILVER_PRICE).",
+            ),
+        };
+        if !missing {
+            return Ok(());
+        }
+        Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
+            code: crate::types::ZakatErrorCode::ConfigError,
+            reason_key: reason_key.to_string(),
+            source_label: label,
+            suggestion: Some(suggestion.to_string()),
+            ..Default::default()
+        })))
+    }
+
+    /// Returns a human-readable, multi-line summary of the resolved configuration.
+    ///
+    /// Intended for diagnostics (e.g. `zakat-cli doctor`); derived values such as
+    /// the monetary Nisab threshold are shown alongside the raw settings.
+    pub fn describe(&self) -> String {
+        let silver_source = match self.gold_silver_ratio {
+            Some(ratio) => format!(" (derived, {}:1 ratio)", ratio),
+            None => String::new(),
+        };
+        let mut lines = vec![
+            format!("Madhab: {:?}", self.madhab),
+            format!("Cash Nisab Standard: {:?}", self.cash_nisab_standard),
+            format!("Mode: {:?}", self.mode),
+            format!("Gold Price: {}/g", self.format_currency(self.gold_price_per_gram)),
+            format!("Silver Price: {}/g{}", self.format_currency(self.silver_price_per_gram), silver_source),
+            format!(
+                "Nisab: {}g gold, {}g silver, {}kg agriculture",
+                self.get_nisab_gold_grams(),
+                self.get_nisab_silver_grams(),
+                self.get_nisab_agriculture_kg()
+            ),
+            format!("Monetary Nisab Threshold: {}", self.format_currency(self.get_monetary_nisab_threshold())),
+            format!("Locale: {}, Currency: {}", self.locale_code, self.currency_code),
+        ];
+        if let Some(min) = self.min_metal_weight_grams {
+            lines.push(format!("Minimum Metal Weight: {}g", min));
+        }
+        if let Some(threshold) = self.jewelry_luxury_threshold_grams {
+            lines.push(format!("Jewelry Luxury Threshold: {}g", threshold));
+        }
+        lines.join("\n")
     }
 
     /// Formats a currency amount with the configured locale/currency (basic implementation).
@@ -707,6 +1489,27 @@ impl ZakatConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_default_values_match_documentation() {
+        let config = ZakatConfig::default();
+
+        assert_eq!(config.madhab, Madhab::Hanafi);
+        assert_eq!(config.cash_nisab_standard, NisabStandard::Gold);
+        assert_eq!(config.gold_price_per_gram, Decimal::ZERO);
+        assert_eq!(config.silver_price_per_gram, Decimal::ZERO);
+        assert!(config.is_unpriced());
+        assert!(config.validate().is_err());
+        assert_eq!(config.get_nisab_gold_grams(), dec!(85));
+        assert_eq!(config.locale_code, "en-US");
+        assert_eq!(config.currency_code, "USD");
+        assert_eq!(config.mode, ZakatMode::Strict);
+        assert!(config.combine_monetary_assets);
+        assert!(!config.include_income_in_combination);
+        assert!(config.rounding.is_none());
+        assert!(config.minimum_payment.is_none());
+        assert!(!ZakatConfig::test_default().is_unpriced());
+    }
+
     #[test]
     fn test_validate_prices() {
         let config = ZakatConfig::new()
@@ -724,6 +1527,175 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_non_positive_gold_silver_ratio_is_rejected() {
+        for ratio in [Decimal::ZERO, dec!(-80)] {
+            let config = ZakatConfig { gold_silver_ratio: Some(ratio), ..ZakatConfig::test_default() };
+            // Re-pricing gold must not divide by the bad ratio.
+            let config = config.with_gold_price(90);
+            assert_eq!(config.gold_price_per_gram, dec!(90));
+
+            match config.validate() {
+                Err(ZakatError::ConfigurationError(details)) => {
+                    assert_eq!(details.reason_key, "error-config-ratio-positive");
+                }
+                other => panic!("Expected ConfigurationError, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_tola_preset_raises_gold_nisab() {
+        let standard = ZakatConfig::test_default().with_nisab_preset(NisabPreset::Standard85g);
+        let tola = ZakatConfig::test_default().with_nisab_preset(NisabPreset::Tola87_48g);
+
+        assert_eq!(tola.get_nisab_gold_grams(), dec!(87.48));
+        assert!(tola.get_monetary_nisab_threshold() > standard.get_monetary_nisab_threshold());
+        assert_eq!(tola.get_monetary_nisab_threshold(), dec!(87.48) * dec!(85));
+
+        let custom = ZakatConfig::test_default().with_nisab_preset(NisabPreset::Custom(dec!(80)));
+        assert_eq!(custom.get_nisab_gold_grams(), dec!(80));
+    }
+
+    #[test]
+    fn test_silver_nisab_grams_override_reaches_calculators() {
+        use crate::traits::CalculateZakat;
+        let config = ZakatConfig::new()
+            .with_gold_price(100)
+            .with_silver_price(1)
+            .with_nisab_standard(NisabStandard::Silver)
+            .with_silver_nisab(dec!(612.36));
+        assert_eq!(config.get_nisab_silver_grams(), dec!(612.36));
+        assert_eq!(ZakatConfig::new().get_nisab_silver_grams(), dec!(595));
+
+        let cash = crate::maal::business::BusinessZakat::new().cash(600).hawl(true)
+            .calculate_zakat(&config).unwrap();
+        assert_eq!(cash.nisab_threshold, dec!(612.36));
+        assert!(!cash.is_payable, "600 clears 595g but not 612.36g");
+
+        let silver = crate::maal::precious_metals::PreciousMetals::silver(600).hawl(true)
+            .calculate_zakat(&config).unwrap();
+        assert!(!silver.is_payable);
+    }
+
+    #[test]
+    fn test_overlay_keeps_base_fields_the_override_left_unset() {
+        let base = ZakatConfig::hanafi(dec!(85), dec!(1)).with_currency_code("USD");
+        let override_ = ZakatConfig::new().with_madhab(Madhab::Shafi);
+
+        let merged = base.overlay(override_);
+
+        assert_eq!(merged.madhab, Madhab::Shafi);
+        assert_eq!(merged.cash_nisab_standard, Madhab::Shafi.get_rules().nisab_standard);
+        assert_eq!(merged.currency_code, "USD");
+        assert_eq!(merged.gold_price_per_gram, dec!(85));
+
+        // A currency left at its default must not clobber the base's choice.
+        let sar = ZakatConfig::hanafi(dec!(85), dec!(1)).with_currency_code("SAR");
+        let merged = sar.overlay(ZakatConfig::new().with_madhab(Madhab::Shafi));
+        assert_eq!(merged.currency_code, "SAR");
+    }
+
+    #[test]
+    fn test_overlay_applies_deserialized_override() {
+        let base = ZakatConfig::hanafi(dec!(85), dec!(1)).with_currency_code("USD");
+        let override_: ZakatConfig = r#"{
+            "madhab": "hanbali",
+            "goldPricePerGram": "0",
+            "silverPricePerGram": "0",
+            "cashNisabStandard": "silver",
+            "currencyCode": "SAR",
+            "mode": "permissive",
+            "combineMonetaryAssets": false
+        }"#.parse().unwrap();
+
+        let merged = base.overlay(override_);
+
+        assert_eq!(merged.madhab, Madhab::Hanbali);
+        assert_eq!(merged.cash_nisab_standard, NisabStandard::Silver);
+        assert_eq!(merged.currency_code, "SAR");
+        assert_eq!(merged.mode, ZakatMode::Permissive);
+        assert!(!merged.combine_monetary_assets);
+        // Fields the JSON left at their defaults keep the base's values.
+        assert_eq!(merged.locale_code, "en-US");
+        assert_eq!(merged.gold_price_per_gram, dec!(85));
+
+        // A default-valued deserialized config changes nothing.
+        let hanafi = ZakatConfig::hanafi(dec!(85), dec!(1));
+        let blank: ZakatConfig = serde_json::from_str(&serde_json::to_string(&ZakatConfig::default()).unwrap()).unwrap();
+        let merged = hanafi.overlay(blank);
+        assert_eq!(merged.cash_nisab_standard, NisabStandard::LowerOfTwo);
+    }
+
+    #[test]
+    fn test_merge_keeps_self_and_fills_missing_prices() {
+        let partial = ZakatConfig::new().with_gold_price(90);
+        let fallback = ZakatConfig::new().with_gold_price(85).with_silver_price(1);
+
+        let merged = partial.merge(fallback);
+
+        assert_eq!(merged.gold_price_per_gram, dec!(90), "self wins where it is set");
+        assert_eq!(merged.silver_price_per_gram, dec!(1), "other fills what self left at zero");
+    }
+
+    #[test]
+    fn test_overlay_carries_gold_silver_ratio() {
+        let gold_only_feed = ZakatConfig::new().with_gold_price(100).with_gold_silver_ratio(80);
+
+        // No silver in the base: the feed's ratio estimates it from the merged gold.
+        let merged = ZakatConfig::new().with_gold_price(80).overlay(gold_only_feed.clone());
+        assert_eq!(merged.gold_price_per_gram, dec!(100));
+        assert_eq!(merged.gold_silver_ratio, Some(dec!(80)));
+        assert_eq!(merged.silver_price_per_gram, dec!(1.25));
+
+        // A base silver that was itself estimated is re-derived with the new ratio.
+        let estimated = ZakatConfig::new().with_gold_price(80).with_gold_silver_ratio(40);
+        let merged = estimated.overlay(ZakatConfig::new().with_gold_silver_ratio(80));
+        assert_eq!(merged.silver_price_per_gram, dec!(1));
+        assert_eq!(merged.gold_silver_ratio, Some(dec!(80)));
+    }
+
+    #[test]
+    fn test_overlay_ratio_never_replaces_a_quoted_silver_price() {
+        // Base silver 2 is not 80 / 80, so an overwrite would be visible.
+        let base = ZakatConfig::new().with_gold_price(80).with_silver_price(2);
+
+        let merged = base.clone().overlay(ZakatConfig::new().with_gold_silver_ratio(80));
+        assert_eq!(merged.silver_price_per_gram, dec!(2));
+        assert_eq!(merged.gold_silver_ratio, None);
+
+        let merged = base.overlay(ZakatConfig::new().with_gold_price(100).with_gold_silver_ratio(80));
+        assert_eq!(merged.gold_price_per_gram, dec!(100));
+        assert_eq!(merged.silver_price_per_gram, dec!(2));
+        assert_eq!(merged.gold_silver_ratio, None);
+    }
+
+    #[test]
+    fn test_overlay_ignores_a_zero_ratio() {
+        let base = ZakatConfig::new().with_gold_price(80);
+        // Builders reject it, but serde or a direct assignment can still set zero.
+        let top = ZakatConfig { gold_silver_ratio: Some(Decimal::ZERO), ..Default::default() };
+
+        let merged = base.overlay(top);
+        assert_eq!(merged.gold_price_per_gram, dec!(80));
+        assert_eq!(merged.silver_price_per_gram, Decimal::ZERO);
+        assert_eq!(merged.gold_silver_ratio, None);
+    }
+
+    #[test]
+    fn test_sanity_check_flags_swapped_prices() {
+        let swapped = ZakatConfig::new().with_gold_price(1).with_silver_price(85);
+        let warnings = swapped.sanity_check();
+        assert!(warnings.iter().any(|w| w.contains("may be swapped")), "{:?}", warnings);
+        assert!(warnings.iter().any(|w| w.contains("Gold Nisab")), "{:?}", warnings);
+
+        let typo = ZakatConfig::new().with_gold_price(dec!(8.5)).with_silver_price(1);
+        assert!(typo.sanity_check().iter().any(|w| w.contains("ratio")));
+
+        let sane = ZakatConfig::new().with_gold_price(85).with_silver_price(1);
+        assert!(sane.sanity_check().is_empty());
+    }
+
     #[test]
     fn test_valid_prices() {
         let config = ZakatConfig::test_default();
@@ -757,5 +1729,118 @@ mod tests {
         let hanbali = ZakatConfig::hanbali(dec!(85));
         assert!(matches!(hanbali.cash_nisab_standard, NisabStandard::Gold));
     }
-}
 
+    #[test]
+    fn test_injected_rules_drive_calculations() {
+        use crate::madhab::ZakatRules;
+        use crate::traits::CalculateZakat;
+
+        let rules = ZakatRules::default()
+            .with_nisab_standard(NisabStandard::Gold)
+            .with_trade_goods_rate(dec!(0.03));
+        let config = ZakatConfig::test_default()
+            .with_madhab(Madhab::Hanafi)
+            .with_strategy(Arc::new(rules.clone()));
+
+        assert_eq!(config.strategy().get_rules(), rules);
+        assert_eq!(config.cash_nisab_standard, NisabStandard::Gold);
+
+        let details = crate::maal::business::BusinessZakat::cash_only(10_000)
+            .hawl(true)
+            .calculate_zakat(&config)
+            .unwrap();
+        assert_eq!(details.zakat_due, dec!(300));
+    }
+
+    #[test]
+    fn test_rounding_per_wealth_type_applies_independently() {
+        use crate::traits::CalculateZakat;
+        use crate::types::WealthType;
+
+        let config = ZakatConfig::new()
+            .with_gold_price(dec!(100.1234))
+            .with_rounding(RoundingRule::new(2, RoundingMode::HalfUp))
+            .with_rounding_per_wealth_type(WealthType::Gold, RoundingRule::new(3, RoundingMode::HalfUp));
+
+        // 100g x 100.1234 = 10012.34; 2.5% = 250.3085 for both assets.
+        let gold = crate::maal::precious_metals::PreciousMetals::gold(100)
+            .hawl(true)
+            .calculate_zakat(&config)
+            .unwrap();
+        assert_eq!(gold.zakat_due, dec!(250.309));
+
+        let cash = crate::maal::business::BusinessZakat::cash_only(dec!(10012.34))
+            .hawl(true)
+            .calculate_zakat(&config)
+            .unwrap();
+        assert_eq!(cash.zakat_due, dec!(250.31));
+        assert!(cash.calculation_breakdown.iter().any(|s| s.key == "info-rounding"));
+
+        // Without any rule the due keeps full precision.
+        let unrounded = crate::maal::business::BusinessZakat::cash_only(dec!(10012.34))
+            .hawl(true)
+            .calculate_zakat(&ZakatConfig::new().with_gold_price(dec!(100.1234)))
+            .unwrap();
+        assert_eq!(unrounded.zakat_due, dec!(250.3085));
+    }
+
+    #[test]
+    fn test_rounding_overrides_serialize_as_named_fields() {
+        use crate::types::WealthType;
+
+        let config = ZakatConfig::new()
+            .with_rounding_per_wealth_type(WealthType::Gold, RoundingRule::new(3, RoundingMode::Up));
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["roundingOverrides"][0]["wealthType"]["type"], "gold");
+        assert_eq!(json["roundingOverrides"][0]["rule"]["decimals"], 3);
+
+        let restored: ZakatConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.rounding_for(&WealthType::Gold), Some(RoundingRule::new(3, RoundingMode::Up)));
+    }
+
+    #[test]
+    fn test_nisab_threshold_without_assets() {
+        let config = ZakatConfig::new().with_gold_price(100).with_silver_price(1);
+
+        assert_eq!(config.nisab_threshold(NisabStandard::Gold).unwrap(), dec!(8500));
+        assert_eq!(config.nisab_threshold(NisabStandard::Silver).unwrap(), dec!(595));
+        assert_eq!(config.nisab_threshold(NisabStandard::LowerOfTwo).unwrap(), dec!(595));
+
+        let gold_only = ZakatConfig::new().with_gold_price(100);
+        assert!(matches!(
+            gold_only.nisab_threshold(NisabStandard::Silver),
+            Err(ZakatError::MissingConfig { ref field, .. }) if field == "silver_price_per_gram"
+        ));
+        assert_eq!(gold_only.nisab_threshold(NisabStandard::LowerOfTwo).unwrap(), dec!(8500));
+    }
+
+    #[test]
+    fn test_silver_derived_from_gold_ratio() {
+        let config = ZakatConfig::new()
+            .with_gold_price(80)
+            .with_gold_silver_ratio(80);
+
+        assert_eq!(config.silver_price_per_gram, Decimal::ONE);
+        assert!(config.silver_price_warning().is_some());
+
+        // Silver-based Nisab: 595 * 1 = 595
+        use crate::traits::CalculateZakat;
+        let details = crate::maal::business::BusinessZakat::new()
+            .cash(1000)
+            .hawl(true)
+            .calculate_zakat(&config.clone().with_nisab_standard(NisabStandard::Silver))
+            .unwrap();
+        assert_eq!(details.nisab_threshold, Decimal::from(595));
+        #[allow(deprecated)]
+        let warnings = details.warnings;
+        assert!(warnings.iter().any(|w| w.contains("80:1")));
+
+        // An explicit silver price is never overridden
+        let quoted = ZakatConfig::new()
+            .with_gold_price(80)
+            .with_silver_price(2)
+            .with_gold_silver_ratio(80);
+        assert_eq!(quoted.silver_price_per_gram, Decimal::from(2));
+        assert!(quoted.silver_price_warning().is_none());
+    }
+}
@@ -0,0 +1,366 @@
+//! # CSV Import/Export
+//!
+//! Flat, spreadsheet-friendly serialization for [`ZakatPortfolio`]. Each row is
+//! one asset: a `type` column (the same names as the JSON `type` tag), an optional
+//! `group` column, then one column per asset field in camelCase (`cashOnHand`,
+//! `weightGrams`, ...). Columns a type does not use are left empty.
+//!
+//! Nested fields such as `namedLiabilities` are stored as JSON inside their cell,
+//! except single-tag enums (`metalType`, `hawlMode`) which are written as their
+//! tag, e.g. `gold`.
+//!
+//! On import, columns unknown to a row's type are ignored and missing optional
+//! fields take the asset's defaults. A row without one of its type's required
+//! fields is rejected with an `InvalidInput` error naming the row and field.
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::assets::{CustomAsset, PortfolioItem};
+use crate::portfolio::ZakatPortfolio;
+use crate::traits::CalculateZakat;
+use crate::types::{InvalidInputDetails, ZakatError};
+
+const TYPE_COLUMN: &str = "type";
+const GROUP_COLUMN: &str = "group";
+
+impl ZakatPortfolio {
+    /// Renders the portfolio as CSV, one row per asset.
+    ///
+    /// See the [module docs](crate::csv) for the column layout.
+    pub fn to_csv(&self) -> Result<String, ZakatError> {
+        let mut columns: Vec<String> = vec![TYPE_COLUMN.to_string(), GROUP_COLUMN.to_string()];
+        let mut rows: Vec<HashMap<String, String>> = Vec::with_capacity(self.get_items().len());
+
+        for item in self.get_items() {
+            let value = serde_json::to_value(item).map_err(|e| ZakatError::serialization("ZakatPortfolio", e))?;
+            let mut row = HashMap::new();
+            row.insert(TYPE_COLUMN.to_string(), value[TYPE_COLUMN].as_str().unwrap_or_default().to_string());
+            if let Some(group) = self.group_of(CalculateZakat::get_id(item)) {
+                row.insert(GROUP_COLUMN.to_string(), group.to_string());
+            }
+            if let Some(fields) = value["data"].as_object() {
+                for (key, field) in fields {
+                    if !columns.contains(key) {
+                        columns.push(key.clone());
+                    }
+                    row.insert(key.clone(), cell_from_value(field));
+                }
+            }
+            rows.push(row);
+        }
+
+        let mut csv = columns.iter().map(|c| escape_field(c)).collect::<Vec<_>>().join(",");
+        csv.push('\n');
+        for row in rows {
+            let line: Vec<String> = columns
+                .iter()
+                .map(|c| escape_field(row.get(c).map(String::as_str).unwrap_or("")))
+                .collect();
+            csv.push_str(&line.join(","));
+            csv.push('\n');
+        }
+        Ok(csv)
+    }
+
+    /// Reads a portfolio written by [`Self::to_csv`] (or by hand in a spreadsheet).
+    ///
+    /// Row numbers in errors are 1-based and count the header, so they match
+    /// what a spreadsheet shows.
+    pub fn from_csv<R: Read>(mut reader: R) -> Result<Self, ZakatError> {
+        let mut content = String::new();
+        reader.read_to_string(&mut content).map_err(|e| csv_error(1, "file", "error-csv-parse", e.to_string()))?;
+
+        let mut records = parse_records(&content).into_iter();
+        let header: Vec<String> = records
+            .next()
+            .ok_or_else(|| csv_error(1, TYPE_COLUMN, "error-csv-missing-field", String::new()))?
+            .into_iter()
+            .map(|c| c.trim().to_string())
+            .collect();
+
+        let mut portfolio = ZakatPortfolio::new();
+        for (index, record) in records.enumerate() {
+            let row_number = index + 2;
+            if record.iter().all(|cell| cell.trim().is_empty()) {
+                continue;
+            }
+            let cells: HashMap<&str, &str> = header
+                .iter()
+                .map(String::as_str)
+                .zip(record.iter().map(|c| c.trim()))
+                .filter(|(_, cell)| !cell.is_empty())
+                .collect();
+
+            let item = item_from_row(&cells, row_number)?;
+            let id = portfolio.push(item);
+            if let Some(group) = cells.get(GROUP_COLUMN) {
+                portfolio.set_group(id, *group)?;
+            }
+        }
+        Ok(portfolio)
+    }
+}
+
+/// Builds one asset from a row's non-empty cells, starting from the type's defaults.
+fn item_from_row(cells: &HashMap<&str, &str>, row: usize) -> Result<PortfolioItem, ZakatError> {
+    let kind = *cells
+        .get(TYPE_COLUMN)
+        .ok_or_else(|| csv_error(row, TYPE_COLUMN, "error-csv-missing-field", String::new()))?;
+    let (template, required) = template_for(kind)
+        .zip(crate::assets::required_fields(kind))
+        .ok_or_else(|| csv_error(row, TYPE_COLUMN, "error-csv-unknown-type", kind.to_string()))?;
+
+    if let Some(missing) = required.iter().find(|field| !cells.contains_key(**field)) {
+        return Err(csv_error(row, missing, "error-csv-missing-field", String::new()));
+    }
+
+    let template = serde_json::to_value(&template).map_err(|e| ZakatError::serialization("ZakatPortfolio", e))?;
+    let mut data: Map<String, Value> = template["data"].as_object().cloned().unwrap_or_default();
+    for (key, default) in data.iter_mut() {
+        if let Some(cell) = cells.get(key.as_str()) {
+            *default = value_from_cell(cell, default).map_err(|e| csv_error(row, key, "error-csv-parse", e))?;
+        }
+    }
+    // Fields a default asset leaves out of its JSON are not in the template, but
+    // they are still the type's own fields and must survive an export round-trip.
+    for (key, cell) in cells {
+        if data.contains_key(*key) {
+            continue;
+        }
+        if let Some(shape) = omitted_field_shape(key) {
+            let value = value_from_cell(cell, &shape).map_err(|e| csv_error(row, key, "error-csv-parse", e))?;
+            data.insert(key.to_string(), value);
+        }
+    }
+    // Templates share fixed ids; an asset without one gets a fresh id.
+    if !cells.contains_key("id") {
+        data.insert("id".to_string(), Value::String(Uuid::new_v4().to_string()));
+    }
+
+    let mut object = Map::new();
+    object.insert(TYPE_COLUMN.to_string(), Value::String(kind.to_string()));
+    object.insert("data".to_string(), Value::Object(data));
+    serde_json::from_value(Value::Object(object)).map_err(|e| csv_error(row, kind, "error-csv-parse", e.to_string()))
+}
+
+/// Default asset for a `type` cell. Required columns come from the same table
+/// as JSON import (see `PortfolioItem`'s `TryFrom<&serde_json::Value>`).
+fn template_for(kind: &str) -> Option<PortfolioItem> {
+    use crate::maal::{agriculture::AgricultureAssets, business::BusinessZakat, income::IncomeZakatCalculator,
+        investments::InvestmentAssets, livestock::LivestockAssets, mining::MiningAssets,
+        precious_metals::PreciousMetals};
+
+    Some(match kind {
+        "business" => BusinessZakat::default().into(),
+        "income" => IncomeZakatCalculator::default().into(),
+        "livestock" => LivestockAssets::default().into(),
+        "agriculture" => AgricultureAssets::default().into(),
+        "investment" => InvestmentAssets::default().into(),
+        "mining" => MiningAssets::default().into(),
+        // The metal is only set so the template carries the `metalType` shape; the row supplies it.
+        "preciousMetals" => PreciousMetals::default().metal_type(crate::types::WealthType::Gold).into(),
+        "fitrah" => crate::fitrah::FitrahCalculator::default().into(),
+        "custom" => PortfolioItem::Custom(CustomAsset::new("", 0, 0, 0)),
+        _ => return None,
+    })
+}
+
+/// Shape of a field that is skipped when serializing its default (`None` or zero),
+/// so that [`value_from_cell`] can read its cell back.
+fn omitted_field_shape(key: &str) -> Option<Value> {
+    match key {
+        // Adjacently tagged `HawlStatus`, exported as its tag (e.g. `unknown`).
+        "hawlStatus" => Some(serde_json::json!({ TYPE_COLUMN: "" })),
+        "paymentValidity" | "zakatableRatio" | "inaccessibleFraction" => Some(Value::String(String::new())),
+        _ => None,
+    }
+}
+
+/// Flattens a field for a cell: scalars as text, single-tag enums as the tag, the rest as JSON.
+fn cell_from_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::Object(map) if map.len() == 1 && map.get(TYPE_COLUMN).is_some_and(Value::is_string) => {
+            map[TYPE_COLUMN].as_str().unwrap_or_default().to_string()
+        }
+        _ => value.to_string(),
+    }
+}
+
+/// Reads a cell back using the template's value for that field to pick the JSON shape.
+fn value_from_cell(cell: &str, template: &Value) -> Result<Value, String> {
+    let looks_like_json = cell.starts_with('{') || cell.starts_with('[');
+    match template {
+        Value::Bool(_) => match cell.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "1" => Ok(Value::Bool(true)),
+            "false" | "no" | "0" => Ok(Value::Bool(false)),
+            _ => Err(format!("expected true or false, got '{}'", cell)),
+        },
+        Value::Number(_) => serde_json::from_str::<serde_json::Number>(cell)
+            .map(Value::Number)
+            .map_err(|_| format!("expected a whole number, got '{}'", cell)),
+        Value::Object(map) if !looks_like_json && map.contains_key(TYPE_COLUMN) => {
+            Ok(serde_json::json!({ TYPE_COLUMN: cell }))
+        }
+        Value::Object(_) | Value::Array(_) => serde_json::from_str(cell).map_err(|e| e.to_string()),
+        Value::Null if looks_like_json => serde_json::from_str(cell).map_err(|e| e.to_string()),
+        Value::String(_) | Value::Null => Ok(Value::String(cell.to_string())),
+    }
+}
+
+/// Splits CSV text into records, honouring quoted fields with commas, quotes and line breaks.
+fn parse_records(content: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+/// Quotes a CSV field when it contains commas, quotes or line breaks.
+pub fn escape_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn csv_error(row: usize, field: &str, reason_key: &str, details: String) -> ZakatError {
+    ZakatError::InvalidInput(Box::new(InvalidInputDetails {
+        field: field.to_string(),
+        value: details.clone(),
+        reason_key: reason_key.to_string(),
+        args: Some(HashMap::from([
+            ("row".to_string(), row.to_string()),
+            ("field".to_string(), field.to_string()),
+            ("details".to_string(), details),
+        ])),
+        source_label: Some(format!("CSV row {}", row)),
+        ..Default::default()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ZakatConfig;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_every_asset_type_round_trips() {
+        let mut portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(10_000).label("Shop, Main St").hawl(true).add_liability("Supplier \"A\"", 500))
+            .add_gold(100, 22)
+            .add(IncomeZakatCalculator::new().income(12_000).prorated(6).label("Salary"))
+            .add(LivestockAssets::new().animal_type(LivestockType::Sheep).count(50))
+            .add(AgricultureAssets::new().harvest_weight(1_000).price(2).irrigation(IrrigationMethod::Rain))
+            .add(InvestmentAssets::new().value(8_000).label("Index fund"))
+            .add(MiningAssets::new().value(3_000))
+            .add(crate::fitrah::FitrahCalculator::new(4, 3, Some(2.5)).unwrap())
+            .add(CustomAsset::new("Royalties", 9_000, 0.025, 7_225));
+        let shop = CalculateZakat::get_id(&portfolio.get_items()[0]);
+        portfolio.set_group(shop, "Business").unwrap();
+
+        let csv = portfolio.to_csv().unwrap();
+        let restored = ZakatPortfolio::from_csv(csv.as_bytes()).unwrap();
+
+        let as_json = |p: &ZakatPortfolio| serde_json::to_value(p.get_items()).unwrap();
+        assert_eq!(as_json(&restored), as_json(&portfolio));
+        assert_eq!(restored.group_of(shop), Some("Business"));
+
+        let config = ZakatConfig::test_default();
+        assert_eq!(restored.calculate_total(&config).total_zakat_due, portfolio.calculate_total(&config).total_zakat_due);
+    }
+
+    #[test]
+    fn test_unknown_hawl_and_optional_fields_round_trip() {
+        let portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(10_000).hawl_status(HawlStatus::Unknown).inaccessible_fraction(0.25))
+            .add(InvestmentAssets::new().value(8_000).zakatable_ratio(0.4));
+        assert_eq!(portfolio.validate_all().len(), 1);
+
+        let csv = portfolio.to_csv().unwrap();
+        assert!(csv.contains("unknown"));
+        let restored = ZakatPortfolio::from_csv(csv.as_bytes()).unwrap();
+
+        assert_eq!(restored.validate_all().len(), 1, "the Unknown Hawl still blocks calculation");
+        let as_json = |p: &ZakatPortfolio| serde_json::to_value(p.get_items()).unwrap();
+        assert_eq!(as_json(&restored), as_json(&portfolio));
+    }
+
+    #[test]
+    fn test_hand_written_rows_ignore_unknown_columns() {
+        let csv = "type,Notes,cashOnHand,weightGrams,metalType,purity,label\n\
+                   business,ignored,1000.50,,,,Till\n\
+                   preciousMetals,,,85,gold,24,\n";
+
+        let portfolio = ZakatPortfolio::from_csv(csv.as_bytes()).unwrap();
+
+        assert_eq!(portfolio.get_items().len(), 2);
+        assert_eq!(CalculateZakat::get_label(&portfolio.get_items()[0]).as_deref(), Some("Till"));
+        assert!(matches!(&portfolio.get_items()[1], PortfolioItem::PreciousMetals(m) if m.weight_grams == rust_decimal_macros::dec!(85)));
+    }
+
+    #[test]
+    fn test_missing_required_field_names_row_and_field() {
+        let csv = "type,cashOnHand,weightGrams\nbusiness,100,\npreciousMetals,,50\n";
+
+        let err = ZakatPortfolio::from_csv(csv.as_bytes()).unwrap_err();
+
+        match err {
+            ZakatError::InvalidInput(details) => {
+                assert_eq!(details.field, "metalType");
+                assert_eq!(details.args.unwrap()["row"], "3");
+            }
+            other => panic!("Expected InvalidInput, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_required_columns_match_json_import() {
+        let csv = "type,inventoryValue,value,rate,nisabThreshold
+business,5000,,,
+custom,,100,0.025,50
+";
+        let portfolio = ZakatPortfolio::from_csv(csv.as_bytes()).expect("business and unlabelled custom rows");
+        assert_eq!(portfolio.get_items().len(), 2);
+
+        let no_price = "type,personCount
+fitrah,4
+";
+        match ZakatPortfolio::from_csv(no_price.as_bytes()).unwrap_err() {
+            ZakatError::InvalidInput(details) => assert_eq!(details.field, "pricePerUnit"),
+            other => panic!("Expected InvalidInput, got {:?}", other),
+        }
+    }
+}
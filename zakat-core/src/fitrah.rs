@@ -2,6 +2,7 @@
 //!
 //! Calculates Zakat al-Fitr (Fitrah), the obligatory charity paid before Eid al-Fitr.
 
+use chrono::{NaiveDateTime, TimeDelta};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Serialize, Deserialize};
@@ -10,6 +11,51 @@ use crate::traits::{CalculateZakat, ZakatConfigArgument};
 use crate::config::ZakatConfig;
 use crate::inputs::IntoZakatDecimal;
 
+/// How many days before the Eid prayer Fitrah may be paid.
+///
+/// Ibn Umar paid a day or two before Eid (Sahih Bukhari 1511). The Shafi'i school
+/// also allows paying from the start of Ramadan; this follows the narrower majority view.
+pub const EARLY_PAYMENT_WINDOW_DAYS: i64 = 2;
+
+/// Whether a payment's timing lets it count as Zakat al-Fitr.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum FitrahValidity {
+    /// Within the early window and before the Eid prayer.
+    Valid,
+    /// After the Eid prayer. The payment is still owed, but it counts as ordinary
+    /// Sadaqah rather than Fitrah (Sunan Abi Dawud 1609).
+    LateButAccepted,
+    /// Earlier than the permitted window before Eid.
+    TooEarly,
+}
+
+impl FitrahValidity {
+    /// Explanation suitable for a recommendation note.
+    pub fn note(&self) -> &'static str {
+        match self {
+            FitrahValidity::Valid => "Paid before the Eid prayer: counts as Zakat al-Fitr.",
+            FitrahValidity::LateButAccepted => {
+                "Paid after the Eid prayer: counts as ordinary Sadaqah, not Zakat al-Fitr."
+            }
+            FitrahValidity::TooEarly => {
+                "Paid too early: most scholars allow paying only a day or two before Eid."
+            }
+        }
+    }
+}
+
+/// Classifies a Fitrah payment by when it was made relative to the Eid prayer.
+pub fn is_within_window(paid: NaiveDateTime, eid_prayer: NaiveDateTime) -> FitrahValidity {
+    if paid >= eid_prayer {
+        FitrahValidity::LateButAccepted
+    } else if eid_prayer - paid > TimeDelta::days(EARLY_PAYMENT_WINDOW_DAYS) {
+        FitrahValidity::TooEarly
+    } else {
+        FitrahValidity::Valid
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct FitrahCalculator {
@@ -17,6 +63,9 @@ pub struct FitrahCalculator {
     pub price_per_unit: Decimal,
     pub unit_amount: Decimal,
     pub label: Option<String>,
+    /// Timing of the payment, set by [`FitrahCalculator::paid_at`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_validity: Option<FitrahValidity>,
     id: uuid::Uuid,
 }
 
@@ -56,6 +105,7 @@ impl FitrahCalculator {
             price_per_unit: price,
             unit_amount: amount,
             label: None,
+            payment_validity: None,
             id: uuid::Uuid::new_v4(),
         })
     }
@@ -64,6 +114,12 @@ impl FitrahCalculator {
         self.label = Some(label.into());
         self
     }
+
+    /// Records when the payment was made, adding a timing note to the result.
+    pub fn paid_at(mut self, paid: NaiveDateTime, eid_prayer: NaiveDateTime) -> Self {
+        self.payment_validity = Some(is_within_window(paid, eid_prayer));
+        self
+    }
 }
 
 impl CalculateZakat for FitrahCalculator {
@@ -82,13 +138,18 @@ impl CalculateZakat for FitrahCalculator {
             })))?;
 
         // Build calculation trace
-        let trace = vec![
+        let mut trace = vec![
             crate::types::CalculationStep::initial("step-person-count", "Person Count", total_people_decimal),
             crate::types::CalculationStep::initial("step-amount-per-person", "Amount per Person (kg)", self.unit_amount),
             crate::types::CalculationStep::initial("step-price-per-kg", "Price per kg", self.price_per_unit),
             crate::types::CalculationStep::info("info-fitrah-obligatory", "Fitrah is obligatory - no Nisab threshold"),
             crate::types::CalculationStep::result("step-total-fitrah-due", "Total Fitrah Due", total_value),
         ];
+        let mut notes = Vec::new();
+        if let Some(validity) = self.payment_validity {
+            trace.push(crate::types::CalculationStep::info("info-fitrah-timing", validity.note()));
+            notes.push(validity.note().to_string());
+        }
 
         #[allow(deprecated)]
         Ok(ZakatDetails {
@@ -97,10 +158,12 @@ impl CalculateZakat for FitrahCalculator {
             liabilities: Vec::new(),
             net_assets: total_value,
             nisab_threshold: Decimal::ZERO, 
+            nisab_metal: None,
             is_payable: true, // Fitrah is obligatory
             zakat_due: total_value,
             wealth_type: crate::types::WealthType::Fitrah,
             status_reason: None,
+            status_reason_key: None,
             label: self.label.clone(),
             asset_id: Some(self.id),
             payload: crate::types::PaymentPayload::Monetary(total_value),
@@ -108,7 +171,7 @@ impl CalculateZakat for FitrahCalculator {
             warnings: Vec::new(),
             structured_warnings: Vec::new(),
             recommendation: crate::types::ZakatRecommendation::None,
-            notes: Vec::new(),
+            notes,
         })
     }
 
@@ -119,6 +182,11 @@ impl CalculateZakat for FitrahCalculator {
     fn get_id(&self) -> uuid::Uuid {
         self.id
     }
+
+    fn zakat_rate(&self, _config: &crate::config::ZakatConfig) -> Decimal {
+        // A fixed amount per person, not a share of wealth.
+        Decimal::ZERO
+    }
 }
 
 /// Calculates Zakat Fitrah.
@@ -171,4 +239,34 @@ mod tests {
         let res = calc.calculate_zakat(&ZakatConfig::default()).unwrap();
         assert_eq!(res.zakat_due, dec!(25)); // 2 * 2.5 * 5 = 25
     }
+
+    fn at(date: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn test_payment_well_before_eid_is_too_early() {
+        let eid_prayer = at("2025-03-30 07:00");
+        assert_eq!(is_within_window(at("2025-03-10 12:00"), eid_prayer), FitrahValidity::TooEarly);
+    }
+
+    #[test]
+    fn test_payment_in_early_window_is_valid() {
+        let eid_prayer = at("2025-03-30 07:00");
+        assert_eq!(is_within_window(at("2025-03-28 20:00"), eid_prayer), FitrahValidity::Valid);
+        assert_eq!(is_within_window(at("2025-03-30 06:30"), eid_prayer), FitrahValidity::Valid);
+    }
+
+    #[test]
+    fn test_payment_after_prayer_is_late_and_noted() {
+        let eid_prayer = at("2025-03-30 07:00");
+        assert_eq!(is_within_window(at("2025-03-30 09:00"), eid_prayer), FitrahValidity::LateButAccepted);
+
+        let res = FitrahCalculator::new(1, 10, None::<Decimal>)
+            .unwrap()
+            .paid_at(at("2025-03-30 09:00"), eid_prayer)
+            .calculate_zakat(&ZakatConfig::default())
+            .unwrap();
+        assert_eq!(res.notes, vec![FitrahValidity::LateButAccepted.note().to_string()]);
+    }
 }
@@ -369,6 +369,88 @@ impl Default for HawlTracker {
     }
 }
 
+// =============================================================================
+// Hawl Status
+// =============================================================================
+
+/// What is known about whether an asset has been held for a full Hawl.
+///
+/// Unlike a plain `bool`, this can say "we don't know", which is a reason to ask
+/// the user rather than to report the asset as exempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(tag = "type", content = "date", rename_all = "camelCase")]
+pub enum HawlStatus {
+    /// A lunar year has passed.
+    Satisfied,
+    /// The asset has been held for less than a lunar year.
+    NotSatisfied,
+    /// Not known yet; calculation is blocked until it is resolved.
+    Unknown,
+    /// Worked out from the acquisition date on each calculation.
+    DerivedFrom(NaiveDate),
+}
+
+impl HawlStatus {
+    /// Whether the Hawl is satisfied on `today`, or `None` when `Unknown`.
+    pub fn is_satisfied_on(&self, today: NaiveDate) -> Option<bool> {
+        match self {
+            HawlStatus::Satisfied => Some(true),
+            HawlStatus::NotSatisfied => Some(false),
+            HawlStatus::Unknown => None,
+            HawlStatus::DerivedFrom(date) => Some(HawlTracker::new(today).acquired_on(*date).is_satisfied()),
+        }
+    }
+}
+
+impl From<bool> for HawlStatus {
+    fn from(satisfied: bool) -> Self {
+        if satisfied { HawlStatus::Satisfied } else { HawlStatus::NotSatisfied }
+    }
+}
+
+// =============================================================================
+// Hawl Mode
+// =============================================================================
+
+/// How an asset's Hawl is applied when calculating.
+///
+/// `Full` is the classical rule: nothing is due until a lunar year has passed.
+/// `Prorated` lets people who prepay (e.g. monthly from salary) pay on the
+/// accumulated amount now, scaled by the share of the year elapsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum HawlMode {
+    /// Zakat is due only once the Hawl is satisfied.
+    #[default]
+    Full,
+    /// Zakat is prepaid at `months_elapsed / 12` of the annual rate.
+    #[serde(rename_all = "camelCase")]
+    Prorated { months_elapsed: u8 },
+}
+
+impl HawlMode {
+    /// Share of the annual rate that applies: 1 for `Full`, `months_elapsed / 12` otherwise.
+    pub fn factor(&self) -> Decimal {
+        match self {
+            HawlMode::Full => Decimal::ONE,
+            HawlMode::Prorated { months_elapsed } => Decimal::from(*months_elapsed) / Decimal::from(12),
+        }
+    }
+}
+
+impl crate::inputs::ToFfiString for HawlMode {
+    fn to_ffi_string(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+impl crate::inputs::FromFfiString for HawlMode {
+    type Err = serde_json::Error;
+    fn from_ffi_string(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,6 +308,84 @@ pub enum InputLocale {
     EasternArabic,
 }
 
+impl InputLocale {
+    /// Returns the `(decimal, grouping)` separators this locale expects.
+    pub fn separators(&self) -> (char, char) {
+        match self {
+            InputLocale::US => ('.', ','),
+            InputLocale::EU => (',', '.'),
+            InputLocale::EasternArabic => ('٫', '٬'),
+        }
+    }
+
+    /// Picks the number format for a language tag such as `de`, `id-ID` or `en_US`.
+    ///
+    /// Languages that write `1.000,50` map to `EU`, Arabic and Persian to
+    /// `EasternArabic`; anything else (including unknown tags) is `US`.
+    pub fn from_language_tag(tag: &str) -> Self {
+        let language = tag.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+        match language.as_str() {
+            "de" | "fr" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "ru" | "da" | "sv" | "nb" | "pl" => InputLocale::EU,
+            "ar" | "fa" => InputLocale::EasternArabic,
+            _ => InputLocale::US,
+        }
+    }
+}
+
+/// Parses a number typed with the given decimal and grouping separators.
+///
+/// Grouping separators are dropped and the decimal separator becomes `.`
+/// before `Decimal` parsing, so `"1.000,50"` with `(',', '.')` and `"1,000.50"`
+/// with `('.', ',')` both give `1000.50`. Arabic-Indic digits, surrounding
+/// whitespace and spaces used as grouping are accepted too.
+///
+/// Grouping must sit between thousands: after a leading group of 1-3 digits
+/// every group has exactly 3, and none may follow the decimal separator. This
+/// rejects a number typed in the other convention, e.g. `"1000.5"` when `,` is
+/// the decimal separator.
+pub fn parse_decimal_with_separators(value: &str, decimal: char, grouping: char) -> Result<Decimal, ZakatError> {
+    let parse_error = |details: String| ZakatError::InvalidInput(Box::new(InvalidInputDetails {
+        field: "localized_input".to_string(),
+        value: value.chars().take(20).collect(),
+        reason_key: "error-parse-locale".to_string(),
+        args: Some(std::collections::HashMap::from([
+            ("locale".to_string(), format!("1{}000{}00", grouping, decimal)),
+            ("details".to_string(), details),
+        ])),
+        suggestion: Some(format!("Use '{}' for decimals and '{}' between thousands (e.g., 1{}000{}50).", decimal, grouping, grouping, decimal)),
+        ..Default::default()
+    }));
+
+    if value.len() > MAX_INPUT_LEN {
+        return Err(parse_error(format!("input longer than {} characters", MAX_INPUT_LEN)));
+    }
+
+    let trimmed = normalize_arabic_numerals(value.trim());
+    let is_grouping = |c: char| c == grouping || c.is_whitespace();
+
+    let mut parts = trimmed.splitn(2, decimal);
+    let integer_part = parts.next().unwrap_or("");
+    if parts.next().is_some_and(|fraction| fraction.contains(is_grouping)) {
+        return Err(parse_error(format!("'{}' after the decimal separator", grouping)));
+    }
+    if integer_part.contains(is_grouping) {
+        let unsigned = integer_part.trim_start_matches(['-', '+']);
+        let mut groups = unsigned.split(is_grouping);
+        let leading_ok = groups.next().is_some_and(|g| (1..=3).contains(&g.len()));
+        if !leading_ok || !groups.all(|g| g.len() == 3) {
+            return Err(parse_error(format!("'{}' must separate groups of three digits", grouping)));
+        }
+    }
+
+    let normalized: String = trimmed
+        .chars()
+        .filter(|c| !is_grouping(*c))
+        .map(|c| if c == decimal { '.' } else { c })
+        .collect();
+
+    Decimal::from_str(&normalized).map_err(|e| parse_error(e.to_string()))
+}
+
 /// A string input with an explicit locale for unambiguous parsing.
 ///
 /// This struct is used with the `with_locale` helper function to specify
@@ -538,6 +616,39 @@ mod tests {
         assert_eq!(result, Decimal::from_str("12.50").unwrap());
     }
 
+    #[test]
+    fn test_configured_separators_agree_across_locales() {
+        let (de_decimal, de_grouping) = InputLocale::from_language_tag("de").separators();
+        let (en_decimal, en_grouping) = InputLocale::from_language_tag("en-US").separators();
+
+        let de = parse_decimal_with_separators("1.000,50", de_decimal, de_grouping).unwrap();
+        let en = parse_decimal_with_separators("1,000.50", en_decimal, en_grouping).unwrap();
+
+        assert_eq!(de, Decimal::from_str("1000.50").unwrap());
+        assert_eq!(de, en);
+        // A dot is a grouping separator in German, so it must not be read as a decimal point.
+        assert_eq!(parse_decimal_with_separators("1.000", de_decimal, de_grouping).unwrap(), Decimal::from(1000));
+        assert!(parse_decimal_with_separators("abc", en_decimal, en_grouping).is_err());
+    }
+
+    #[test]
+    fn test_grouping_separator_positions_are_validated() {
+        let (de_decimal, de_grouping) = InputLocale::from_language_tag("de").separators();
+        let (en_decimal, en_grouping) = InputLocale::from_language_tag("en-US").separators();
+
+        // A US-style number typed while `,` is the decimal separator.
+        assert!(parse_decimal_with_separators("1000.5", de_decimal, de_grouping).is_err());
+        assert!(parse_decimal_with_separators("1.5", de_decimal, de_grouping).is_err());
+        assert!(parse_decimal_with_separators("1,000.50", de_decimal, de_grouping).is_err());
+        assert!(parse_decimal_with_separators("1,00", en_decimal, en_grouping).is_err());
+        assert!(parse_decimal_with_separators("10,00,000", en_decimal, en_grouping).is_err());
+
+        assert_eq!(parse_decimal_with_separators("1.234.567,5", de_decimal, de_grouping).unwrap(), Decimal::from_str("1234567.5").unwrap());
+        assert_eq!(parse_decimal_with_separators("-12,345.6", en_decimal, en_grouping).unwrap(), Decimal::from_str("-12345.6").unwrap());
+        assert_eq!(parse_decimal_with_separators("1 000 000", en_decimal, en_grouping).unwrap(), Decimal::from(1_000_000));
+        assert_eq!(parse_decimal_with_separators("1000,5", de_decimal, de_grouping).unwrap(), Decimal::from_str("1000.5").unwrap());
+    }
+
     #[test]
     fn test_localized_input_eastern_arabic() {
         // Eastern Arabic numerals with Arabic decimal separator
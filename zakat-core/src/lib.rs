@@ -18,6 +18,7 @@ pub mod types;
 pub mod utils;
 pub mod fitrah;
 pub mod portfolio;
+pub mod csv;
 pub mod assets;
 pub mod hawl;
 pub mod debt;
@@ -25,14 +26,17 @@ pub mod distribution;
 pub mod partnership;
 pub mod validation;
 
+#[cfg(feature = "result-cache")]
+pub mod cache;
+
 pub mod prelude;
 
 // Re-export key types at crate root
 pub use config::ZakatConfig;
-pub use inputs::{IntoZakatDecimal, InputLocale, LocalizedInput, with_locale};
-pub use madhab::{Madhab, NisabStandard, ZakatRules, ZakatStrategy};
-pub use traits::{CalculateZakat, ZakatConfigArgument};
-pub use types::{WealthType, ZakatDetails, ZakatError, ZakatExplanation};
+pub use inputs::{IntoZakatDecimal, InputLocale, LocalizedInput, with_locale, parse_decimal_with_separators};
+pub use madhab::{Madhab, NisabStandard, SheepTierRuling, ZakatRules, ZakatStrategy};
+pub use traits::{CalculateZakat, DynCalculateZakat, ZakatConfigArgument};
+pub use types::{json_schema, Money, WealthType, ZakatDetails, ZakatError, ZakatExplanation};
 
 #[cfg(feature = "async")]
 pub use traits::AsyncCalculateZakat;
@@ -8,12 +8,19 @@
 //! ## Nisab
 //! - **Threshold**: 5 Awsuq. (Source: Sahih Muslim 979).
 //! - **Conversion**: Configurable, defaults to **653 kg** based on the research of Dr. Yusuf Al-Qaradawi (*Fiqh al-Zakah*).
+//! - **Rice in husk**: Stored with its husk, so the Nisab is doubled to 10 Awsuq (Shafi'i/Hanbali practice).
+//! - **In kind**: Without a crop price, the Nisab is checked against the harvest weight and the due is reported in kg.
+//!
+//! ## Storability
+//! - Only storable staples (grains, dates, raisins) are zakatable according to the majority.
+//! - Perishable produce (vegetables, fresh fruit) is zakatable only under the Hanafi school.
 
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use crate::types::{ZakatDetails, ZakatError};
 use serde::{Serialize, Deserialize};
 use crate::traits::{CalculateZakat, ZakatConfigArgument};
+use crate::madhab::Madhab;
 use crate::validation::Validator;
 
 use crate::inputs::IntoZakatDecimal;
@@ -28,12 +35,44 @@ pub enum IrrigationMethod {
     Mixed, // Both, 7.5%
 }
 
+/// Category of the harvested crop, deciding its Nisab weight and storability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum CropCategory {
+    /// Storable grains such as wheat, barley or maize.
+    #[default]
+    Grain,
+    /// Dates and raisins, dried and stored.
+    DriedFruit,
+    /// Rice stored in its husk; the Nisab is doubled to account for the husk weight.
+    RiceInHusk,
+    /// Vegetables and fresh fruit that cannot be stored.
+    Perishable,
+}
+
+impl CropCategory {
+    /// Multiplier applied to the configured Nisab weight.
+    pub fn nisab_multiplier(&self) -> Decimal {
+        match self {
+            CropCategory::RiceInHusk => dec!(2),
+            _ => Decimal::ONE,
+        }
+    }
+
+    /// Whether the produce can be stored as a staple.
+    pub fn is_storable(&self) -> bool {
+        !matches!(self, CropCategory::Perishable)
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AgricultureAssets {
     pub harvest_weight_kg: Decimal,
     pub price_per_kg: Decimal,
     pub irrigation: IrrigationMethod,
+    #[serde(default)]
+    pub crop_type: CropCategory,
     pub cultivation_costs: Decimal, // Expenses deductible from gross value
     pub liabilities_due_now: Decimal,
     pub hawl_satisfied: bool,
@@ -88,6 +127,11 @@ impl AgricultureAssets {
         self
     }
 
+    pub fn crop_type(mut self, crop_type: CropCategory) -> Self {
+        self.crop_type = crop_type;
+        self
+    }
+
     /// Sets the cultivation costs (fertilizer, labor, seeds, etc.) which are deductible.
     /// Zakat is paid on the Net Value (Gross - Costs) according to Ibn Abbas/Ibn Masud view.
     pub fn costs(mut self, amount: impl IntoZakatDecimal) -> Self {
@@ -117,6 +161,16 @@ impl AgricultureAssets {
     pub fn validate(&self) -> Result<(), ZakatError> {
         Ok(())
     }
+
+    /// Rate for this irrigation method from the strategy's `agriculture_rates`.
+    fn irrigation_rate(&self, config: &crate::config::ZakatConfig) -> Decimal {
+        let (rain_fed, irrigated, mixed) = config.strategy.get_rules().agriculture_rates;
+        match self.irrigation {
+            IrrigationMethod::Rain => rain_fed,
+            IrrigationMethod::Irrigated => irrigated,
+            IrrigationMethod::Mixed => mixed,
+        }
+    }
 }
 
 impl CalculateZakat for AgricultureAssets {
@@ -133,13 +187,18 @@ impl CalculateZakat for AgricultureAssets {
             ("price", self.price_per_kg)
         ], self.label.clone())?;
 
-        let rate = match self.irrigation {
-            IrrigationMethod::Rain => dec!(0.10),
-            IrrigationMethod::Irrigated => dec!(0.05),
-            IrrigationMethod::Mixed => dec!(0.075),
-        };
+        let rate = self.irrigation_rate(config);
         
-        let nisab_threshold_kg = config.get_nisab_agriculture_kg();
+        let nisab_threshold_kg = ZakatDecimal::new(config.get_nisab_agriculture_kg())
+            .checked_mul(self.crop_type.nisab_multiplier())?
+            .with_source(self.label.clone())
+            .value;
+
+        // Perishable produce is only zakatable under the Hanafi school.
+        let exempt_as_perishable = !self.crop_type.is_storable() && config.madhab != Madhab::Hanafi;
+
+        // Without a price the harvest can only be assessed and paid in kind.
+        let in_kind = self.price_per_kg == Decimal::ZERO;
 
         let total_value = ZakatDecimal::new(self.harvest_weight_kg)
             .checked_mul(self.price_per_kg)?
@@ -152,13 +211,8 @@ impl CalculateZakat for AgricultureAssets {
         let liabilities = self.liabilities_due_now;
         
         // Fiqh Note: Agriculture Nisab is based on the Harvest Quantity (5 Wasqs).
-        // However, calculation is done on the monetary value for consistency.
-        // We check if (Net Value) >= (Nisab Quantity Value) to determine payability.
-        
-        // Use *total_value to get Decimal for creating ZakatDecimal again, or implement methods on ZakatDecimal to take ZakatDecimal
-        // checked_sub takes impl Into<Decimal>, so passing ZakatDecimal works (it implements Into<Decimal>).
-        // Use *total_value to get Decimal for creating ZakatDecimal again, or implement methods on ZakatDecimal to take ZakatDecimal
-        // checked_sub takes impl Into<Decimal>, so passing ZakatDecimal works (it implements Into<Decimal>).
+        // When a price is known, calculation is done on the monetary value for consistency:
+        // we check if (Net Value) >= (Nisab Quantity Value) to determine payability.
         let gross_value = total_value.clone();
         
         // Net Value = Gross - Cultivation Costs.
@@ -177,7 +231,16 @@ impl CalculateZakat for AgricultureAssets {
              .checked_sub(liabilities)?
              .with_source(self.label.clone());
 
-        let zakat_due = if net_value_final.value >= nisab_value.value {
+        let meets_nisab = if in_kind {
+            self.harvest_weight_kg >= nisab_threshold_kg
+        } else {
+            net_value_final.value >= nisab_value.value
+        };
+
+        // Cash Zakat due: the harvest value net of costs and debts, times the rate.
+        // Zero when paid in kind, below Nisab or exempt; `due_kg` carries the in-kind
+        // share instead. Also reported as `PaymentPayload::Agriculture::crop_value`.
+        let zakat_due = if meets_nisab && !exempt_as_perishable && !in_kind {
              net_value_final.clone()
                  .checked_mul(rate)?
                  .with_source(self.label.clone())
@@ -185,7 +248,23 @@ impl CalculateZakat for AgricultureAssets {
              ZakatDecimal::default()
         };
 
-        let is_payable = zakat_due.value > Decimal::ZERO;
+        // Weight of the harvest due. In kind, costs and debts cannot be valued, so the
+        // rate applies to the whole harvest.
+        let due_kg = if !meets_nisab || exempt_as_perishable {
+            Decimal::ZERO
+        } else if in_kind {
+            ZakatDecimal::new(self.harvest_weight_kg)
+                .checked_mul(rate)?
+                .with_source(self.label.clone())
+                .value
+        } else {
+            ZakatDecimal::new(zakat_due.value)
+                .checked_div(self.price_per_kg)?
+                .with_source(self.label.clone())
+                .value
+        };
+
+        let is_payable = if in_kind { due_kg > Decimal::ZERO } else { zakat_due.value > Decimal::ZERO };
 
         // Build calculation trace
         let irrigation_desc = match self.irrigation {
@@ -196,28 +275,56 @@ impl CalculateZakat for AgricultureAssets {
         
         let mut trace = vec![
             crate::types::CalculationStep::initial("step-harvest-weight", "Harvest Weight (kg)", self.harvest_weight_kg),
-            crate::types::CalculationStep::initial("step-price-per-kg", "Price per kg", self.price_per_kg),
-            crate::types::CalculationStep::result("step-total-harvest-value", "Gross Harvest Value", total_value.value),
         ];
 
-        if cultivation_costs > Decimal::ZERO {
-             trace.push(crate::types::CalculationStep::subtract("step-deduct-costs", "Cultivation Costs", cultivation_costs));
-             trace.push(crate::types::CalculationStep::result("step-net-after-costs", "Net Value (After Costs)", net_value.value));
+        if !in_kind {
+            trace.push(crate::types::CalculationStep::initial("step-price-per-kg", "Price per kg", self.price_per_kg));
+            trace.push(crate::types::CalculationStep::result("step-total-harvest-value", "Gross Harvest Value", total_value.value));
+
+            if cultivation_costs > Decimal::ZERO {
+                 trace.push(crate::types::CalculationStep::subtract("step-deduct-costs", "Cultivation Costs", cultivation_costs));
+                 trace.push(crate::types::CalculationStep::result("step-net-after-costs", "Net Value (After Costs)", net_value.value));
+            }
+
+            trace.push(crate::types::CalculationStep::subtract("step-debts-due-now", "Liabilities Due Now", liabilities));
+            trace.push(crate::types::CalculationStep::result("step-final-net-value", "Final Net Value", net_value_final.value));
         }
 
-        trace.push(crate::types::CalculationStep::subtract("step-debts-due-now", "Liabilities Due Now", liabilities));
-        trace.push(crate::types::CalculationStep::result("step-final-net-value", "Final Net Value", net_value_final.value));
-        trace.push(crate::types::CalculationStep::compare("step-nisab-check-value", "Nisab Threshold (653kg value)", nisab_value.value));
+        trace.push(crate::types::CalculationStep::compare("step-nisab-check-kg", "Nisab Threshold (kg)", nisab_threshold_kg)
+            .with_args(std::collections::HashMap::from([
+                ("weight".to_string(), self.harvest_weight_kg.to_string()),
+                ("crop".to_string(), format!("{:?}", self.crop_type)),
+            ])));
+        if !in_kind {
+            trace.push(crate::types::CalculationStep::compare("step-nisab-check-value", format!("Nisab Threshold ({}kg value)", nisab_threshold_kg), nisab_value.value));
+        }
 
         if is_payable {
             trace.push(crate::types::CalculationStep::info("info-irrigation-method", format!("Irrigation Method: {}", irrigation_desc))
                  .with_args(std::collections::HashMap::from([("method".to_string(), irrigation_desc.to_string())])));
             trace.push(crate::types::CalculationStep::rate("step-rate-applied", "Applied Rate", rate));
-            trace.push(crate::types::CalculationStep::result("step-zakat-due", "Zakat Due", zakat_due.value));
+            if !in_kind {
+                trace.push(crate::types::CalculationStep::result("step-zakat-due", "Zakat Due", zakat_due.value));
+            }
+            trace.push(crate::types::CalculationStep::result("step-crop-due-kg", "Harvest Due In Kind (kg)", due_kg));
+            if !in_kind {
+                trace.push(crate::types::CalculationStep::result("step-crop-value", "Cash Value of Crop Due", zakat_due.value));
+            }
+        } else if exempt_as_perishable {
+            trace.push(crate::types::CalculationStep::info("status-exempt-perishable", "Perishable produce is not zakatable under this Madhab"));
         } else {
             trace.push(crate::types::CalculationStep::info("status-exempt", "Net Value below Nisab - No Zakat Due"));
         }
 
+        let mut warnings = Vec::new();
+        let mut notes = Vec::new();
+        if in_kind && is_payable {
+            notes.push(format!("No crop price given: pay {} kg of the harvest in kind.", due_kg));
+            if cultivation_costs > Decimal::ZERO || liabilities > Decimal::ZERO {
+                warnings.push("Cultivation costs and debts cannot be deducted without a crop price.".to_string());
+            }
+        }
+
         #[allow(deprecated)]
         Ok(ZakatDetails {
             total_assets: total_value.value,
@@ -225,10 +332,12 @@ impl CalculateZakat for AgricultureAssets {
             liabilities: Vec::new(),
             net_assets: net_value_final.value, 
             nisab_threshold: nisab_value.value, 
+            nisab_metal: None,
             is_payable,
             zakat_due: zakat_due.value,
             wealth_type: crate::types::WealthType::Agriculture,
             status_reason: None,
+            status_reason_key: None,
             label: self.label.clone(),
             asset_id: Some(self.id),
             payload: crate::types::PaymentPayload::Agriculture {
@@ -237,14 +346,14 @@ impl CalculateZakat for AgricultureAssets {
                 crop_value: zakat_due.value,
             },
             calculation_breakdown: crate::types::CalculationBreakdown(trace),
-            warnings: Vec::new(),
+            warnings,
             structured_warnings: Vec::new(),
             recommendation: if is_payable { 
                 crate::types::ZakatRecommendation::Obligatory 
             } else { 
                 crate::types::ZakatRecommendation::None 
             },
-            notes: Vec::new(),
+            notes,
         })
     }
 
@@ -255,6 +364,10 @@ impl CalculateZakat for AgricultureAssets {
     fn get_id(&self) -> uuid::Uuid {
         self.id
     }
+
+    fn zakat_rate(&self, config: &crate::config::ZakatConfig) -> Decimal {
+        self.irrigation_rate(config)
+    }
 }
 
 #[cfg(test)]
@@ -342,4 +455,119 @@ mod tests {
             _ => panic!("Expected Agriculture payload"),
         }
     }
+
+    #[test]
+    fn test_crop_value_matches_rain_fed_due() {
+        let config = ZakatConfig::default();
+        let res = AgricultureAssets::new()
+            .harvest_weight(2000)
+            .price(dec!(2.5))
+            .irrigation(IrrigationMethod::Rain)
+            .hawl(true)
+            .calculate_zakat(&config)
+            .unwrap();
+
+        // 2000kg * 2.5 * 10% = 500
+        assert_eq!(res.zakat_due, dec!(500));
+        match res.payload {
+            crate::types::PaymentPayload::Agriculture { crop_value, .. } => assert_eq!(crop_value, res.zakat_due),
+            _ => panic!("Expected Agriculture payload"),
+        }
+
+        let steps = &res.calculation_breakdown.0;
+        let due_kg = steps.iter().find(|s| s.key == "step-crop-due-kg").expect("in-kind step");
+        assert_eq!(due_kg.amount, Some(dec!(200)));
+        assert!(steps.iter().any(|s| s.key == "step-crop-value" && s.amount == Some(dec!(500))));
+    }
+
+    #[test]
+    fn test_crop_nisab_is_checked_in_kind_without_price() {
+        let config = ZakatConfig::default();
+        let wheat = AgricultureAssets::new()
+            .harvest_weight(1000)
+            .irrigation(IrrigationMethod::Rain)
+            .calculate_zakat(&config)
+            .unwrap();
+
+        assert!(wheat.is_payable);
+        assert_eq!(wheat.zakat_due, Decimal::ZERO);
+        let steps = &wheat.calculation_breakdown.0;
+        let nisab_kg = steps.iter().find(|s| s.key == "step-nisab-check-kg").expect("nisab step in kg");
+        assert_eq!(nisab_kg.amount, Some(dec!(653)));
+        let due_kg = steps.iter().find(|s| s.key == "step-crop-due-kg").expect("in-kind step");
+        assert_eq!(due_kg.amount, Some(dec!(100)));
+        match wheat.payload {
+            crate::types::PaymentPayload::Agriculture { harvest_weight, crop_value, .. } => {
+                assert_eq!(harvest_weight, dec!(1000));
+                assert_eq!(crop_value, Decimal::ZERO);
+            }
+            _ => panic!("Expected Agriculture payload"),
+        }
+
+        // Rice in husk needs twice the weight.
+        let rice = AgricultureAssets::new()
+            .harvest_weight(1000)
+            .crop_type(CropCategory::RiceInHusk)
+            .calculate_zakat(&config)
+            .unwrap();
+        assert!(!rice.is_payable);
+
+        let priced_rice = AgricultureAssets::new()
+            .harvest_weight(1400)
+            .price(2)
+            .crop_type(CropCategory::RiceInHusk)
+            .calculate_zakat(&config)
+            .unwrap();
+        assert_eq!(priced_rice.nisab_threshold, dec!(2612));
+        assert_eq!(priced_rice.zakat_due, dec!(280));
+    }
+
+    #[test]
+    fn test_perishable_produce_follows_madhab() {
+        use crate::madhab::Madhab;
+
+        let vegetables = AgricultureAssets::new()
+            .harvest_weight(1000)
+            .price(1)
+            .crop_type(CropCategory::Perishable);
+
+        let hanafi = vegetables.calculate_zakat(&ZakatConfig::default()).unwrap();
+        assert_eq!(hanafi.zakat_due, dec!(100));
+
+        let shafi = ZakatConfig { madhab: Madhab::Shafi, ..Default::default() };
+        let exempt = vegetables.calculate_zakat(&shafi).unwrap();
+        assert!(!exempt.is_payable);
+        assert!(exempt.calculation_breakdown.0.iter().any(|s| s.key == "status-exempt-perishable"));
+    }
+
+    #[test]
+    fn test_zakat_rate_reports_rate_before_calculating() {
+        use crate::madhab::{ZakatRules, ZakatStrategy};
+        use crate::maal::business::BusinessZakat;
+
+        #[derive(Debug)]
+        struct ThreePercent;
+        impl ZakatStrategy for ThreePercent {
+            fn get_rules(&self) -> ZakatRules {
+                ZakatRules::default()
+                    .with_trade_goods_rate(dec!(0.03))
+                    .with_agriculture_rates(dec!(0.10), dec!(0.04), dec!(0.07))
+            }
+        }
+
+        let config = ZakatConfig::test_default();
+        let irrigated = AgricultureAssets::new().irrigation(IrrigationMethod::Irrigated);
+        assert_eq!(irrigated.zakat_rate(&config), dec!(0.05));
+
+        let business = BusinessZakat::new().cash(1000);
+        assert_eq!(business.zakat_rate(&config), dec!(0.025));
+
+        let custom = config.with_strategy(std::sync::Arc::new(ThreePercent));
+        assert_eq!(business.zakat_rate(&custom), dec!(0.03));
+
+        // The rate reported is the rate applied, both taken from the strategy.
+        assert_eq!(irrigated.zakat_rate(&custom), dec!(0.04));
+        let harvest = irrigated.harvest_weight(1000).price(1).calculate_zakat(&custom).unwrap();
+        assert_eq!(harvest.zakat_due, dec!(40));
+    }
 }
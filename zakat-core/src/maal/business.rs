@@ -9,7 +9,7 @@
 //! - **Debts**: Deducting `liabilities_due_now` aligns with the principle of *Dayn al-Hal* (immediate debt) preventing Zakat, as supported by AAOIFI Standard 35.
 
 use rust_decimal::Decimal;
-use crate::types::{ZakatDetails, ZakatError, ErrorDetails};
+use crate::types::{ZakatDetails, ZakatError};
 use crate::math::ZakatDecimal;
 use serde::{Serialize, Deserialize};
 use crate::traits::{CalculateZakat, ZakatConfigArgument};
@@ -35,6 +35,10 @@ crate::zakat_ffi_export! {
         #[deprecated(since = "1.3.0", note = "Use `receivables_list` instead")]
         pub receivables: Decimal,
         pub receivables_list: Vec<crate::debt::ReceivableItem>,
+        /// Share of `cash_on_hand` (0 to 1) held where the owner cannot reach it,
+        /// e.g. a foreign account under withholding. Excluded from the base.
+        #[serde(default, skip_serializing_if = "Decimal::is_zero")]
+        pub inaccessible_fraction: Decimal,
         // Business-specific liabilities are now unified with `liabilities_due_now`
     }
 }
@@ -48,12 +52,14 @@ impl Default for BusinessZakat {
             inventory_value: Decimal::ZERO,
             receivables: Decimal::ZERO,
             receivables_list: Vec::new(),
+            inaccessible_fraction: Decimal::ZERO,
             liabilities_due_now,
             named_liabilities,
             hawl_satisfied,
             label,
             id,
             acquisition_date,
+            hawl_status: None,
             _input_errors,
         }
     }
@@ -131,6 +137,20 @@ impl BusinessZakat {
         }
         self
     }
+    /// Sets the share of cash (0 to 1) that cannot be accessed, such as funds in
+    /// a foreign account held back by withholding.
+    ///
+    /// Like a locked retirement fund, wealth without full possession (*Milk Tam*)
+    /// is not zakatable yet; that share is excluded with a warning, and Zakat is
+    /// paid on it once it becomes accessible.
+    pub fn inaccessible_fraction(mut self, fraction: impl IntoZakatDecimal) -> Self {
+        match fraction.into_zakat_decimal() {
+            Ok(v) => self.inaccessible_fraction = v,
+            Err(e) => self._input_errors.push(e),
+        }
+        self
+    }
+
     /// Sets short-term business liabilities (deducted from gross assets).
     /// 
     /// If the value cannot be converted to a valid decimal, the error is
@@ -146,11 +166,42 @@ impl BusinessZakat {
     }
 }
 
+impl BusinessZakat {
+    /// Returns an error for every invalid field (see `validate()`).
+    #[allow(deprecated)] // Checks deprecated `liabilities_due_now` for backward compat
+    fn field_errors(&self) -> Vec<ZakatError> {
+        let mut errors = Validator::collect_negative(&[
+            ("cash_on_hand", self.cash_on_hand),
+            ("inventory_value", self.inventory_value),
+            ("liabilities", self.liabilities_due_now),
+        ], self.label.clone());
+        if self.inaccessible_fraction < Decimal::ZERO || self.inaccessible_fraction > Decimal::ONE {
+            errors.push(ZakatError::InvalidInput(Box::new(crate::types::InvalidInputDetails {
+                field: "inaccessible_fraction".to_string(),
+                value: self.inaccessible_fraction.to_string(),
+                reason_key: "error-inaccessible-fraction".to_string(),
+                source_label: self.label.clone(),
+                suggestion: Some("Enter the share as a fraction, e.g. 0.2 for 20%.".to_string()),
+                ..Default::default()
+            })));
+        }
+        errors
+    }
+}
+
 impl CalculateZakat for BusinessZakat {
     fn validate_input(&self) -> Result<(), ZakatError> {
         self.validate()
     }
 
+    fn preflight(&self, config: &crate::config::ZakatConfig) -> Result<(), ZakatError> {
+        self.validate()?;
+        config.require_monetary_prices(self.label.clone())
+    }
+
+    /// The Nisab is valued from gold/silver prices, even for pure cash.
+    fn requires_prices(&self) -> bool { true }
+
     #[allow(deprecated)]
     fn calculate_zakat<C: ZakatConfigArgument>(&self, config: C) -> Result<ZakatDetails, ZakatError> {
         // Validate deferred input errors first
@@ -177,30 +228,7 @@ impl CalculateZakat for BusinessZakat {
             })));
         }
 
-        // For LowerOfTwo or Silver standard, we need silver price too
-        let needs_silver = matches!(
-            config.cash_nisab_standard,
-            crate::madhab::NisabStandard::Silver | crate::madhab::NisabStandard::LowerOfTwo
-        );
-        
-        if config.gold_price_per_gram <= Decimal::ZERO && !needs_silver {
-            return Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
-                code: crate::types::ZakatErrorCode::ConfigError,
-                reason_key: "error-gold-price-required".to_string(),
-                source_label: self.label.clone(),
-                suggestion: Some("Run with --gold-price X or set ZAKAT_GOLD_PRICE env var.".to_string()),
-                ..Default::default()
-            })));
-        }
-        if needs_silver && config.silver_price_per_gram <= Decimal::ZERO {
-            return Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
-                code: crate::types::ZakatErrorCode::ConfigError,
-                reason_key: "error-silver-price-required".to_string(),
-                source_label: self.label.clone(),
-                suggestion: Some("Run with --silver-price X or set ZAKAT_SILVER_PRICE env var.".to_string()),
-                ..Default::default()
-            })));
-        }
+        config.require_monetary_prices(self.label.clone())?;
         
         // Dynamic Nisab threshold based on config (Gold, Silver, or LowerOfTwo)
         let nisab_threshold_value = config.get_monetary_nisab_threshold();
@@ -210,31 +238,53 @@ impl CalculateZakat for BusinessZakat {
         
         // Calculate Total Receivables (Legacy + Strong Receivables from List)
         // Weak receivables are excluded.
-        let mut total_receivables = self.receivables; // Start with legacy field
-        let mut weak_receivables = Decimal::ZERO;
+        let mut total_receivables = ZakatDecimal::new(self.receivables) // Start with legacy field
+            .with_source(self.label.clone());
+        let mut weak_receivables = ZakatDecimal::new(Decimal::ZERO)
+            .with_source(self.label.clone());
 
         for item in &self.receivables_list {
             match item.quality {
                 crate::debt::ReceivableQuality::Strong => {
-                    total_receivables += item.amount;
+                    total_receivables = total_receivables.checked_add(item.amount)?;
                 }
                 crate::debt::ReceivableQuality::Weak => {
-                    weak_receivables += item.amount;
+                    weak_receivables = weak_receivables.checked_add(item.amount)?;
                 }
             }
         }
+        let (total_receivables, weak_receivables) = (*total_receivables, *weak_receivables);
         
+        // Cash the owner cannot reach (e.g. withheld abroad) is not yet zakatable
+        let inaccessible_cash = *ZakatDecimal::new(self.cash_on_hand)
+            .with_source(self.label.clone())
+            .checked_mul(self.inaccessible_fraction)?;
+
         let gross_assets = ZakatDecimal::new(self.cash_on_hand)
             .with_source(self.label.clone())
+            .checked_sub(inaccessible_cash)?
             .checked_add(self.inventory_value)?
             .checked_add(total_receivables)?;
         
         let mut trace_steps = vec![
             crate::types::CalculationStep::initial("step-cash-on-hand", "Cash on Hand", self.cash_on_hand)
                 .with_reference("Sunan Abu Dawud 1562"),
+        ];
+        let mut warnings = config.monetary_price_warnings();
+        if !inaccessible_cash.is_zero() {
+            trace_steps.push(
+                crate::types::CalculationStep::subtract("step-inaccessible-cash", "Inaccessible Cash (excluded)", inaccessible_cash)
+                    .with_note("No full possession (Milk Tam) until it can be withdrawn")
+            );
+            warnings.push(format!(
+                "{} of cash is inaccessible and was excluded; pay Zakat on it once it can be withdrawn.",
+                inaccessible_cash
+            ));
+        }
+        trace_steps.push(
             crate::types::CalculationStep::add("step-inventory-value", "Inventory Value", self.inventory_value)
                 .with_reference("Fiqh al-Zakah (Commercial Goods)"),
-        ];
+        );
 
         if !total_receivables.is_zero() {
             trace_steps.push(
@@ -250,33 +300,34 @@ impl CalculateZakat for BusinessZakat {
 
         trace_steps.push(crate::types::CalculationStep::result("step-gross-assets", "Gross Assets", *gross_assets));
 
-        // Calculate total liabilities (legacy + named)
-        let total_liabilities = self.total_liabilities();
+        // Calculate total liabilities (legacy + named) under the configured debt policy
+        let total_liabilities = self.checked_liabilities_under(config.debt_policy, config.as_of_date())?;
+        trace_steps.extend(self.debt_policy_step(config.debt_policy));
+        trace_steps.extend(config.monetary_nisab_step());
 
-        // Override hawl_satisfied if acquisition_date is present
-        let hawl_is_satisfied = if let Some(date) = self.acquisition_date {
-            let tracker = crate::hawl::HawlTracker::new(chrono::Local::now().date_naive())
-                .acquired_on(date);
-            tracker.is_satisfied()
-        } else {
-            self.hawl_satisfied
-        };        
+        // Explicit status first, then acquisition_date, then the hawl_satisfied flag
+        let hawl_is_satisfied = self.hawl_is_satisfied(config.as_of_date());
 
         let params = MonetaryCalcParams {
             total_assets: *gross_assets,
             liabilities: total_liabilities,
             nisab_threshold: nisab_threshold_value,
             rate,
+            rounding: config.rounding_for(&crate::types::WealthType::Business),
             wealth_type: crate::types::WealthType::Business,
             label: self.label.clone(),
             hawl_satisfied: hawl_is_satisfied,
             asset_id: Some(self.id),
             trace_steps,
-            warnings: Vec::new(),
+            warnings,
             observer: Some(config.observer.clone()),
+            minimum_payment: config.minimum_payment,
+            nisab_band: Some(config.nisab_band()),
+            liability_steps: self.liability_steps(config.debt_policy, config.as_of_date())?,
         };
 
-        let mut result = calculate_monetary_asset(params)?;
+        let mut result = calculate_monetary_asset(params)?
+            .with_nisab_metal(config.monetary_nisab_basis().0);
         
         if !weak_receivables.is_zero() {
              result.notes.push(format!("Excluded Weak Receivables: {}. Pay Zakat on this amount only upon receipt.", weak_receivables));
@@ -409,6 +460,82 @@ mod tests {
         assert_eq!(result.net_assets, dec!(8500));
     }
 
+    #[test]
+    fn test_only_debts_due_within_hawl_are_deducted() {
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let config = ZakatConfig::test_default().with_calculation_date(today);
+        let business = BusinessZakat::cash_only(20000)
+            .add_liability("Supplier", 1000)
+            .add_liability_due("Rent", 500, today + chrono::Duration::days(30))
+            .add_liability_due("Bank Loan", 5000, today + chrono::Duration::days(400))
+            .hawl(true);
+
+        assert_eq!(business.total_liabilities(), dec!(6500));
+        assert_eq!(business.liabilities_due_before(today + chrono::Duration::days(30)).len(), 2);
+
+        let result = business.calculate_zakat(&config).unwrap();
+        assert_eq!(result.net_assets, dec!(18500), "The loan falls due after the Hawl");
+        assert_eq!(result.zakat_due, dec!(462.5));
+
+        let deducted: Vec<(&str, Decimal)> = result.calculation_breakdown.0.iter()
+            .filter(|step| step.key == "step-liability")
+            .map(|step| (step.description.as_str(), step.amount.unwrap()))
+            .collect();
+        assert_eq!(deducted, [("Supplier", dec!(1000)), ("Rent", dec!(500))]);
+        assert!(result.calculation_breakdown.0.iter()
+            .any(|step| step.key == "info-liability-deferred" && step.description.starts_with("Bank Loan")));
+
+        // Two months on, the loan falls inside the coming Hawl.
+        let later = config.with_calculation_date(today + chrono::Duration::days(60));
+        assert_eq!(business.calculate_zakat(&later).unwrap().net_assets, dec!(13500));
+    }
+
+    #[test]
+    fn test_hawl_from_acquisition_date_uses_the_calculation_date() {
+        let acquired = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let business = BusinessZakat::cash_only(20000).acquired_on(acquired);
+
+        let before = ZakatConfig::test_default().with_calculation_date(acquired + chrono::Duration::days(300));
+        assert!(!business.calculate_zakat(&before).unwrap().is_payable);
+
+        let after = ZakatConfig::test_default().with_calculation_date(acquired + chrono::Duration::days(400));
+        assert!(business.calculate_zakat(&after).unwrap().is_payable);
+    }
+
+    #[test]
+    fn test_long_term_debt_due_after_hawl_keeps_one_year_portion() {
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let config = ZakatConfig::test_default().with_calculation_date(today);
+        let mut mortgage = crate::types::Liability::long_term("Mortgage", dec!(100000), dec!(500));
+        mortgage.due_date = Some(today + chrono::Duration::days(3650));
+        let mut business = BusinessZakat::cash_only(20000).hawl(true);
+        business.named_liabilities.push(mortgage);
+
+        let result = business.calculate_zakat(&config).unwrap();
+        assert_eq!(result.net_assets, dec!(14000), "12 monthly payments are deducted despite the final due date");
+        assert!(result.calculation_breakdown.0.iter().all(|step| step.key != "info-liability-deferred"));
+    }
+
+    #[test]
+    fn test_liabilities_under_matches_calculated_net() {
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let config = ZakatConfig::test_default()
+            .with_debt_policy(crate::types::DebtDeductionPolicy::ImmediateOnly)
+            .with_calculation_date(today);
+        let business = BusinessZakat::cash_only(20000)
+            .add_liability("Supplier", 1000)
+            .add_liability_due("Bank Loan", 5000, today + chrono::Duration::days(400))
+            .add_long_term_liability("Mortgage", 100000, 500)
+            .hawl(true);
+
+        assert_eq!(business.total_liabilities(), dec!(12000), "Ignores due dates and policy");
+        assert_eq!(business.liabilities_under(config.debt_policy, today), dec!(1000));
+
+        let result = business.calculate_zakat(&config).unwrap();
+        let item = crate::assets::PortfolioItem::Business(business);
+        assert_eq!(item.estimated_value(&config).unwrap(), result.net_assets);
+    }
+
     #[test]
     fn test_receivables_quality() {
         let config = ZakatConfig { gold_price_per_gram: Decimal::from(100), ..Default::default() };
@@ -440,4 +567,168 @@ mod tests {
         assert_eq!(liability.description, "Rent");
         assert_eq!(liability.amount, dec!(2000));
     }
+
+    #[test]
+    fn test_near_max_inputs_overflow_gracefully() {
+        let config = ZakatConfig { gold_price_per_gram: dec!(100), ..Default::default() };
+
+        let receivables = BusinessZakat::cash_only(1)
+            .add_receivable("Client A", Decimal::MAX, crate::debt::ReceivableQuality::Strong)
+            .add_receivable("Client B", Decimal::MAX, crate::debt::ReceivableQuality::Strong)
+            .hawl(true);
+        assert!(matches!(receivables.calculate_zakat(&config), Err(ZakatError::Overflow { .. })));
+
+        let debts = BusinessZakat::cash_only(Decimal::MAX)
+            .add_liability("Loan A", Decimal::MAX)
+            .add_liability("Loan B", Decimal::MAX)
+            .hawl(true);
+        assert!(matches!(debts.calculate_zakat(&config), Err(ZakatError::Overflow { .. })));
+    }
+
+    #[test]
+    fn test_breakdown_by_component_sums_to_net_assets() {
+        let config = ZakatConfig { gold_price_per_gram: dec!(100), ..Default::default() };
+        let business = BusinessZakat::new()
+            .cash(5000)
+            .inventory(3000)
+            .add_receivable("Client", 2000, crate::debt::ReceivableQuality::Strong)
+            .add_liability("Supplier", 1500)
+            .hawl(true);
+
+        let result = business.calculate_zakat(&config).unwrap();
+        let components = result.breakdown_by_component();
+
+        assert_eq!(components.len(), 4, "{:?}", components);
+        assert!(components.iter().any(|(_, amount)| *amount == dec!(-1500)));
+        let sum: Decimal = components.iter().map(|(_, amount)| *amount).sum();
+        assert_eq!(sum, result.net_assets);
+        assert_eq!(result.net_assets, dec!(8500));
+
+        let json = serde_json::to_value(result.to_explanation(&config)).unwrap();
+        assert_eq!(json["components"].as_array().map(Vec::len), Some(4));
+    }
+
+    #[test]
+    fn test_minimum_payment_rounds_small_due_up() {
+        // Nisab = 85g * 0.1 = 8.5, so 16 cash is payable: 16 * 2.5% = 0.40
+        let config = ZakatConfig::new().with_gold_price(dec!(0.1)).with_minimum_payment(dec!(1.00));
+        let result = BusinessZakat::cash_only(16).hawl(true).calculate_zakat(&config).unwrap();
+
+        assert_eq!(result.zakat_due, dec!(1.00));
+        assert_eq!(result.payload, crate::types::PaymentPayload::Monetary(dec!(1.00)));
+        let steps = &result.calculation_breakdown.0;
+        assert!(steps.iter().any(|s| s.key == "step-computed-due" && s.amount == Some(dec!(0.40))));
+        assert!(steps.iter().any(|s| s.key == "info-minimum-payment"));
+        #[allow(deprecated)]
+        let warned = result.warnings.iter().any(|w| w.contains("minimum payment"));
+        assert!(warned);
+
+        // Exempt assets are never rounded up.
+        let exempt = BusinessZakat::cash_only(5).hawl(true).calculate_zakat(&config).unwrap();
+        assert_eq!(exempt.zakat_due, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_debt_policy_controls_long_term_deduction() {
+        use crate::types::DebtDeductionPolicy;
+
+        // 50,000 mortgage at 1,000/month: 12,000 under the one-year rule.
+        let business = BusinessZakat::cash_only(100_000)
+            .add_long_term_liability("Mortgage", 50_000, 1_000)
+            .hawl(true);
+        let net_under = |policy| {
+            let config = ZakatConfig::test_default().with_debt_policy(policy);
+            let details = business.calculate_zakat(&config).unwrap();
+            assert!(
+                details.calculation_breakdown.iter().any(|s| s.key == "info-debt-policy" && s.description == policy.description()),
+                "Trace should name the {:?} policy",
+                policy
+            );
+            details.net_assets
+        };
+
+        assert_eq!(net_under(DebtDeductionPolicy::ImmediateOnly), dec!(100000));
+        assert_eq!(net_under(DebtDeductionPolicy::OneYearPortion), dec!(88000));
+        assert_eq!(net_under(DebtDeductionPolicy::FullOutstanding), dec!(50000));
+
+        // Without long-term debts the policy is irrelevant and left out of the trace.
+        let plain = BusinessZakat::cash_only(100_000).hawl(true).calculate_zakat(&ZakatConfig::test_default()).unwrap();
+        assert!(plain.calculation_breakdown.iter().all(|s| s.key != "info-debt-policy"));
+    }
+
+    #[test]
+    #[allow(deprecated)] // Reads the plain `warnings` list
+    fn test_lower_of_two_nisab_falls_back_to_the_priced_metal() {
+        use crate::madhab::NisabStandard;
+
+        let nisab_metal = |details: &ZakatDetails| {
+            details.calculation_breakdown.iter()
+                .find(|s| s.key == "info-nisab-metal")
+                .and_then(|s| s.args.as_ref())
+                .map(|args| args["metal"].clone())
+        };
+        let business = BusinessZakat::cash_only(10_000).hawl(true);
+
+        // Both prices set: silver (595g x 1 = 595) is lower than gold (85g x 100 = 8500).
+        let both = ZakatConfig::new()
+            .with_gold_price(100)
+            .with_silver_price(1)
+            .with_nisab_standard(NisabStandard::LowerOfTwo);
+        let details = business.calculate_zakat(&both).unwrap();
+        assert_eq!(details.nisab_threshold, dec!(595));
+        assert_eq!(nisab_metal(&details).as_deref(), Some("Silver"));
+        assert_eq!(details.nisab_metal, Some(crate::types::WealthType::Silver));
+        assert!(details.warnings.iter().all(|w| !w.contains("fell back")));
+
+        // Silver missing: use the gold Nisab instead of a zero threshold, and say so.
+        let gold_only = ZakatConfig::new()
+            .with_gold_price(100)
+            .with_nisab_standard(NisabStandard::LowerOfTwo);
+        let details = business.calculate_zakat(&gold_only).unwrap();
+        assert_eq!(details.nisab_threshold, dec!(8500));
+        assert_eq!(nisab_metal(&details).as_deref(), Some("Gold"));
+        assert_eq!(details.nisab_metal, Some(crate::types::WealthType::Gold));
+        assert!(details.warnings.iter().any(|w| w.contains("fell back to the gold Nisab")));
+
+        // Metals keep their own weight Nisab even when silver's is lower.
+        let gold_bar = crate::maal::precious_metals::PreciousMetals::gold(100).hawl(true);
+        let details = gold_bar.calculate_zakat(&both).unwrap();
+        assert_eq!(details.nisab_threshold, dec!(8500));
+        assert_eq!(nisab_metal(&details).as_deref(), Some("Gold"));
+        assert_eq!(details.nisab_metal, Some(crate::types::WealthType::Gold));
+    }
+
+    #[test]
+    fn test_wealth_between_silver_and_gold_nisab_is_recommended() {
+        // Gold Nisab 8500, silver Nisab 595; the Gold standard applies
+        let config = ZakatConfig::new()
+            .with_gold_price(100)
+            .with_silver_price(1)
+            .with_nisab_standard(crate::madhab::NisabStandard::Gold);
+        let at = |cash: i32| BusinessZakat::new().cash(cash).hawl(true).calculate_zakat(&config).unwrap();
+
+        assert_eq!(at(594).recommendation, crate::types::ZakatRecommendation::None);
+        let between = at(595);
+        assert!(!between.is_payable);
+        assert_eq!(between.recommendation, crate::types::ZakatRecommendation::Recommended);
+        assert!(between.calculation_breakdown.0.iter().any(|s| s.key == "info-sadaqah-recommended"));
+        assert_eq!(at(8500).recommendation, crate::types::ZakatRecommendation::Obligatory);
+    }
+
+    #[test]
+    fn test_inaccessible_fraction_reduces_the_zakatable_base() {
+        let config = ZakatConfig::new().with_gold_price(100);
+        let abroad = BusinessZakat::cash_only(20_000).inaccessible_fraction(dec!(0.2));
+
+        let details = abroad.calculate_zakat(&config).unwrap();
+
+        assert_eq!(details.total_assets, dec!(16000));
+        assert_eq!(details.zakat_due, dec!(400));
+        assert!(details.calculation_breakdown.0.iter().any(|s| s.key == "step-inaccessible-cash"));
+        #[allow(deprecated)]
+        let warnings = details.warnings;
+        assert!(warnings.iter().any(|w| w.contains("4000") && w.contains("inaccessible")));
+
+        assert!(BusinessZakat::cash_only(100).inaccessible_fraction(dec!(1.5)).validate().is_err());
+    }
 }
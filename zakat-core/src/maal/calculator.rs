@@ -8,6 +8,7 @@ pub struct MonetaryCalcParams {
     pub liabilities: Decimal,       // Deductible liabilities
     pub nisab_threshold: Decimal,   // The threshold to check against
     pub rate: Decimal,              // The Zakat rate (e.g., 0.025)
+    pub rounding: Option<crate::config::RoundingRule>, // Applied to the due before the minimum payment
     pub wealth_type: WealthType,
     pub label: Option<String>,
     pub hawl_satisfied: bool,
@@ -16,6 +17,70 @@ pub struct MonetaryCalcParams {
     pub trace_steps: Vec<CalculationStep>, // Asset-specific steps leading up to Total Assets
     pub warnings: Vec<String>, // Non-fatal warnings to include in the result
     pub observer: Option<std::sync::Arc<dyn crate::traits::CalculationObserver>>,
+    pub minimum_payment: Option<Decimal>, // Round positive dues below this up to it
+    pub nisab_band: Option<(Decimal, Decimal)>, // Gold and silver Nisab values for the Sadaqah band
+    pub liability_steps: Vec<CalculationStep>, // Per-debt steps; empty falls back to one "Liabilities" step
+}
+
+/// A computed due after the configured rounding and minimum payment.
+///
+/// Shared by every path that sets a monetary due, so rounding is applied the
+/// same way whether the due comes from the calculator, a pooled recompute or
+/// a special case such as Rikaz.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DueAdjustment {
+    /// The unrounded due (net assets × rate).
+    pub computed: Decimal,
+    /// The due after rounding, before the minimum payment.
+    pub rounded: Decimal,
+    /// The final due.
+    pub due: Decimal,
+    rounding: Option<crate::config::RoundingRule>,
+}
+
+impl DueAdjustment {
+    /// Rounds `computed` with `rounding`, then raises a positive result below
+    /// `minimum_payment` up to it.
+    pub fn new(
+        computed: Decimal,
+        rounding: Option<crate::config::RoundingRule>,
+        minimum_payment: Option<Decimal>,
+    ) -> Self {
+        let rounded = rounding.map_or(computed, |rule| rule.apply(computed));
+        let due = match minimum_payment {
+            Some(min) if rounded > Decimal::ZERO && rounded < min => min,
+            _ => rounded,
+        };
+        Self { computed, rounded, due, rounding }
+    }
+
+    /// Trace steps explaining how `due` differs from `computed`, if at all.
+    pub fn steps(&self) -> Vec<CalculationStep> {
+        let mut steps = Vec::new();
+        if self.due != self.computed {
+            steps.push(CalculationStep::result("step-computed-due", "Computed Due", self.computed));
+        }
+        if let Some(rule) = self.rounding.filter(|_| self.rounded != self.computed) {
+            steps.push(CalculationStep::info("info-rounding", format!("Rounded to {} decimal places", rule.decimals))
+                .with_args(std::collections::HashMap::from([("decimals".to_string(), rule.decimals.to_string())])));
+        }
+        if self.due != self.rounded {
+            steps.push(CalculationStep::info("info-minimum-payment", format!("Rounded up to minimum payment of {}", self.due))
+                .with_args(std::collections::HashMap::from([("minimum".to_string(), self.due.to_string())])));
+        }
+        steps
+    }
+
+    /// Stores the final due (and its payload) on `details`, warning when the
+    /// minimum payment raised it.
+    pub fn apply_to(&self, details: &mut ZakatDetails) {
+        details.zakat_due = self.due;
+        details.payload = crate::types::PaymentPayload::Monetary(self.due);
+        if self.due != self.rounded {
+            #[allow(deprecated)] // Uses deprecated `warnings` field for backward compat
+            details.warnings.push(format!("Zakat due of {} was rounded up to the minimum payment of {}.", self.computed, self.due));
+        }
+    }
 }
 
 /// Standardized Zakat calculation logic for monetary assets.
@@ -27,7 +92,8 @@ pub fn calculate_monetary_asset(params: MonetaryCalcParams) -> Result<ZakatDetai
             params.nisab_threshold,
             params.wealth_type,
             "Hawl (1 lunar year) not met"
-        ).with_label(params.label.unwrap_or_default()));
+        ).with_status_reason_key("reason-hawl-not-met")
+        .with_label(params.label.unwrap_or_default()));
     }
 
     // 2. Net Calculation
@@ -41,7 +107,7 @@ pub fn calculate_monetary_asset(params: MonetaryCalcParams) -> Result<ZakatDetai
     // 3. Payability Check
     let is_payable = net_assets >= params.nisab_threshold && net_assets > Decimal::ZERO;
     
-    let zakat_due = if is_payable {
+    let computed_due = if is_payable {
         ZakatDecimal::new(net_assets)
             .checked_mul(params.rate)
             .map_err(|e| e.with_source(params.label.clone().unwrap_or_default()))?
@@ -49,12 +115,15 @@ pub fn calculate_monetary_asset(params: MonetaryCalcParams) -> Result<ZakatDetai
     } else {
         Decimal::ZERO
     };
+    let adjustment = DueAdjustment::new(computed_due, params.rounding, params.minimum_payment);
 
     // 4. Construct Final Trace
     let mut final_trace = params.trace_steps;
     
-    // Auto-add liability step if liabilities exist
-    if params.liabilities > Decimal::ZERO {
+    // Itemized debts if the asset lists them, otherwise a single liability step
+    if !params.liability_steps.is_empty() {
+        final_trace.extend(params.liability_steps);
+    } else if params.liabilities > Decimal::ZERO {
         final_trace.push(CalculationStep::subtract("step-debts-due-now", "Liabilities", params.liabilities));
     }
     
@@ -63,7 +132,8 @@ pub fn calculate_monetary_asset(params: MonetaryCalcParams) -> Result<ZakatDetai
 
     if is_payable {
         final_trace.push(CalculationStep::rate("step-rate-applied", "Rate Applied", params.rate));
-        final_trace.push(CalculationStep::result("status-due", "Zakat Due", zakat_due));
+        final_trace.extend(adjustment.steps());
+        final_trace.push(CalculationStep::result("status-due", "Zakat Due", adjustment.due));
     } else {
         final_trace.push(CalculationStep::info("status-exempt", "Below Nisab"));
     }
@@ -85,6 +155,22 @@ pub fn calculate_monetary_asset(params: MonetaryCalcParams) -> Result<ZakatDetai
     ).with_label(params.label.unwrap_or_default());
     
     result.asset_id = params.asset_id;
+
+    if let Some((gold_nisab, silver_nisab)) = params.nisab_band
+        && !is_payable
+        && result.recommendation != crate::types::ZakatRecommendation::Recommended
+        && crate::madhab::recommendation_for(net_assets, gold_nisab, silver_nisab) == crate::types::ZakatRecommendation::Recommended
+    {
+        result.recommendation = crate::types::ZakatRecommendation::Recommended;
+        result.calculation_breakdown.0.push(CalculationStep::info(
+            "info-sadaqah-recommended",
+            "Info: Wealth is between the silver and gold Nisab. Voluntary Sadaqah is recommended."
+        ));
+    }
+
+    if adjustment.due != computed_due {
+        adjustment.apply_to(&mut result);
+    }
     
     // Add any warnings from params to the result
     #[allow(deprecated)] // Uses deprecated `warnings` field for backward compat
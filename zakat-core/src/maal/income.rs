@@ -9,13 +9,14 @@
 //! - **Net**: Deduct basic needs (*Hajah Asliyyah*) and debts before calculating surplus (Lenient).
 
 use rust_decimal::Decimal;
-use crate::types::{ZakatDetails, ZakatError, ErrorDetails};
+use crate::types::{ZakatDetails, ZakatError};
 use serde::{Serialize, Deserialize};
 use crate::traits::{CalculateZakat, ZakatConfigArgument};
 use crate::inputs::IntoZakatDecimal;
 use crate::math::ZakatDecimal;
 use crate::maal::calculator::{calculate_monetary_asset, MonetaryCalcParams};
 use crate::validation::Validator;
+use crate::hawl::HawlMode;
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, strum::Display, strum::EnumString, schemars::JsonSchema)]
@@ -37,6 +38,31 @@ impl crate::inputs::FromFfiString for IncomeCalculationMethod {
     }
 }
 
+/// One named source of income (e.g. salary, freelance) received on a given date.
+///
+/// Streams are prorated by how much of the Hawl has passed since they were
+/// received, then added to the calculator's zakatable base.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, schemars::JsonSchema)]
+#[typeshare::typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct IncomeStream {
+    pub label: String,
+    #[typeshare(serialized_as = "string")]
+    pub amount: Decimal,
+    #[typeshare(serialized_as = "string")]
+    pub received_on: chrono::NaiveDate,
+}
+
+impl IncomeStream {
+    /// Share of the stream that counts today: days held / 354, capped at 1.
+    pub fn hawl_share(&self, today: chrono::NaiveDate) -> Decimal {
+        crate::hawl::HawlTracker::new(today)
+            .acquired_on(self.received_on)
+            .completion_percentage()
+            .min(Decimal::ONE)
+    }
+}
+
 // MACRO USAGE
 crate::zakat_ffi_export! {
     #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,6 +70,12 @@ crate::zakat_ffi_export! {
         pub income: Decimal,
         pub expenses: Decimal,
         pub method: IncomeCalculationMethod,
+        /// Whether zakat waits for a full Hawl or is prepaid pro rata.
+        #[serde(default)]
+        pub hawl_mode: HawlMode,
+        /// Additional dated income streams, each prorated by time held.
+        #[serde(default)]
+        pub streams: Vec<IncomeStream>,
     }
 }
 
@@ -55,12 +87,15 @@ impl Default for IncomeZakatCalculator {
             income: Decimal::ZERO,
             expenses: Decimal::ZERO,
             method: IncomeCalculationMethod::default(),
+            hawl_mode: HawlMode::default(),
+            streams: Vec::new(),
             liabilities_due_now,
             named_liabilities,
             hawl_satisfied,
             label,
             id,
             acquisition_date,
+            hawl_status: None,
             _input_errors,
         }
     }
@@ -113,10 +148,69 @@ impl IncomeZakatCalculator {
         self.method = method;
         self
     }
+
+    /// Prepays zakat on the income accumulated over `months_elapsed` months of the Hawl.
+    ///
+    /// The due is scaled by `months_elapsed / 12`; Nisab is still checked against
+    /// the full accumulated amount.
+    pub fn prorated(mut self, months_elapsed: u8) -> Self {
+        self.hawl_mode = HawlMode::Prorated { months_elapsed };
+        self
+    }
+
+    pub fn hawl_mode(mut self, mode: HawlMode) -> Self {
+        self.hawl_mode = mode;
+        self
+    }
+
+    /// Adds a named income stream received on `received_on`.
+    ///
+    /// Its amount joins the base scaled by the share of the Hawl held since
+    /// receipt, so a payment received half a lunar year ago counts at 50%.
+    pub fn add_stream(mut self, label: impl Into<String>, amount: impl IntoZakatDecimal, received_on: chrono::NaiveDate) -> Self {
+        match amount.into_zakat_decimal() {
+            Ok(amount) => self.streams.push(IncomeStream { label: label.into(), amount, received_on }),
+            Err(e) => self._input_errors.push(e),
+        }
+        self
+    }
+}
+
+impl IncomeZakatCalculator {
+    /// Returns an error for every invalid field (see `validate()`).
+    fn field_errors(&self) -> Vec<ZakatError> {
+        let mut errors = Validator::collect_negative(&[
+            ("income", self.income),
+            ("expenses", self.expenses),
+        ], self.label.clone());
+        let stream_amounts: Vec<(&str, Decimal)> = self.streams.iter().map(|s| ("streams", s.amount)).collect();
+        errors.extend(Validator::collect_negative(&stream_amounts, self.label.clone()));
+        if let HawlMode::Prorated { months_elapsed } = self.hawl_mode
+            && months_elapsed > 12
+        {
+            errors.push(ZakatError::InvalidInput(Box::new(crate::types::InvalidInputDetails {
+                field: "hawl_mode".to_string(),
+                value: months_elapsed.to_string(),
+                reason_key: "error-prorated-months".to_string(),
+                source_label: self.label.clone(),
+                suggestion: Some("Use HawlMode::Full once the whole year has passed.".to_string()),
+                ..Default::default()
+            })));
+        }
+        errors
+    }
 }
 
 impl CalculateZakat for IncomeZakatCalculator {
     fn validate_input(&self) -> Result<(), ZakatError> { self.validate() }
+
+    fn preflight(&self, config: &crate::config::ZakatConfig) -> Result<(), ZakatError> {
+        self.validate()?;
+        config.require_monetary_prices(self.label.clone())
+    }
+
+    /// The Nisab is valued from gold/silver prices, even for pure cash.
+    fn requires_prices(&self) -> bool { true }
     fn get_label(&self) -> Option<String> { self.label.clone() }
     fn get_id(&self) -> uuid::Uuid { self.id }
 
@@ -131,39 +225,42 @@ impl CalculateZakat for IncomeZakatCalculator {
             ("expenses", self.expenses)
         ], self.label.clone())?;
 
-        // For LowerOfTwo or Silver standard, we need silver price too
-        let needs_silver = matches!(
-            config.cash_nisab_standard,
-            crate::madhab::NisabStandard::Silver | crate::madhab::NisabStandard::LowerOfTwo
-        );
-        
-        if config.gold_price_per_gram <= Decimal::ZERO && !needs_silver {
-            return Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
-                code: crate::types::ZakatErrorCode::ConfigError,
-                reason_key: "error-gold-price-required".to_string(),
-                source_label: self.label.clone(),
-                suggestion: Some("Run with --gold-price X or set ZAKAT_GOLD_PRICE env var.".to_string()),
-                ..Default::default()
-            })));
-        }
-        if needs_silver && config.silver_price_per_gram <= Decimal::ZERO {
-            return Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
-                code: crate::types::ZakatErrorCode::ConfigError,
-                reason_key: "error-silver-price-required".to_string(),
-                source_label: self.label.clone(),
-                suggestion: Some("Run with --silver-price X or set ZAKAT_SILVER_PRICE env var.".to_string()),
-                ..Default::default()
-            })));
-        }
+        config.require_monetary_prices(self.label.clone())?;
         
         let nisab_threshold_value = config.get_monetary_nisab_threshold();
 
         // Dynamic rate from strategy (default 2.5%)
         let rate = config.strategy.get_rules().trade_goods_rate;
-        let external_debt = self.total_liabilities(); // Uses total of legacy + named
+        let external_debt = self.checked_liabilities_under(config.debt_policy, config.as_of_date())?; // Uses total of legacy + named
 
         // Collect any warnings
-        let mut warnings = Vec::new();
+        let mut warnings = config.monetary_price_warnings();
+
+        // Prorate each dated stream by the share of the Hawl held, then add it to the base.
+        // Under `HawlMode::Prorated` the rate already scales the whole base by time
+        // elapsed, so streams count in full there to avoid prorating them twice.
+        let today = config.as_of_date();
+        let prorate_streams = !matches!(self.hawl_mode, HawlMode::Prorated { .. });
+        let mut stream_steps = Vec::with_capacity(self.streams.len());
+        let mut total_income = ZakatDecimal::new(self.income);
+        for stream in &self.streams {
+            let share = if prorate_streams { stream.hawl_share(today) } else { Decimal::ONE };
+            let prorated = *ZakatDecimal::new(stream.amount).checked_mul(share)?;
+            total_income = total_income.checked_add(prorated)?.with_source(self.label.clone());
+            stream_steps.push(
+                crate::types::CalculationStep::add(
+                    "step-income-stream",
+                    format!("{} (received {}, {}% of Hawl)", stream.label, stream.received_on, (share * Decimal::from(100)).round_dp(2)),
+                    prorated,
+                )
+                .with_args(std::collections::HashMap::from([
+                    ("label".to_string(), stream.label.clone()),
+                    ("date".to_string(), stream.received_on.to_string()),
+                    ("share".to_string(), share.round_dp(4).to_string()),
+                ])),
+            );
+        }
+        let total_income = *total_income;
 
         // Calculate total_assets and liabilities based on method
         let (total_assets, liabilities) = match self.method {
@@ -177,7 +274,7 @@ impl CalculateZakat for IncomeZakatCalculator {
                     warnings.push("Expenses are ignored when using the Gross calculation method.".to_string());
                 }
                 
-                (self.income, external_debt)
+                (total_income, external_debt)
             },
             IncomeCalculationMethod::Net => {
                 // Net means (Income - Basic Living Expenses).
@@ -185,7 +282,7 @@ impl CalculateZakat for IncomeZakatCalculator {
                 let combined_liabilities = ZakatDecimal::new(self.expenses)
                     .checked_add(external_debt)?
                     .with_source(self.label.clone());
-                (self.income, *combined_liabilities)
+                (total_income, *combined_liabilities)
             }
         };
 
@@ -194,6 +291,7 @@ impl CalculateZakat for IncomeZakatCalculator {
             crate::types::CalculationStep::initial("step-total-income", "Total Income", self.income)
                 .with_reference("Fiqh al-Zakah (Yusuf Al-Qaradawi)"),
         ];
+        trace_steps.extend(stream_steps);
         
         match self.method {
             IncomeCalculationMethod::Net => {
@@ -204,21 +302,29 @@ impl CalculateZakat for IncomeZakatCalculator {
                 trace_steps.push(crate::types::CalculationStep::info("info-gross-method", "Gross Method used (Expenses not deducted)"));
             }
         }
+        trace_steps.extend(self.debt_policy_step(config.debt_policy));
+        trace_steps.extend(config.monetary_nisab_step());
 
-        // Override hawl_satisfied if acquisition_date is present
-        let hawl_is_satisfied = if let Some(date) = self.acquisition_date {
-            let tracker = crate::hawl::HawlTracker::new(chrono::Local::now().date_naive())
-                .acquired_on(date);
-            tracker.is_satisfied()
-        } else {
-            self.hawl_satisfied
-        };
+        // Prepaying pro rata does not wait for the Hawl; the rate is scaled instead.
+        let mut rate = rate;
+        if let HawlMode::Prorated { months_elapsed } = self.hawl_mode {
+            let factor = self.hawl_mode.factor();
+            trace_steps.push(crate::types::CalculationStep::rate(
+                "step-hawl-proration",
+                format!("Hawl Proration ({}/12 months)", months_elapsed),
+                factor,
+            ));
+            rate = *ZakatDecimal::new(rate).checked_mul(factor)?;
+        }
+
+        let hawl_is_satisfied = matches!(self.hawl_mode, HawlMode::Prorated { .. }) || self.hawl_is_satisfied(config.as_of_date());
 
         let params = MonetaryCalcParams {
             total_assets,
             liabilities,
             nisab_threshold: nisab_threshold_value,
             rate,
+            rounding: config.rounding_for(&crate::types::WealthType::Income),
             wealth_type: crate::types::WealthType::Income,
             label: self.label.clone(),
             asset_id: Some(self.id),
@@ -226,9 +332,12 @@ impl CalculateZakat for IncomeZakatCalculator {
             trace_steps,
             warnings,
             observer: Some(config.observer.clone()),
+            minimum_payment: config.minimum_payment,
+            nisab_band: Some(config.nisab_band()),
+            liability_steps: Vec::new(),
         };
 
-        calculate_monetary_asset(params)
+        Ok(calculate_monetary_asset(params)?.with_nisab_metal(config.monetary_nisab_basis().0))
     }
 }
 
@@ -271,4 +380,93 @@ mod tests {
         assert!(!res.is_payable);
         // (12000 - 4000) = 8000. 8000 < 8500.
     }
+
+    #[test]
+    fn test_prorated_income_prepays_before_hawl() {
+        let config = ZakatConfig { gold_price_per_gram: dec!(100), ..Default::default() };
+
+        // Six months of salary, Hawl not complete: half of 2.5% on 10,000.
+        let res = IncomeZakatCalculator::new()
+            .income(10000)
+            .prorated(6)
+            .hawl(false)
+            .calculate_zakat(&config)
+            .unwrap();
+
+        assert!(res.is_payable);
+        assert_eq!(res.zakat_due, dec!(125));
+        let step = res.calculation_breakdown.iter().find(|s| s.key == "step-hawl-proration").unwrap();
+        assert_eq!(step.amount, Some(dec!(0.5)));
+
+        // Nisab is still checked on the accumulated base, not the prorated one.
+        let below = IncomeZakatCalculator::new()
+            .income(8000)
+            .prorated(6)
+            .calculate_zakat(&config)
+            .unwrap();
+        assert!(!below.is_payable);
+
+        assert!(IncomeZakatCalculator::new().income(10000).prorated(13).calculate_zakat(&config).is_err());
+    }
+
+    #[test]
+    fn test_income_streams_are_prorated_by_receipt_date() {
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let config = ZakatConfig { gold_price_per_gram: dec!(100), ..Default::default() }
+            .with_calculation_date(today);
+
+        // Salary held a full Hawl counts in full; freelance income held half a Hawl counts at 50%.
+        let res = IncomeZakatCalculator::new()
+            .add_stream("Salary", 12000, chrono::NaiveDate::from_ymd_opt(2024, 4, 28).unwrap())
+            .add_stream("Freelance", 6000, chrono::NaiveDate::from_ymd_opt(2024, 12, 6).unwrap())
+            .hawl(true)
+            .calculate_zakat(&config)
+            .unwrap();
+
+        assert_eq!(res.net_assets, dec!(15000));
+        assert_eq!(res.zakat_due, dec!(375));
+        let streams: Vec<_> = res.calculation_breakdown.iter().filter(|s| s.key == "step-income-stream").collect();
+        assert_eq!(streams.len(), 2);
+        assert_eq!(streams[0].amount, Some(dec!(12000)));
+        assert_eq!(streams[1].amount, Some(dec!(3000)));
+        assert!(streams[1].description.starts_with("Freelance"));
+    }
+
+    #[test]
+    fn test_prorated_hawl_does_not_prorate_streams_twice() {
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let config = ZakatConfig { gold_price_per_gram: dec!(100), ..Default::default() }
+            .with_calculation_date(today);
+
+        // Six months prepaid: the rate halves, so the freelance stream is not also halved by its date.
+        let res = IncomeZakatCalculator::new()
+            .add_stream("Salary", 12000, chrono::NaiveDate::from_ymd_opt(2024, 4, 28).unwrap())
+            .add_stream("Freelance", 6000, chrono::NaiveDate::from_ymd_opt(2024, 12, 6).unwrap())
+            .prorated(6)
+            .calculate_zakat(&config)
+            .unwrap();
+
+        assert_eq!(res.net_assets, dec!(18000));
+        assert_eq!(res.zakat_due, dec!(225));
+    }
+
+    #[test]
+    fn test_near_max_inputs_overflow_gracefully() {
+        let config = ZakatConfig { gold_price_per_gram: dec!(100), ..Default::default() };
+
+        let calc = IncomeZakatCalculator::new()
+            .income(Decimal::MAX)
+            .add_liability("Loan A", Decimal::MAX)
+            .add_liability("Loan B", Decimal::MAX)
+            .hawl(true);
+        assert!(matches!(calc.calculate_zakat(&config), Err(ZakatError::Overflow { .. })));
+
+        let net = IncomeZakatCalculator::new()
+            .income(Decimal::MAX)
+            .expenses(Decimal::MAX)
+            .add_liability("Loan", Decimal::MAX)
+            .method(IncomeCalculationMethod::Net)
+            .hawl(true);
+        assert!(matches!(net.calculate_zakat(&config), Err(ZakatError::Overflow { .. })));
+    }
 }
@@ -9,7 +9,7 @@
 //! - **IIFA Resolutions**: Cryptocurrencies recognized as wealth (*Mal*) are subject to Zakat if they meet conditions of value and possession.
 
 use rust_decimal::Decimal;
-use crate::types::{ZakatDetails, ZakatError, ErrorDetails};
+use crate::types::{ZakatDetails, ZakatError};
 use serde::{Serialize, Deserialize};
 use crate::traits::{CalculateZakat, ZakatConfigArgument};
 use crate::inputs::IntoZakatDecimal;
@@ -30,6 +30,7 @@ pub enum InvestmentStrategy {
     /// Uses the 30% proxy rule (Deduction of fixed assets). Instead of analyzing 
     /// balance sheets for every stock, 30% of market value is taken as the 
     /// zakatable portion (representing liquid assets like cash and receivables).
+    /// Set `zakatable_ratio` to use the company's actual ratio instead.
     DividendYield,
 }
 
@@ -79,6 +80,14 @@ crate::zakat_ffi_export! {
         /// Differentiates between trading (100% base) and long-term holding (30% proxy).
         #[serde(default)]
         pub strategy: InvestmentStrategy,
+        /// Cash dividends received during the Hawl and still held.
+        /// Added in full to the zakatable base; the 30% proxy only applies to the holding.
+        #[serde(default)]
+        pub dividends_received: Decimal,
+        /// Company's zakatable (net liquid) assets as a share of its market value,
+        /// e.g. `0.42`. Replaces the 30% proxy for `DividendYield` holdings.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub zakatable_ratio: Option<Decimal>,
     }
 }
 
@@ -91,12 +100,15 @@ impl Default for InvestmentAssets {
             investment_type: InvestmentType::default(),
             purification_rate: None,
             strategy: Default::default(),
+            dividends_received: Decimal::ZERO,
+            zakatable_ratio: None,
             liabilities_due_now,
             named_liabilities,
             hawl_satisfied,
             label,
             id,
             acquisition_date,
+            hawl_status: None,
             _input_errors,
         }
     }
@@ -136,15 +148,67 @@ impl InvestmentAssets {
         self
     }
 
+    /// Sets the cash dividends received during the Hawl.
+    pub fn dividends_received(mut self, amount: impl IntoZakatDecimal) -> Self {
+        match amount.into_zakat_decimal() {
+            Ok(v) => self.dividends_received = v,
+            Err(e) => self._input_errors.push(e),
+        }
+        self
+    }
+
     /// Sets the investment strategy (Niyyah).
     pub fn strategy(mut self, strategy: InvestmentStrategy) -> Self {
         self.strategy = strategy;
         self
     }
+
+    /// Sets the company's zakatable-asset ratio (0 to 1) for long-term holdings,
+    /// taken from its balance sheet instead of the 30% proxy.
+    pub fn zakatable_ratio(mut self, ratio: impl IntoZakatDecimal) -> Self {
+        match ratio.into_zakat_decimal() {
+            Ok(v) => self.zakatable_ratio = Some(v),
+            Err(e) => self._input_errors.push(e),
+        }
+        self
+    }
+}
+
+impl InvestmentAssets {
+    /// Returns an error for every invalid field (see `validate()`).
+    #[allow(deprecated)] // Checks deprecated `liabilities_due_now` for backward compat
+    fn field_errors(&self) -> Vec<ZakatError> {
+        let mut errors = Validator::collect_negative(&[
+            ("market_value", self.value),
+            ("dividends_received", self.dividends_received),
+            ("debt", self.liabilities_due_now),
+        ], self.label.clone());
+        if let Some(ratio) = self.zakatable_ratio
+            && (ratio < Decimal::ZERO || ratio > Decimal::ONE)
+        {
+            errors.push(ZakatError::InvalidInput(Box::new(crate::types::InvalidInputDetails {
+                field: "zakatable_ratio".to_string(),
+                value: ratio.to_string(),
+                reason_key: "error-zakatable-ratio".to_string(),
+                source_label: self.label.clone(),
+                suggestion: Some("Enter the ratio as a fraction, e.g. 0.42 for 42%.".to_string()),
+                ..Default::default()
+            })));
+        }
+        errors
+    }
 }
 
 impl CalculateZakat for InvestmentAssets {
     fn validate_input(&self) -> Result<(), ZakatError> { self.validate() }
+
+    fn preflight(&self, config: &crate::config::ZakatConfig) -> Result<(), ZakatError> {
+        self.validate()?;
+        config.require_monetary_prices(self.label.clone())
+    }
+
+    /// The Nisab is valued from gold/silver prices, even for pure cash.
+    fn requires_prices(&self) -> bool { true }
     fn get_label(&self) -> Option<String> { self.label.clone() }
     fn get_id(&self) -> uuid::Uuid { self.id }
 
@@ -157,33 +221,11 @@ impl CalculateZakat for InvestmentAssets {
         // Specific input validation
         Validator::ensure_non_negative(&[
             ("market_value", self.value),
+            ("dividends_received", self.dividends_received),
             ("debt", self.liabilities_due_now)
         ], self.label.clone())?;
 
-        // For LowerOfTwo or Silver standard, we need silver price too
-        let needs_silver = matches!(
-            config.cash_nisab_standard,
-            crate::madhab::NisabStandard::Silver | crate::madhab::NisabStandard::LowerOfTwo
-        );
-        
-        if config.gold_price_per_gram <= Decimal::ZERO && !needs_silver {
-            return Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
-                code: crate::types::ZakatErrorCode::ConfigError,
-                reason_key: "error-gold-price-required".to_string(),
-                source_label: self.label.clone(),
-                suggestion: Some("Run with --gold-price X or set ZAKAT_GOLD_PRICE env var.".to_string()),
-                ..Default::default()
-            })));
-        }
-        if needs_silver && config.silver_price_per_gram <= Decimal::ZERO {
-            return Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
-                code: crate::types::ZakatErrorCode::ConfigError,
-                reason_key: "error-silver-price-required".to_string(),
-                source_label: self.label.clone(),
-                suggestion: Some("Run with --silver-price X or set ZAKAT_SILVER_PRICE env var.".to_string()),
-                ..Default::default()
-            })));
-        }
+        config.require_monetary_prices(self.label.clone())?;
         
         let nisab_threshold_value = config.get_monetary_nisab_threshold();
 
@@ -209,18 +251,33 @@ impl CalculateZakat for InvestmentAssets {
 
         // START CHANGE: Feature 3 (Investment Strategy)
         let zakatable_base = match self.strategy {
-            InvestmentStrategy::CapitalAppreciation => self.value,
+            InvestmentStrategy::CapitalAppreciation => {
+                 trace_steps.push(crate::types::CalculationStep::info(
+                     "info-trading-full-value",
+                     "Held for Trading: Full Market Value is Zakatable",
+                 ));
+                 self.value
+            }
             InvestmentStrategy::DividendYield => {
                  use rust_decimal_macros::dec;
-                 // 30% Proxy Rule for "Net Zakatable Assets"
-                 let proxy_rate = dec!(0.30);
-                 let zakatable_portion = self.value * proxy_rate;
+                 // Company-reported ratio if given, else the 30% Proxy Rule for "Net Zakatable Assets"
+                 let (proxy_rate, basis_step) = match self.zakatable_ratio {
+                     Some(ratio) => (ratio, crate::types::CalculationStep::rate(
+                         "step-zakatable-ratio",
+                         "Held Long-Term: Company Zakatable Asset Ratio",
+                         ratio,
+                     ).with_reference("AAOIFI Sharia Standard No. 35")),
+                     None => (dec!(0.30), crate::types::CalculationStep::rate(
+                         "step-dividend-proxy", 
+                         "Held for Dividends: 30% Proxy Rule Applied", 
+                         dec!(0.30)
+                     ).with_reference("Modern Fiqh Resolution")),
+                 };
+                 let zakatable_portion = *ZakatDecimal::new(self.value)
+                     .checked_mul(proxy_rate)?
+                     .with_source(self.label.clone());
                  
-                 trace_steps.push(crate::types::CalculationStep::rate(
-                     "step-dividend-proxy", 
-                     "Held for Dividends: 30% Proxy Rule Applied", 
-                     proxy_rate
-                 ).with_reference("Modern Fiqh Resolution"));
+                 trace_steps.push(basis_step);
                  trace_steps.push(crate::types::CalculationStep::result(
                      "step-zakatable-portion", 
                      "Net Zakatable Assets (Proxy)", 
@@ -232,6 +289,20 @@ impl CalculateZakat for InvestmentAssets {
         };
         // END CHANGE
 
+        // Dividends are cash in hand, so they join the base in full.
+        let zakatable_base = if self.dividends_received > Decimal::ZERO {
+            trace_steps.push(crate::types::CalculationStep::add(
+                "step-dividends-received",
+                "Dividends Received",
+                self.dividends_received,
+            ));
+            *ZakatDecimal::new(zakatable_base)
+                .checked_add(self.dividends_received)?
+                .with_source(self.label.clone())
+        } else {
+            zakatable_base
+        };
+
         // Apply Purification if set
         let zakatable_gross = if let Some(purify_rate) = self.purification_rate {
              // Purify calculate on the BASE (zakatable portion)
@@ -259,30 +330,31 @@ impl CalculateZakat for InvestmentAssets {
             zakatable_base
         };
 
-        // Override hawl_satisfied if acquisition_date is present
-        let hawl_is_satisfied = if let Some(date) = self.acquisition_date {
-            let tracker = crate::hawl::HawlTracker::new(chrono::Local::now().date_naive())
-                .acquired_on(date);
-            tracker.is_satisfied()
-        } else {
-            self.hawl_satisfied
-        };  
+        trace_steps.extend(self.debt_policy_step(config.debt_policy));
+        trace_steps.extend(config.monetary_nisab_step());
+
+        // Explicit status first, then acquisition_date, then the hawl_satisfied flag
+        let hawl_is_satisfied = self.hawl_is_satisfied(config.as_of_date());
 
         let params = MonetaryCalcParams {
             total_assets: zakatable_gross,
-            liabilities: self.total_liabilities(), // Uses total of legacy + named
+            liabilities: self.checked_liabilities_under(config.debt_policy, config.as_of_date())?, // Uses total of legacy + named
             nisab_threshold: nisab_threshold_value,
             rate,
+            rounding: config.rounding_for(&crate::types::WealthType::Investment),
             wealth_type: crate::types::WealthType::Investment,
             label: self.label.clone(),
             hawl_satisfied: hawl_is_satisfied,
             asset_id: Some(self.id),
             trace_steps,
-            warnings: Vec::new(),
+            warnings: config.monetary_price_warnings(),
             observer: Some(config.observer.clone()),
+            minimum_payment: config.minimum_payment,
+            nisab_band: Some(config.nisab_band()),
+            liability_steps: Vec::new(),
         };
 
-        calculate_monetary_asset(params)
+        Ok(calculate_monetary_asset(params)?.with_nisab_metal(config.monetary_nisab_basis().0))
     }
 }
 
@@ -329,4 +401,68 @@ mod tests {
         let trace = res.calculation_breakdown.0;
         assert!(trace.iter().any(|s| s.description.contains("30% Proxy")));
     }
+
+    #[test]
+    fn test_long_term_holding_uses_company_ratio() {
+        let config = ZakatConfig { gold_price_per_gram: dec!(100), ..Default::default() };
+
+        // 100,000 at a 42% company ratio -> 42,000 base -> 1,050 due.
+        let res = InvestmentAssets::stock(100_000)
+            .strategy(InvestmentStrategy::DividendYield)
+            .zakatable_ratio(dec!(0.42))
+            .hawl(true)
+            .calculate_zakat(&config)
+            .unwrap();
+        assert_eq!(res.zakat_due, dec!(1050));
+        let trace = &res.calculation_breakdown.0;
+        assert!(trace.iter().any(|s| s.key == "step-zakatable-ratio" && s.amount == Some(dec!(0.42))));
+        assert!(!trace.iter().any(|s| s.key == "step-dividend-proxy"));
+
+        // Trading ignores the ratio: the full market value is zakatable.
+        let trading = InvestmentAssets::stock(100_000)
+            .zakatable_ratio(dec!(0.42))
+            .hawl(true)
+            .calculate_zakat(&config)
+            .unwrap();
+        assert_eq!(trading.zakat_due, dec!(2500));
+        assert!(trading.calculation_breakdown.0.iter().any(|s| s.key == "info-trading-full-value"));
+
+        let invalid = InvestmentAssets::stock(100_000).zakatable_ratio(dec!(1.5));
+        assert!(matches!(invalid.validate(), Err(ZakatError::InvalidInput(d)) if d.reason_key == "error-zakatable-ratio"));
+    }
+
+    #[test]
+    fn test_near_max_inputs_overflow_gracefully() {
+        let config = ZakatConfig { gold_price_per_gram: dec!(100), ..Default::default() };
+
+        let inv = InvestmentAssets::new()
+            .value(Decimal::MAX)
+            .add_liability("Margin A", Decimal::MAX)
+            .add_liability("Margin B", Decimal::MAX)
+            .hawl(true);
+        assert!(matches!(inv.calculate_zakat(&config), Err(ZakatError::Overflow { .. })));
+
+        // Applying the rate shrinks the value, so the largest representable value still calculates.
+        let res = InvestmentAssets::new().value(Decimal::MAX).hawl(true).calculate_zakat(&config).unwrap();
+        assert!(res.is_payable);
+        assert_eq!(res.zakat_due, Decimal::MAX * dec!(0.025));
+    }
+
+    #[test]
+    fn test_dividends_raise_base_and_due() {
+        let config = ZakatConfig { gold_price_per_gram: dec!(100), ..Default::default() };
+
+        let without = InvestmentAssets::stock(10000).hawl(true).calculate_zakat(&config).unwrap();
+        let with = InvestmentAssets::stock(10000)
+            .dividends_received(2000)
+            .hawl(true)
+            .calculate_zakat(&config)
+            .unwrap();
+
+        assert_eq!(without.net_assets, dec!(10000));
+        assert_eq!(with.net_assets, dec!(12000));
+        assert_eq!(without.zakat_due, dec!(250));
+        assert_eq!(with.zakat_due, dec!(300));
+        assert!(with.calculation_breakdown.iter().any(|s| s.key == "step-dividends-received"));
+    }
 }
@@ -73,6 +73,15 @@ impl LivestockPrices {
         }
         self
     }
+
+    /// Returns the per-head price for `animal_type`.
+    pub fn price_of(&self, animal_type: LivestockType) -> Decimal {
+        match animal_type {
+            LivestockType::Sheep => self.sheep_price,
+            LivestockType::Cow => self.cow_price,
+            LivestockType::Camel => self.camel_price,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
@@ -172,25 +181,10 @@ impl LivestockAssets {
     pub fn validate(&self) -> Result<(), ZakatError> {
         Ok(())
     }
-}
-
-
-
-impl CalculateZakat for LivestockAssets {
-    fn validate_input(&self) -> Result<(), ZakatError> {
-        self.validate()
-    }
-
-    fn calculate_zakat<C: ZakatConfigArgument>(&self, config: C) -> Result<ZakatDetails, ZakatError> {
-        // Early return optimization for zero count
-        if self.count == 0 {
-            return Ok(ZakatDetails::below_threshold(Decimal::ZERO, crate::types::WealthType::Livestock, "Count is zero")
-                .with_label(self.label.clone().unwrap_or_default()));
-        }
-        
-        let config_cow = config.resolve_config();
-        let _config_ref = config_cow.as_ref();
 
+    /// Returns the animal type and its price, or the error `calculate_zakat`
+    /// raises when either is missing.
+    fn checked_price(&self) -> Result<(LivestockType, Decimal), ZakatError> {
         let animal_type = self.animal_type.as_ref().ok_or_else(|| 
             ZakatError::InvalidInput(Box::new(InvalidInputDetails {
                 field: "animal_type".to_string(),
@@ -203,11 +197,7 @@ impl CalculateZakat for LivestockAssets {
         )?;
 
         // Validate price for the specific animal type
-        let single_price = match animal_type {
-            LivestockType::Sheep => self.prices.sheep_price,
-            LivestockType::Cow => self.prices.cow_price,
-            LivestockType::Camel => self.prices.camel_price,
-        };
+        let single_price = self.prices.price_of(*animal_type);
 
         if single_price <= Decimal::ZERO {
             let animal_str = match animal_type {
@@ -225,6 +215,39 @@ impl CalculateZakat for LivestockAssets {
             })));
         }
 
+        Ok((*animal_type, single_price))
+    }
+}
+
+
+
+impl CalculateZakat for LivestockAssets {
+    fn validate_input(&self) -> Result<(), ZakatError> {
+        self.validate()
+    }
+
+    fn preflight(&self, _config: &crate::config::ZakatConfig) -> Result<(), ZakatError> {
+        self.validate()?;
+        // A zero count is reported below Nisab before any price is read.
+        if self.count == 0 {
+            return Ok(());
+        }
+        self.checked_price().map(|_| ())
+    }
+
+    fn calculate_zakat<C: ZakatConfigArgument>(&self, config: C) -> Result<ZakatDetails, ZakatError> {
+        // Early return optimization for zero count
+        if self.count == 0 {
+            return Ok(ZakatDetails::below_threshold(Decimal::ZERO, crate::types::WealthType::Livestock, "Count is zero")
+                .with_status_reason_key("reason-count-zero")
+                .with_label(self.label.clone().unwrap_or_default()));
+        }
+        
+        let config_cow = config.resolve_config();
+        let config = config_cow.as_ref();
+
+        let (animal_type, single_price) = self.checked_price()?;
+
         // Calculate Nisab Count Value for reporting consistency even if not payable
         let nisab_count_val = match animal_type {
             LivestockType::Sheep => ZakatDecimal::new(Decimal::from(40)).checked_mul(single_price)?.with_source(self.label.clone()),
@@ -238,23 +261,26 @@ impl CalculateZakat for LivestockAssets {
                 *nisab_count_val, 
                 crate::types::WealthType::Livestock, 
                 "Working animals (Awamil) are exempt"
-            ).with_label(self.label.clone().unwrap_or_default()));
+            ).with_status_reason_key("reason-working-animals")
+            .with_label(self.label.clone().unwrap_or_default()));
         }
 
         if self.grazing_method != GrazingMethod::Saimah {
              return Ok(ZakatDetails::below_threshold(*nisab_count_val, crate::types::WealthType::Livestock, "Not Sa'imah (naturally grazed)")
+                .with_status_reason_key("reason-not-saimah")
                 .with_label(self.label.clone().unwrap_or_default()));
         }
 
         if !self.hawl_satisfied {
              return Ok(ZakatDetails::below_threshold(*nisab_count_val, crate::types::WealthType::Livestock, "Hawl (1 lunar year) not met")
+                .with_status_reason_key("reason-hawl-not-met")
                 .with_label(self.label.clone().unwrap_or_default()));
         }
 
-        // Note: translator is available via config_ref if needed for trace messages
+        // Note: translator is available via config if needed for trace messages
 
         let (zakat_value, nisab_count, heads_due) = match animal_type {
-            LivestockType::Sheep => calculate_sheep_zakat(self.count, self.prices.sheep_price)?,
+            LivestockType::Sheep => calculate_sheep_zakat(self.count, self.prices.sheep_price, config.strategy.get_rules().sheep_tiers)?,
             LivestockType::Cow => calculate_cow_zakat(self.count, self.prices.cow_price)?,
             LivestockType::Camel => calculate_camel_zakat(self.count, &self.prices)?,
         };
@@ -313,10 +339,12 @@ impl CalculateZakat for LivestockAssets {
             liabilities: Vec::new(),
             net_assets: *total_value, 
             nisab_threshold: *nisab_threshold, 
+            nisab_metal: None,
             is_payable,
             zakat_due: zakat_value,
             wealth_type: crate::types::WealthType::Livestock,
             status_reason: None,
+            status_reason_key: None,
             label: self.label.clone(),
             asset_id: Some(self.id),
             payload: crate::types::PaymentPayload::Livestock { heads_due },
@@ -339,25 +367,206 @@ impl CalculateZakat for LivestockAssets {
     fn get_id(&self) -> uuid::Uuid {
         self.id
     }
+
+    fn zakat_rate(&self, _config: &crate::config::ZakatConfig) -> Decimal {
+        // Due in heads from the tier tables, not as a share of value.
+        Decimal::ZERO
+    }
+
+    fn requires_prices(&self) -> bool {
+        true
+    }
+}
+
+/// A herd of several species assessed together as one asset.
+///
+/// Every species keeps its own Nisab and tier table; the result merges the
+/// heads due into a single `PaymentPayload::Livestock` and sums the herd value.
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MixedHerd {
+    pub camel_count: u32,
+    pub cow_count: u32,
+    pub sheep_count: u32,
+    pub prices: LivestockPrices,
+    pub hawl_satisfied: bool,
+    pub grazing_method: GrazingMethod,
+    pub label: Option<String>,
+    pub id: uuid::Uuid,
+}
+
+impl Default for MixedHerd {
+    fn default() -> Self {
+        Self {
+            camel_count: 0,
+            cow_count: 0,
+            sheep_count: 0,
+            prices: LivestockPrices::default(),
+            hawl_satisfied: true,
+            grazing_method: GrazingMethod::Saimah,
+            label: None,
+            id: uuid::Uuid::new_v4(),
+        }
+    }
+}
+
+impl MixedHerd {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the head count for one species, replacing any earlier count.
+    pub fn count(mut self, animal_type: LivestockType, count: u32) -> Self {
+        match animal_type {
+            LivestockType::Camel => self.camel_count = count,
+            LivestockType::Cow => self.cow_count = count,
+            LivestockType::Sheep => self.sheep_count = count,
+        }
+        self
+    }
+
+    pub fn prices(mut self, prices: LivestockPrices) -> Self {
+        self.prices = prices;
+        self
+    }
+
+    pub fn hawl(mut self, satisfied: bool) -> Self {
+        self.hawl_satisfied = satisfied;
+        self
+    }
+
+    pub fn grazing(mut self, method: GrazingMethod) -> Self {
+        self.grazing_method = method;
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Returns the species present in the herd as single-species assets.
+    pub fn species(&self) -> Vec<LivestockAssets> {
+        [
+            (LivestockType::Camel, self.camel_count),
+            (LivestockType::Cow, self.cow_count),
+            (LivestockType::Sheep, self.sheep_count),
+        ]
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(animal_type, count)| LivestockAssets {
+            count,
+            animal_type: Some(animal_type),
+            prices: self.prices,
+            hawl_satisfied: self.hawl_satisfied,
+            grazing_method: self.grazing_method,
+            label: self.label.clone(),
+            id: self.id,
+            ..Default::default()
+        })
+        .collect()
+    }
+}
+
+impl CalculateZakat for MixedHerd {
+    fn preflight(&self, config: &crate::config::ZakatConfig) -> Result<(), ZakatError> {
+        self.species().iter().try_for_each(|herd| herd.preflight(config))
+    }
+
+    fn calculate_zakat<C: ZakatConfigArgument>(&self, config: C) -> Result<ZakatDetails, ZakatError> {
+        let config_cow = config.resolve_config();
+        let config = config_cow.as_ref();
+
+        let species = self.species();
+        if species.is_empty() {
+            return Ok(ZakatDetails::below_threshold(Decimal::ZERO, crate::types::WealthType::Livestock, "Count is zero")
+                .with_status_reason_key("reason-count-zero")
+                .with_label(self.label.clone().unwrap_or_default()));
+        }
+
+        let mut total_value = ZakatDecimal::new(Decimal::ZERO).with_source(self.label.clone());
+        let mut nisab_threshold = ZakatDecimal::new(Decimal::ZERO).with_source(self.label.clone());
+        let mut zakat_due = ZakatDecimal::new(Decimal::ZERO).with_source(self.label.clone());
+        let mut heads_due = Vec::new();
+        let mut trace = Vec::new();
+        let mut notes = Vec::new();
+
+        // Each species is judged against its own Nisab; only the results are merged.
+        for herd in &species {
+            let details = herd.calculate_zakat(config)?;
+            total_value = total_value.checked_add(details.total_assets)?;
+            nisab_threshold = nisab_threshold.checked_add(details.nisab_threshold)?;
+            zakat_due = zakat_due.checked_add(details.zakat_due)?;
+            if let crate::types::PaymentPayload::Livestock { heads_due: species_heads } = details.payload {
+                heads_due.extend(species_heads);
+            }
+            trace.extend(details.calculation_breakdown.0);
+            if let Some(reason) = details.status_reason {
+                notes.push(reason);
+            }
+        }
+
+        let is_payable = *zakat_due > Decimal::ZERO;
+        trace.push(crate::types::CalculationStep::result("step-mixed-herd-value", "Combined Herd Value", *total_value));
+        if is_payable {
+            trace.push(crate::types::CalculationStep::result("step-zakat-due", "Zakat Due", *zakat_due));
+        }
+
+        #[allow(deprecated)]
+        Ok(ZakatDetails {
+            total_assets: *total_value,
+            liabilities_due_now: Decimal::ZERO,
+            liabilities: Vec::new(),
+            net_assets: *total_value,
+            // Sum of the per-species Nisab values, for reporting only.
+            nisab_threshold: *nisab_threshold,
+            nisab_metal: None,
+            is_payable,
+            zakat_due: *zakat_due,
+            wealth_type: crate::types::WealthType::Livestock,
+            status_reason: None,
+            status_reason_key: None,
+            label: self.label.clone(),
+            asset_id: Some(self.id),
+            payload: crate::types::PaymentPayload::Livestock { heads_due },
+            calculation_breakdown: crate::types::CalculationBreakdown(trace),
+            structured_warnings: Vec::new(),
+            warnings: Vec::new(),
+            recommendation: if is_payable {
+                crate::types::ZakatRecommendation::Obligatory
+            } else {
+                crate::types::ZakatRecommendation::None
+            },
+            notes,
+        })
+    }
+
+    fn get_label(&self) -> Option<String> {
+        self.label.clone()
+    }
+
+    fn get_id(&self) -> uuid::Uuid {
+        self.id
+    }
+
+    fn zakat_rate(&self, _config: &crate::config::ZakatConfig) -> Decimal {
+        // Due in heads from the tier tables, not as a share of value.
+        Decimal::ZERO
+    }
+
+    fn requires_prices(&self) -> bool {
+        true
+    }
 }
 
 #[allow(clippy::type_complexity)]
-fn calculate_sheep_zakat(count: u32, price: Decimal) -> Result<(Decimal, u32, Vec<LivestockDueItem>), ZakatError> {
+fn calculate_sheep_zakat(count: u32, price: Decimal, tiers: crate::madhab::SheepTierRuling) -> Result<(Decimal, u32, Vec<LivestockDueItem>), ZakatError> {
     let nisab = 40;
     if count < 40 {
         return Ok((Decimal::ZERO, nisab, vec![]));
     }
     
-    let sheep_due = if count <= 120 {
-        1
-    } else if count <= 200 {
-        2
-    } else if count <= 300 {
-        3
-    } else {
-        // Above 300: 1 sheep for every 100 sheep.
-        count / 100
-    };
+    let sheep_due = tiers.sheep_due(count);
 
     let zakat_value = ZakatDecimal::new(Decimal::from(sheep_due))
         .checked_mul(price)?
@@ -669,4 +878,147 @@ mod tests {
         // Verify that the result is within the expected order of magnitude.
         assert!(details.zakat_due > dec!(1_000_000_000));
     }
+
+    #[test]
+    fn test_livestock_details_are_in_kind() {
+        let prices = LivestockPrices::new().sheep_price(100);
+        let sheep = LivestockAssets::new()
+            .count(40)
+            .animal_type(LivestockType::Sheep)
+            .prices(prices)
+            .hawl(true)
+            .calculate_zakat(&ZakatConfig::default())
+            .unwrap();
+        assert!(sheep.is_in_kind());
+
+        let business = crate::maal::business::BusinessZakat::cash_only(10_000)
+            .hawl(true)
+            .calculate_zakat(&ZakatConfig::test_default())
+            .unwrap();
+        assert!(!business.is_in_kind());
+    }
+
+    #[test]
+    fn test_huge_herds_cover_every_head() {
+        // Regression guard for the former per-head iteration: a full-size herd must
+        // be split into its 30/40, 40/50 or 100-head units, leaving less than one
+        // unit uncounted. The `calculation` bench tracks the timings.
+        use crate::types::{LivestockAge, PaymentPayload};
+
+        let prices = LivestockPrices::new().camel_price(1000).cow_price(500).sheep_price(100);
+        for (animal, largest_unit) in [
+            (LivestockType::Camel, 50u64),
+            (LivestockType::Cow, 40),
+            (LivestockType::Sheep, 100),
+        ] {
+            let details = LivestockAssets::new()
+                .count(u32::MAX)
+                .animal_type(animal)
+                .prices(prices)
+                .hawl(true)
+                .calculate_zakat(&ZakatConfig::default())
+                .unwrap();
+            assert!(details.is_payable);
+
+            let PaymentPayload::Livestock { heads_due } = details.payload else {
+                panic!("expected a livestock payload");
+            };
+            let covered: u64 = heads_due.iter().map(|item| {
+                let unit = match item.age {
+                    LivestockAge::Tabi => 30,
+                    LivestockAge::Musinnah | LivestockAge::BintLabun => 40,
+                    LivestockAge::Hiqqah => 50,
+                    LivestockAge::Jadha => 100,
+                    other => panic!("unexpected {:?} in a huge herd", other),
+                };
+                u64::from(item.count) * unit
+            }).sum();
+            let herd = u64::from(u32::MAX);
+            assert!(covered <= herd && herd - covered < largest_unit, "{:?}: {} of {} heads covered", animal, covered, herd);
+        }
+    }
+
+    #[test]
+    fn test_sheep_tiers_follow_configured_ruling() {
+        use crate::madhab::{SheepTierRuling, ZakatRules, ZakatStrategy};
+
+        #[derive(Debug)]
+        struct ExtraAbove300;
+        impl ZakatStrategy for ExtraAbove300 {
+            fn get_rules(&self) -> ZakatRules {
+                ZakatRules::default().with_sheep_tiers(SheepTierRuling::ExtraAbove300)
+            }
+        }
+
+        let heads_due = |count: u32, config: &ZakatConfig| {
+            let details = LivestockAssets::new()
+                .count(count)
+                .animal_type(LivestockType::Sheep)
+                .prices(LivestockPrices::new().sheep_price(100))
+                .hawl(true)
+                .calculate_zakat(config)
+                .unwrap();
+            details.zakat_due / dec!(100)
+        };
+        let majority = ZakatConfig::default();
+        let minority = ZakatConfig::default().with_strategy(std::sync::Arc::new(ExtraAbove300));
+
+        assert_eq!(heads_due(300, &majority), dec!(3));
+        assert_eq!(heads_due(301, &majority), dec!(3));
+        assert_eq!(heads_due(300, &minority), dec!(3));
+        assert_eq!(heads_due(301, &minority), dec!(4));
+    }
+
+    #[test]
+    fn test_mixed_herd_merges_heads_due_per_species() {
+        let prices = LivestockPrices::new().sheep_price(100).cow_price(500).camel_price(1000);
+        let herd = MixedHerd::new()
+            .count(LivestockType::Camel, 5)
+            .count(LivestockType::Cow, 35)
+            .count(LivestockType::Sheep, 39)
+            .prices(prices)
+            .label("Farm");
+
+        let res = herd.calculate_zakat(&ZakatConfig::default()).unwrap();
+
+        // 5 camels -> 1 sheep, 35 cows -> 1 Tabi', 39 sheep below their own Nisab.
+        let single = |animal_type, count| LivestockAssets::new()
+            .count(count)
+            .animal_type(animal_type)
+            .prices(prices)
+            .calculate_zakat(&ZakatConfig::default())
+            .unwrap()
+            .zakat_due;
+        assert!(res.is_payable);
+        assert_eq!(res.zakat_due, single(LivestockType::Camel, 5) + single(LivestockType::Cow, 35));
+        assert_eq!(res.total_assets, dec!(5000) + dec!(17500) + dec!(3900));
+        match res.payload {
+            crate::types::PaymentPayload::Livestock { heads_due } => {
+                assert_eq!(heads_due.len(), 2);
+                assert!(heads_due.iter().any(|h| h.kind == LivestockKind::Sheep && h.count == 1));
+                assert!(heads_due.iter().any(|h| h.kind == LivestockKind::Cow && h.count == 1));
+            }
+            _ => panic!("Expected Livestock payload"),
+        }
+    }
+
+    #[test]
+    fn test_preflight_requires_the_animal_prices_calculation_uses() {
+        let config = ZakatConfig::default();
+        let unpriced = LivestockAssets::new().count(40).animal_type(LivestockType::Sheep);
+        let preflight = unpriced.preflight(&config).unwrap_err();
+        let calculated = unpriced.calculate_zakat(&config).unwrap_err();
+        assert_eq!(preflight.to_string(), calculated.to_string());
+
+        let priced = unpriced.prices(LivestockPrices::new().sheep_price(100));
+        assert!(priced.preflight(&config).is_ok());
+        assert!(LivestockAssets::new().count(0).preflight(&config).is_ok());
+
+        let herd = MixedHerd::new()
+            .count(LivestockType::Sheep, 40)
+            .count(LivestockType::Cow, 30)
+            .prices(LivestockPrices::new().sheep_price(100));
+        assert!(herd.preflight(&config).is_err());
+        assert!(herd.calculate_zakat(&config).is_err());
+    }
 }
@@ -16,6 +16,9 @@ use crate::inputs::IntoZakatDecimal;
 use crate::math::ZakatDecimal;
 use crate::maal::calculator::{calculate_monetary_asset, MonetaryCalcParams};
 
+/// Khumus (one-fifth) due on Rikaz, fixed by the hadith rather than by the Madhab strategy.
+const RIKAZ_RATE: Decimal = dec!(0.20);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, strum::Display, strum::EnumString, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum MiningType {
@@ -59,6 +62,7 @@ impl Default for MiningAssets {
             label,
             id,
             acquisition_date,
+            hawl_status: None,
             _input_errors,
         }
     }
@@ -83,6 +87,21 @@ impl MiningAssets {
         self.mining_type = kind;
         self
     }
+
+    /// Creates a Rikaz (found treasure) asset: 20% of `value`, due immediately
+    /// with no Hawl, Nisab or debt deduction.
+    pub fn rikaz(value: impl IntoZakatDecimal) -> Self {
+        Self::new().value(value).kind(MiningType::Rikaz)
+    }
+}
+
+impl MiningAssets {
+    /// Returns an error for every invalid field (see `validate()`).
+    fn field_errors(&self) -> Vec<ZakatError> {
+        Validator::collect_negative(&[
+            ("value", self.value),
+        ], self.label.clone())
+    }
 }
 
 impl CalculateZakat for MiningAssets {
@@ -90,6 +109,18 @@ impl CalculateZakat for MiningAssets {
     fn get_label(&self) -> Option<String> { self.label.clone() }
     fn get_id(&self) -> uuid::Uuid { self.id }
 
+    /// Mines use the gold Nisab; Rikaz has no Nisab and needs no prices.
+    fn requires_prices(&self) -> bool {
+        self.mining_type == MiningType::Mines
+    }
+
+    fn zakat_rate(&self, config: &crate::config::ZakatConfig) -> Decimal {
+        match self.mining_type {
+            MiningType::Rikaz => RIKAZ_RATE,
+            MiningType::Mines => config.strategy.get_rules().trade_goods_rate,
+        }
+    }
+
     #[allow(deprecated)] // Uses deprecated `liabilities_due_now` for backward compat
     fn calculate_zakat<C: ZakatConfigArgument>(&self, config: C) -> Result<ZakatDetails, ZakatError> {
         // Validate deferred input errors first
@@ -107,19 +138,25 @@ impl CalculateZakat for MiningAssets {
                 // Rate: 20%. No Nisab (or minimal). No Debts deduction.
                 // Requirement: "Rikaz Rate: 20% (No Hawl, No Debts deduction)."
                 // We IGNORE hawl_satisfied here.
-                let rate = dec!(0.20);
+                let rate = RIKAZ_RATE;
                 
                 // We purposefully IGNORE extra_debts for Rikaz as per requirement.
                 // We set liabilities to 0.
                 // Nisab: 0 (Paying on whatever is found).
                 
                 // Calculate Trace
-                let trace = vec![
+                let mut trace = vec![
                     crate::types::CalculationStep::initial("step-rikaz-value", "Rikaz Found Value", self.value)
                         .with_reference("Sahih Bukhari 1499"),
                     crate::types::CalculationStep::info("info-rikaz-rule", "Rikaz Rule: No Nisab, No Debt Deduction, 20% Rate"),
                     crate::types::CalculationStep::rate("step-rate-applied", "Applied Rate (20%)", rate),
                 ];
+                let adjustment = crate::maal::calculator::DueAdjustment::new(
+                    ZakatDecimal::new(self.value).checked_mul(rate)?.value,
+                    config.rounding_for(&crate::types::WealthType::Rikaz),
+                    config.minimum_payment,
+                );
+                trace.extend(adjustment.steps());
                 
                 // Manually notify observer since we bypass standard calculator
                 let observer = config.observer.clone();
@@ -127,8 +164,10 @@ impl CalculateZakat for MiningAssets {
                     observer.on_step(step);
                 }
 
-                Ok(ZakatDetails::with_breakdown(self.value, Decimal::ZERO, Decimal::ZERO, rate, crate::types::WealthType::Rikaz, trace)
-                    .with_label(self.label.clone().unwrap_or_default()))
+                let mut details = ZakatDetails::with_breakdown(self.value, Decimal::ZERO, Decimal::ZERO, rate, crate::types::WealthType::Rikaz, trace)
+                    .with_label(self.label.clone().unwrap_or_default());
+                adjustment.apply_to(&mut details);
+                Ok(details)
             },
             MiningType::Mines => {
                 let nisab_threshold = ZakatDecimal::new(config.gold_price_per_gram)
@@ -139,23 +178,28 @@ impl CalculateZakat for MiningAssets {
                 // Dynamic rate from strategy (default 2.5%)
                 let rate = config.strategy.get_rules().trade_goods_rate;
 
-                let trace_steps = vec![
+                let mut trace_steps = vec![
                     crate::types::CalculationStep::initial("step-extracted-value", "Extracted Value", self.value)
                         .with_reference("Fiqh Consensus"),
                 ];
+                trace_steps.extend(self.debt_policy_step(config.debt_policy));
 
                 let params = MonetaryCalcParams {
                     total_assets: self.value,
-                    liabilities: self.total_liabilities(),
+                    liabilities: self.checked_liabilities_under(config.debt_policy, config.as_of_date())?,
                     nisab_threshold: *nisab_threshold,
                     rate,
+                    rounding: config.rounding_for(&crate::types::WealthType::Mining),
                     wealth_type: crate::types::WealthType::Mining,
                     label: self.label.clone(),
                     asset_id: Some(self.id),
-                    hawl_satisfied: self.hawl_satisfied,
+                    hawl_satisfied: self.hawl_is_satisfied(config.as_of_date()),
                     trace_steps,
                     warnings: Vec::new(),
                     observer: Some(config.observer.clone()),
+                    minimum_payment: config.minimum_payment,
+                    nisab_band: Some(config.nisab_band()),
+                    liability_steps: Vec::new(),
                 };
 
                 calculate_monetary_asset(params)
@@ -185,6 +229,39 @@ mod tests {
         assert_eq!(res.zakat_due, Decimal::from(200));
     }
     
+    #[test]
+    fn test_rikaz_constructor_ignores_hawl_and_nisab() {
+        let res = MiningAssets::rikaz(50)
+            .hawl(false)
+            .calculate_zakat(&ZakatConfig::new().with_gold_price(100))
+            .unwrap();
+
+        // 50 is far below the gold Nisab, yet the fifth is still due.
+        assert_eq!(res.wealth_type, crate::types::WealthType::Rikaz);
+        assert_eq!(res.zakat_due, dec!(10));
+        let rate = res.calculation_breakdown.0.iter().find(|s| s.key == "step-rate-applied").expect("rate step");
+        assert_eq!(rate.amount, Some(dec!(0.20)));
+    }
+
+    #[test]
+    fn test_rikaz_applies_rounding_and_minimum_payment() {
+        use crate::config::{RoundingMode, RoundingRule};
+        let config = ZakatConfig::new()
+            .with_rounding(RoundingRule::new(2, RoundingMode::HalfUp))
+            .with_minimum_payment(dec!(1));
+
+        // 1234.56 x 20% = 246.912
+        let res = MiningAssets::rikaz(dec!(1234.56)).calculate_zakat(&config).unwrap();
+        assert_eq!(res.zakat_due, dec!(246.91));
+        assert_eq!(res.payload, crate::types::PaymentPayload::Monetary(dec!(246.91)));
+        assert!(res.calculation_breakdown.iter().any(|s| s.key == "info-rounding"));
+
+        // 2 x 20% = 0.4, raised to the minimum payment.
+        let small = MiningAssets::rikaz(2).calculate_zakat(&config).unwrap();
+        assert_eq!(small.zakat_due, dec!(1));
+        assert!(small.calculation_breakdown.iter().any(|s| s.key == "info-minimum-payment"));
+    }
+
     #[test]
     fn test_minerals() {
          let config = ZakatConfig::new().with_gold_price(100);
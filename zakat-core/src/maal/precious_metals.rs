@@ -63,6 +63,30 @@ impl crate::inputs::FromFfiString for Gender {
     }
 }
 
+/// Which value to zakat when a coin carries a collectible (numismatic) premium.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, strum::Display, strum::EnumString, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum NumismaticValuation {
+    /// Use whichever of melt value and numismatic value is higher.
+    #[default]
+    Higher,
+    /// Always use the melt (metal content) value.
+    Melt,
+    /// Always use the numismatic (market) value.
+    Numismatic,
+}
+
+impl crate::inputs::ToFfiString for NumismaticValuation {
+    fn to_ffi_string(&self) -> String { self.to_string() }
+}
+impl crate::inputs::FromFfiString for NumismaticValuation {
+    type Err = strum::ParseError;
+    fn from_ffi_string(s: &str) -> Result<Self, Self::Err> {
+        use std::str::FromStr;
+        Self::from_str(s)
+    }
+}
+
 // MACRO USAGE
 crate::zakat_ffi_export! {
     #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,6 +97,8 @@ crate::zakat_ffi_export! {
         pub usage: JewelryUsage,
         pub stone_weight_grams: Decimal,
         pub gender: Option<Gender>,
+        pub numismatic_value: Option<Decimal>,
+        pub numismatic_valuation: NumismaticValuation,
     }
 }
 
@@ -87,12 +113,15 @@ impl Default for PreciousMetals {
             usage: JewelryUsage::Investment,
             stone_weight_grams: Decimal::ZERO,
             gender: None,
+            numismatic_value: None,
+            numismatic_valuation: NumismaticValuation::default(),
             liabilities_due_now,
             named_liabilities,
             hawl_satisfied,
             label,
             id,
             acquisition_date,
+            hawl_status: None,
             _input_errors,
         }
     }
@@ -203,6 +232,21 @@ impl PreciousMetals {
         self
     }
 
+    /// Sets the collectible market value of coins, as opposed to their melt value.
+    ///
+    /// When set, the value zakated is chosen by [`Self::numismatic_valuation`]
+    /// (the higher of the two by default). Pass `None` to clear it.
+    pub fn numismatic_value(mut self, value: Option<Decimal>) -> Self {
+        self.numismatic_value = value;
+        self
+    }
+
+    /// Sets which value is zakated when a numismatic value is present.
+    pub fn numismatic_valuation(mut self, valuation: NumismaticValuation) -> Self {
+        self.numismatic_valuation = valuation;
+        self
+    }
+
     /// Constructor for White Gold (alias for Gold).
     /// Treat as Gold but semantically clearer for users.
     pub fn white_gold(weight: impl IntoZakatDecimal, purity: impl IntoZakatDecimal) -> Self {
@@ -210,19 +254,35 @@ impl PreciousMetals {
     }
 }
 
-impl CalculateZakat for PreciousMetals {
-    fn validate_input(&self) -> Result<(), ZakatError> { self.validate() }
-    fn get_label(&self) -> Option<String> { self.label.clone() }
-    fn get_id(&self) -> uuid::Uuid { self.id }
-
-    #[allow(deprecated)] // Uses deprecated `liabilities_due_now` for backward compat
-    fn calculate_zakat<C: ZakatConfigArgument>(&self, config: C) -> Result<ZakatDetails, ZakatError> {
-        // Validate deferred input errors first
-        self.validate()?;
+impl PreciousMetals {
+    /// Returns an error for every invalid field (see `validate()`).
+    fn field_errors(&self) -> Vec<ZakatError> {
+        let mut errors = Validator::collect_negative(&[
+            ("weight", self.weight_grams),
+            ("stone_weight_grams", self.stone_weight_grams),
+            ("numismatic_value", self.numismatic_value.unwrap_or_default()),
+        ], self.label.clone());
+
+        if self.metal_type == Some(WealthType::Gold) && self.purity > Decimal::from(24) {
+            errors.push(ZakatError::InvalidInput(Box::new(InvalidInputDetails {
+                field: "purity".to_string(),
+                value: self.purity.to_string(),
+                reason_key: "error-gold-purity".to_string(),
+                source_label: self.label.clone(),
+                asset_id: Some(self.id),
+                suggestion: Some("Gold purity must be 1-24 karats.".to_string()),
+                ..Default::default()
+            })));
+        }
 
-        let config_cow = config.resolve_config();
-        let config = config_cow.as_ref();
+        errors
+    }
+}
 
+impl PreciousMetals {
+    /// Checks the metal type, weights and purity, returning the metal and its
+    /// net weight after stones. Shared by `calculate_zakat` and `preflight`.
+    fn checked_metal(&self) -> Result<(WealthType, Decimal), ZakatError> {
         // 1. Validate metal type
         let metal_type = Validator::require(&self.metal_type, "metal_type", self.label.clone())?.clone();
 
@@ -242,7 +302,8 @@ impl CalculateZakat for PreciousMetals {
 
         Validator::ensure_non_negative(&[
             ("weight", self.weight_grams),
-            ("net_weight", net_weight)
+            ("net_weight", net_weight),
+            ("numismatic_value", self.numismatic_value.unwrap_or_default())
         ], self.label.clone())?;
 
         // 3. Validate purity range based on metal type
@@ -272,6 +333,45 @@ impl CalculateZakat for PreciousMetals {
             }))),
         };
 
+        Ok((metal_type, net_weight))
+    }
+}
+
+impl CalculateZakat for PreciousMetals {
+    fn validate_input(&self) -> Result<(), ZakatError> { self.validate() }
+    fn get_label(&self) -> Option<String> { self.label.clone() }
+    fn get_id(&self) -> uuid::Uuid { self.id }
+    fn requires_prices(&self) -> bool { true }
+
+    fn preflight(&self, config: &crate::config::ZakatConfig) -> Result<(), ZakatError> {
+        self.validate()?;
+        let (metal_type, _) = self.checked_metal()?;
+        let (price, field) = if metal_type == WealthType::Gold {
+            (config.gold_price_per_gram, "gold_price_per_gram")
+        } else {
+            (config.silver_price_per_gram, "silver_price_per_gram")
+        };
+        if price <= Decimal::ZERO {
+            return Err(ZakatError::MissingConfig {
+                field: field.to_string(),
+                source_label: self.label.clone(),
+                asset_id: Some(self.id),
+            });
+        }
+        Ok(())
+    }
+
+    #[allow(deprecated)] // Uses deprecated `liabilities_due_now` for backward compat
+    fn calculate_zakat<C: ZakatConfigArgument>(&self, config: C) -> Result<ZakatDetails, ZakatError> {
+        // Validate deferred input errors first
+        self.validate()?;
+
+        let config_cow = config.resolve_config();
+        let config = config_cow.as_ref();
+
+        // 1-3. Validate metal type, weights and purity
+        let (metal_type, net_weight) = self.checked_metal()?;
+
         // 4. Check for personal usage exemption (Madhab-specific)
         // Fiqh Rule: Gold for Men is Haram. Haram wealth is not exempt (Kanz).
         let is_male_gold = matches!((&self.gender, &metal_type), (Some(Gender::Male), WealthType::Gold));
@@ -285,12 +385,19 @@ impl CalculateZakat for PreciousMetals {
              exempt_base
         };
 
-        if effectively_exempt {
+        // Luxury rule: personal jewelry beyond the customary weight is zakated anyway.
+        let luxury_split = match config.jewelry_luxury_threshold_grams {
+            Some(threshold) if effectively_exempt && net_weight > threshold => Some((threshold, net_weight - threshold)),
+            _ => None,
+        };
+
+        if effectively_exempt && luxury_split.is_none() {
             return Ok(ZakatDetails::below_threshold(
                 Decimal::ZERO, 
                 metal_type, 
                 "Exempt per Madhab (Huliyy al-Mubah)"
-            ).with_label(self.label.clone().unwrap_or_default()));
+            ).with_status_reason_key("reason-jewelry-exempt")
+            .with_label(self.label.clone().unwrap_or_default()));
         }
 
         // 5. Get price and nisab for metal type
@@ -316,17 +423,12 @@ impl CalculateZakat for PreciousMetals {
             .checked_mul(price_per_gram)?;
 
         // 7. Determine hawl satisfaction (acquisition_date takes precedence)
-        let hawl_is_satisfied = if let Some(date) = self.acquisition_date {
-            crate::hawl::HawlTracker::new(chrono::Local::now().date_naive())
-                .acquired_on(date)
-                .is_satisfied()
-        } else {
-            self.hawl_satisfied
-        };
+        let hawl_is_satisfied = self.hawl_is_satisfied(config.as_of_date());
 
         // 8. Apply purity normalization (unique to precious metals)
         // Use net_weight (after stone deduction) for purity calc
-        let (effective_weight, purity_trace_steps) = self.normalize_purity(&metal_type, net_weight)?;
+        let assessed_weight = luxury_split.map_or(net_weight, |(_, excess)| excess);
+        let (effective_weight, purity_trace_steps) = self.normalize_purity(&metal_type, assessed_weight)?;
 
         // 9. Calculate total value
         let total_value = effective_weight
@@ -345,6 +447,11 @@ impl CalculateZakat for PreciousMetals {
              trace_steps.push(CalculationStep::result("step-net-weight", "Net Metal Weight", net_weight));
         }
 
+        if let Some((threshold, excess)) = luxury_split {
+             trace_steps.push(CalculationStep::subtract("step-jewelry-customary", "Customary Personal Jewelry (exempt)", threshold));
+             trace_steps.push(CalculationStep::result("step-jewelry-luxury", "Jewelry Above Customary Amount", excess));
+        }
+
         if is_male_gold && self.usage == JewelryUsage::PersonalUse {
              trace_steps.push(CalculationStep::info("info-male-gold", "Gold held by male is not exempt (Haram usage)")
                 .with_args(std::collections::HashMap::from([("gender".to_string(), "Male".to_string())]))
@@ -355,24 +462,69 @@ impl CalculateZakat for PreciousMetals {
         trace_steps.extend(purity_trace_steps);
         trace_steps.push(CalculationStep::result("step-total-value", "Total Value", *total_value));
 
+        let zakatable_value = match self.numismatic_value {
+            Some(numismatic) => {
+                let use_numismatic = match self.numismatic_valuation {
+                    NumismaticValuation::Higher => numismatic > *total_value,
+                    NumismaticValuation::Melt => false,
+                    NumismaticValuation::Numismatic => true,
+                };
+                trace_steps.push(CalculationStep::initial("step-numismatic-value", "Numismatic Value", numismatic));
+                if use_numismatic {
+                    trace_steps.push(CalculationStep::info("info-numismatic-used", "Zakat assessed on numismatic value instead of melt value")
+                        .with_args(std::collections::HashMap::from([("valuation".to_string(), self.numismatic_valuation.to_string())])));
+                    numismatic
+                } else {
+                    trace_steps.push(CalculationStep::info("info-melt-used", "Zakat assessed on melt value")
+                        .with_args(std::collections::HashMap::from([("valuation".to_string(), self.numismatic_valuation.to_string())])));
+                    *total_value
+                }
+            }
+            None => *total_value,
+        };
+
         // 11. Delegate to shared monetary calculator
         let rate = config.strategy.get_rules().trade_goods_rate;
 
+        let mut warnings: Vec<String> = if metal_type == WealthType::Silver {
+            config.silver_price_warning().into_iter().collect()
+        } else {
+            Vec::new()
+        };
+
+        if let Some(min_weight) = config.min_metal_weight_grams
+            && self.weight_grams > Decimal::ZERO
+            && self.weight_grams < min_weight
+        {
+            warnings.push(format!(
+                "Weight of {}g is below the plausible minimum of {}g; please check for a data entry error.",
+                self.weight_grams, min_weight
+            ));
+        }
+
+        trace_steps.extend(self.debt_policy_step(config.debt_policy));
+        // Metals are always measured against their own weight Nisab.
+        trace_steps.extend(config.nisab_metal_step(&metal_type));
+
         let params = MonetaryCalcParams {
-            total_assets: *total_value,
-            liabilities: self.total_liabilities(),
+            total_assets: zakatable_value,
+            liabilities: self.checked_liabilities_under(config.debt_policy, config.as_of_date())?,
             nisab_threshold: *nisab_value,
             rate,
-            wealth_type: metal_type,
+            rounding: config.rounding_for(&metal_type),
+            wealth_type: metal_type.clone(),
             label: self.label.clone(),
             asset_id: Some(self.id),
             hawl_satisfied: hawl_is_satisfied,
             trace_steps,
-            warnings: Vec::new(),
+            warnings,
             observer: Some(config.observer.clone()),
+            minimum_payment: config.minimum_payment,
+            nisab_band: Some(config.nisab_band()),
+            liability_steps: self.liability_steps(config.debt_policy, config.as_of_date())?,
         };
 
-        calculate_monetary_asset(params)
+        Ok(calculate_monetary_asset(params)?.with_nisab_metal(metal_type))
     }
 }
 
@@ -485,6 +637,24 @@ mod tests {
         assert_eq!(zakat.zakat_due, Decimal::ZERO);
     }
 
+    #[test]
+    fn test_gold_debt_with_due_date_is_itemized() {
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let config = ZakatConfig::new().with_gold_price(100).with_calculation_date(today);
+        let due = today + chrono::Duration::days(10);
+        let metal = PreciousMetals::gold(100)
+            .add_liability_due("Jeweller", 1000, due)
+            .hawl(true);
+
+        let zakat = metal.calculate_zakat(&config).unwrap();
+        assert_eq!(zakat.net_assets, dec!(9000));
+        assert_eq!(zakat.zakat_due, dec!(225));
+
+        let step = zakat.calculation_breakdown.0.iter().find(|step| step.key == "step-liability").unwrap();
+        assert_eq!(step.amount, Some(dec!(1000)));
+        assert_eq!(step.args.as_ref().unwrap()["label"], "Jeweller");
+    }
+
     #[test]
     fn test_gold_purity_18k() {
         let config = ZakatConfig::new().with_gold_price(100);
@@ -574,6 +744,55 @@ mod tests {
         assert_eq!(zakat.status_reason, Some("Exempt per Madhab (Huliyy al-Mubah)".to_string()));
     }
 
+    #[test]
+    fn test_jewelry_above_luxury_threshold_is_zakated() {
+        let config = ZakatConfig::new()
+            .with_gold_price(100)
+            .with_madhab(Madhab::Shafi)
+            .with_jewelry_luxury_threshold_grams(Some(dec!(100)));
+
+        let zakat = PreciousMetals::new()
+            .weight(200)
+            .metal_type(WealthType::Gold)
+            .usage(JewelryUsage::PersonalUse)
+            .hawl(true)
+            .calculate_zakat(&config)
+            .unwrap();
+
+        // Only the 100g above the threshold: 100g * 100 * 2.5% = 250.
+        assert!(zakat.is_payable);
+        assert_eq!(zakat.total_assets, dec!(10000));
+        assert_eq!(zakat.zakat_due, dec!(250));
+        let steps = &zakat.calculation_breakdown.0;
+        assert!(steps.iter().any(|s| s.key == "step-jewelry-customary" && s.amount == Some(dec!(100))));
+        assert!(steps.iter().any(|s| s.key == "step-jewelry-luxury" && s.amount == Some(dec!(100))));
+
+        // Within the customary amount the Madhab exemption still holds.
+        let modest = PreciousMetals::new()
+            .weight(80)
+            .metal_type(WealthType::Gold)
+            .usage(JewelryUsage::PersonalUse)
+            .hawl(true)
+            .calculate_zakat(&config)
+            .unwrap();
+        assert!(!modest.is_payable);
+    }
+
+    #[test]
+    fn test_gold_between_silver_and_gold_nisab_is_recommended() {
+        // Gold Nisab 8500, silver Nisab 595; the Gold standard applies
+        let config = ZakatConfig::new()
+            .with_gold_price(100)
+            .with_silver_price(1)
+            .with_nisab_standard(crate::madhab::NisabStandard::Gold);
+
+        let details = PreciousMetals::gold(10).hawl(true).calculate_zakat(&config).unwrap();
+
+        assert!(!details.is_payable);
+        assert_eq!(details.recommendation, crate::types::ZakatRecommendation::Recommended);
+        assert!(details.calculation_breakdown.0.iter().any(|s| s.key == "info-sadaqah-recommended"));
+    }
+
     #[test]
     fn test_silver_purity_925() {
         let config = ZakatConfig::new().with_silver_price(1.0); // $1/g
@@ -614,4 +833,97 @@ mod tests {
         // 10 Ounce * 31.1034768 = 311.034768g
         assert_eq!(metal_ounce.weight_grams, dec!(311.034768));
     }
+
+    #[test]
+    fn test_numismatic_value_higher_than_melt() {
+        let config = ZakatConfig::new().with_gold_price(100);
+        // 100g coins: melt value 10,000, collector value 15,000
+        let coins = PreciousMetals::gold(100).numismatic_value(Some(dec!(15000)));
+
+        let zakat = coins.calculate_zakat(&config).unwrap();
+        assert_eq!(zakat.total_assets, dec!(15000));
+        assert_eq!(zakat.zakat_due, dec!(375));
+        assert!(zakat.calculation_breakdown.iter().any(|s| s.key == "info-numismatic-used"));
+
+        let melt_only = coins.numismatic_valuation(NumismaticValuation::Melt)
+            .calculate_zakat(&config)
+            .unwrap();
+        assert_eq!(melt_only.total_assets, dec!(10000));
+    }
+
+    #[test]
+    fn test_clearing_numismatic_value_falls_back_to_melt() {
+        let config = ZakatConfig::new().with_gold_price(100);
+        let coins = PreciousMetals::gold(100)
+            .numismatic_value(Some(dec!(15000)))
+            .numismatic_value(None);
+
+        let zakat = coins.calculate_zakat(&config).unwrap();
+        assert_eq!(zakat.total_assets, dec!(10000));
+        assert!(!zakat.calculation_breakdown.iter().any(|s| s.key == "info-numismatic-used"));
+    }
+
+    #[test]
+    fn test_min_metal_weight_warning() {
+        let config = ZakatConfig::new()
+            .with_gold_price(100)
+            .with_min_metal_weight(dec!(0.01));
+
+        let tiny = PreciousMetals::gold(dec!(0.0001)).calculate_zakat(&config).unwrap();
+        #[allow(deprecated)]
+        let warnings = tiny.warnings;
+        assert!(warnings.iter().any(|w| w.contains("plausible minimum")));
+
+        let normal = PreciousMetals::gold(100).calculate_zakat(&config).unwrap();
+        #[allow(deprecated)]
+        let warnings = normal.warnings;
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_validate_reports_every_bad_field() {
+        let metal = PreciousMetals::gold(-5).purity(2000);
+
+        match metal.validate() {
+            Err(ZakatError::MultipleErrors(errors)) => {
+                let fields: Vec<String> = errors.iter().map(|e| match e {
+                    ZakatError::InvalidInput(details) => details.field.clone(),
+                    other => panic!("unexpected error: {:?}", other),
+                }).collect();
+                assert_eq!(fields.len(), 2);
+                assert!(fields.contains(&"weight".to_string()));
+                assert!(fields.contains(&"purity".to_string()));
+            }
+            other => panic!("expected MultipleErrors, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_summary_reports_label_and_due() {
+        let config = ZakatConfig { gold_price_per_gram: dec!(100), ..Default::default() };
+        let bar = PreciousMetals::gold(100).hawl(true).label("Savings Bar");
+
+        let summary = bar.summary(&config).unwrap();
+
+        // 100g * 100 * 2.5% = 250
+        assert!(summary.contains("Savings Bar"), "{summary}");
+        assert!(summary.contains("250.00"), "{summary}");
+    }
+
+    #[test]
+    fn test_preflight_checks_inputs_and_prices_without_calculating() {
+        let config = ZakatConfig::new().with_gold_price(100);
+
+        assert!(PreciousMetals::gold(100).preflight(&config).is_ok());
+        let too_pure = PreciousMetals::gold(100).purity(25);
+        assert!(matches!(too_pure.preflight(&config), Err(ZakatError::InvalidInput(_))));
+        assert!(matches!(
+            PreciousMetals::silver(600).preflight(&config),
+            Err(ZakatError::MissingConfig { ref field, .. }) if field == "silver_price_per_gram"
+        ));
+
+        let unpriced = ZakatConfig::new();
+        assert!(crate::maal::business::BusinessZakat::new().cash(100).preflight(&unpriced).is_err());
+        assert!(crate::maal::business::BusinessZakat::new().cash(100).preflight(&config).is_ok());
+    }
 }
@@ -89,6 +89,7 @@ impl Default for RestrictedFund {
             label,
             id,
             acquisition_date,
+            hawl_status: None,
             _input_errors,
         }
     }
@@ -135,11 +136,25 @@ impl RestrictedFund {
     }
 }
 
+impl RestrictedFund {
+    /// Returns an error for every invalid field (see `validate()`).
+    fn field_errors(&self) -> Vec<ZakatError> {
+        crate::validation::Validator::collect_negative(&[
+            ("total_value", self.total_value),
+            ("vested_amount", self.vested_amount),
+            ("withdrawal_penalty", self.withdrawal_penalty),
+        ], self.label.clone())
+    }
+}
+
 impl CalculateZakat for RestrictedFund {
     fn validate_input(&self) -> Result<(), ZakatError> {
         self.validate()
     }
 
+    /// The Nisab is valued from gold/silver prices.
+    fn requires_prices(&self) -> bool { true }
+
     fn calculate_zakat<C: ZakatConfigArgument>(&self, config: C) -> Result<ZakatDetails, ZakatError> {
         self.validate()?;
         
@@ -174,23 +189,28 @@ impl CalculateZakat for RestrictedFund {
         let rate = config.strategy.get_rules().savings_rate; // Treat as savings
 
         let total_assets = ZakatDecimal::new(zakatable_amount).with_source(self.label.clone());
-        let trace_steps = vec![
+        let mut trace_steps = vec![
             crate::types::CalculationStep::initial("step-total-value", "Total Fund Value", self.total_value),
             crate::types::CalculationStep::result("step-zakatable-base", note, zakatable_amount),
         ];
+        trace_steps.extend(self.debt_policy_step(config.debt_policy));
 
         let params = MonetaryCalcParams {
             total_assets: *total_assets,
-            liabilities: self.total_liabilities(),
+            liabilities: self.checked_liabilities_under(config.debt_policy, config.as_of_date())?,
             nisab_threshold: nisab,
             rate,
+            rounding: config.rounding_for(&WealthType::Investment),
             wealth_type: WealthType::Investment, // Broadly cash/savings
             label: self.label.clone(),
-            hawl_satisfied: self.hawl_satisfied,
+            hawl_satisfied: self.hawl_is_satisfied(config.as_of_date()),
             asset_id: Some(self.id),
             trace_steps,
-            warnings: Vec::new(),
+            warnings: config.monetary_price_warnings(),
             observer: Some(config.observer.clone()),
+            minimum_payment: config.minimum_payment,
+            nisab_band: Some(config.nisab_band()),
+            liability_steps: Vec::new(),
         };
 
         let mut result = calculate_monetary_asset(params)?;
@@ -214,6 +234,10 @@ impl CalculateZakat for RestrictedFund {
     fn get_id(&self) -> uuid::Uuid {
         self.id
     }
+
+    fn zakat_rate(&self, config: &crate::config::ZakatConfig) -> Decimal {
+        config.strategy.get_rules().savings_rate
+    }
 }
 
 #[cfg(test)]
@@ -9,7 +9,7 @@
 /// - The struct definition with user-defined fields plus common fields
 ///   (`liabilities_due_now`, `hawl_satisfied`, `label`, `id`, `_input_errors`)
 /// - Common setters: `debt()`, `hawl()`, `label()`, `with_id()`
-/// - A `validate()` method that returns deferred input errors
+/// - A `validate()` method that returns deferred input errors and field errors
 /// - Helper methods: `get_id()`, `get_label()`, `default_common()`
 ///
 /// # Error Handling
@@ -44,7 +44,9 @@
 /// }
 /// ```
 ///
-/// The user must still implement `calculate_zakat` manually as it differs per asset.
+/// The user must still implement `calculate_zakat` manually as it differs per asset,
+/// as well as a private `fn field_errors(&self) -> Vec<ZakatError>` returning every
+/// invalid field (used by `validate()`).
 #[macro_export]
 macro_rules! zakat_asset {
     (
@@ -82,6 +84,10 @@ macro_rules! zakat_asset {
             pub id: uuid::Uuid,
             /// Date when the asset was acquired (for precise Hawl calculation).
             pub acquisition_date: Option<chrono::NaiveDate>,
+            /// Explicit Hawl status, used for `Unknown`. `None` falls back to
+            /// `acquisition_date` and then `hawl_satisfied`.
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub hawl_status: Option<$crate::hawl::HawlStatus>,
             /// Hidden field for deferred input validation errors.
             #[serde(skip)]
             _input_errors: Vec<$crate::types::ZakatError>,
@@ -123,6 +129,24 @@ macro_rules! zakat_asset {
                 self
             }
 
+            /// Adds a named (immediate) liability that falls due on `due_date`.
+            ///
+            /// It is only deducted if it falls due within the coming lunar year.
+            pub fn add_liability_due(mut self, description: impl Into<String>, amount: impl $crate::inputs::IntoZakatDecimal, due_date: chrono::NaiveDate) -> Self {
+                match amount.into_zakat_decimal() {
+                    Ok(v) => {
+                        self.named_liabilities.push($crate::types::Liability::new(description, v).due_on(due_date));
+                    },
+                    Err(e) => self._input_errors.push(e),
+                }
+                self
+            }
+
+            /// Returns the named liabilities due on or before `date`, undated ones included.
+            pub fn liabilities_due_before(&self, date: chrono::NaiveDate) -> Vec<&$crate::types::Liability> {
+                self.named_liabilities.iter().filter(|l| l.is_due_by(date)).collect()
+            }
+
             /// Adds a long-term liability (e.g., Mortgage).
             /// Only 12 months of payments will be deductible.
             pub fn add_long_term_liability(mut self, description: impl Into<String>, total_amount: impl $crate::inputs::IntoZakatDecimal, monthly_payment: impl $crate::inputs::IntoZakatDecimal) -> Self {
@@ -139,11 +163,15 @@ macro_rules! zakat_asset {
                 self
             }
             
-            /// Returns the total *deductible* liabilities (legacy + smart calculation).
+            /// Returns all liabilities (legacy + named), with long-term debts capped at
+            /// the upcoming year's payments.
+            ///
+            /// This ignores due dates and the configured [`DebtDeductionPolicy`]($crate::types::DebtDeductionPolicy);
+            /// use [`Self::liabilities_under`] for what a calculation actually deducts.
             ///
             /// # Fiqh Logic (Dayn al-Hal)
-            /// - **Immediate Debt**: Fully deductible.
-            /// - **Long-Term Debt**: Only the upcoming year's payments (12 months) are deductible.
+            /// - **Immediate Debt**: Fully counted.
+            /// - **Long-Term Debt**: Only the upcoming year's payments (12 months) are counted.
             #[allow(deprecated)]
             pub fn total_liabilities(&self) -> rust_decimal::Decimal {
                 // Emit deprecation warning if legacy field is used
@@ -181,18 +209,157 @@ macro_rules! zakat_asset {
                 self.liabilities_due_now + named_sum
             }
 
+            /// Deductible liabilities under the default `OneYearPortion` policy.
+            ///
+            /// Shorthand for [`Self::checked_liabilities_under`]; unlike
+            /// [`Self::total_liabilities`] it leaves out immediate debts due more than
+            /// a lunar year after `as_of`.
+            pub fn checked_total_liabilities(&self, as_of: chrono::NaiveDate) -> Result<rust_decimal::Decimal, $crate::types::ZakatError> {
+                self.checked_liabilities_under($crate::types::DebtDeductionPolicy::default(), as_of)
+            }
+
+            /// The liabilities a calculation under `policy` deducts, for display.
+            ///
+            /// Same as [`Self::checked_liabilities_under`], saturating on overflow.
+            pub fn liabilities_under(&self, policy: $crate::types::DebtDeductionPolicy, as_of: chrono::NaiveDate) -> rust_decimal::Decimal {
+                self.checked_liabilities_under(policy, as_of).unwrap_or(rust_decimal::Decimal::MAX)
+            }
+
+            /// Overflow-safe deductible liabilities under the given debt policy.
+            ///
+            /// Dated immediate debts falling due more than a lunar year after `as_of`
+            /// (usually [`ZakatConfig::as_of_date`]($crate::config::ZakatConfig::as_of_date)) are not deducted.
+            #[allow(deprecated)]
+            pub fn checked_liabilities_under(&self, policy: $crate::types::DebtDeductionPolicy, as_of: chrono::NaiveDate) -> Result<rust_decimal::Decimal, $crate::types::ZakatError> {
+                let mut total = $crate::math::ZakatDecimal::new(self.liabilities_due_now)
+                    .with_source(self.label.clone());
+                for (_, deductible) in self.deductible_liabilities(policy, as_of)? {
+                    total = total.checked_add(deductible)?;
+                }
+                Ok(*total)
+            }
+
+            /// Each named liability with the amount deductible under `policy`.
+            ///
+            /// Immediate debts count only if due within the coming lunar year. Long-term
+            /// debts are capped by the policy whatever their final due date, since
+            /// `OneYearPortion` deducts the coming year's instalments of exactly those.
+            fn deductible_liabilities(&self, policy: $crate::types::DebtDeductionPolicy, as_of: chrono::NaiveDate) -> Result<Vec<(&$crate::types::Liability, rust_decimal::Decimal)>, $crate::types::ZakatError> {
+                use rust_decimal_macros::dec;
+                use $crate::types::{DebtDeductionPolicy, LiabilityType};
+                let window_end = $crate::types::liability_window_end(as_of);
+                self.named_liabilities
+                    .iter()
+                    .filter(|l| l.kind == LiabilityType::LongTerm || l.is_due_by(window_end))
+                    .map(|l| {
+                        let deductible = match (l.kind, policy, l.monthly_payment) {
+                            (LiabilityType::LongTerm, DebtDeductionPolicy::ImmediateOnly, _) => rust_decimal::Decimal::ZERO,
+                            (LiabilityType::LongTerm, DebtDeductionPolicy::OneYearPortion, Some(monthly)) => {
+                                let annual_cap = $crate::math::ZakatDecimal::new(monthly)
+                                    .with_source(self.label.clone())
+                                    .checked_mul(dec!(12))?;
+                                l.amount.min(*annual_cap)
+                            }
+                            _ => l.amount,
+                        };
+                        Ok((l, deductible))
+                    })
+                    .collect()
+            }
+
+            /// Trace steps for the deducted liabilities: one `subtract` per named debt,
+            /// plus the legacy `liabilities_due_now` amount and a note per deferred immediate debt.
+            #[allow(deprecated)]
+            pub fn liability_steps(&self, policy: $crate::types::DebtDeductionPolicy, as_of: chrono::NaiveDate) -> Result<Vec<$crate::types::CalculationStep>, $crate::types::ZakatError> {
+                use $crate::types::CalculationStep;
+                let mut steps = Vec::new();
+                if self.liabilities_due_now > rust_decimal::Decimal::ZERO {
+                    steps.push(CalculationStep::subtract("step-debts-due-now", "Liabilities", self.liabilities_due_now));
+                }
+                for (l, deductible) in self.deductible_liabilities(policy, as_of)? {
+                    if deductible > rust_decimal::Decimal::ZERO {
+                        steps.push(CalculationStep::subtract("step-liability", l.description.clone(), deductible)
+                            .with_args(std::collections::HashMap::from([("label".to_string(), l.description.clone())])));
+                    }
+                }
+                let window_end = $crate::types::liability_window_end(as_of);
+                let deferred = self.named_liabilities.iter()
+                    .filter(|l| l.kind == $crate::types::LiabilityType::Immediate && !l.is_due_by(window_end));
+                for l in deferred {
+                    let due = l.due_date.map(|d| d.to_string()).unwrap_or_default();
+                    steps.push(CalculationStep::info("info-liability-deferred", format!("{} (due {}) falls outside this Hawl and is not deducted", l.description, due))
+                        .with_args(std::collections::HashMap::from([
+                            ("label".to_string(), l.description.clone()),
+                            ("due_date".to_string(), due),
+                        ])));
+                }
+                Ok(steps)
+            }
+
+            /// Trace note naming the debt policy, present only when a long-term debt makes it matter.
+            pub fn debt_policy_step(&self, policy: $crate::types::DebtDeductionPolicy) -> Option<$crate::types::CalculationStep> {
+                self.named_liabilities
+                    .iter()
+                    .any(|l| l.kind == $crate::types::LiabilityType::LongTerm)
+                    .then(|| $crate::types::CalculationStep::info("info-debt-policy", policy.description()))
+            }
+
+            /// Marks the Hawl as satisfied or not.
+            ///
+            /// Boolean shorthand for [`Self::hawl_status`]; an `acquisition_date`, if
+            /// set, still takes precedence as before.
             pub fn hawl(mut self, satisfied: bool) -> Self {
                 self.hawl_satisfied = satisfied;
+                self.hawl_status = None;
                 self
             }
 
+            /// Sets the Hawl status.
+            ///
+            /// `Satisfied`/`NotSatisfied` and `DerivedFrom` are stored in `hawl_satisfied`
+            /// and `acquisition_date`; only [`HawlStatus::Unknown`]($crate::hawl::HawlStatus::Unknown)
+            /// needs the explicit field. An unknown Hawl fails validation, so the asset is
+            /// reported instead of silently treated as exempt.
+            pub fn hawl_status(mut self, status: $crate::hawl::HawlStatus) -> Self {
+                self.hawl_status = None;
+                match status {
+                    $crate::hawl::HawlStatus::Satisfied | $crate::hawl::HawlStatus::NotSatisfied => {
+                        self.hawl_satisfied = status == $crate::hawl::HawlStatus::Satisfied;
+                        self.acquisition_date = None;
+                    }
+                    $crate::hawl::HawlStatus::DerivedFrom(date) => self.acquisition_date = Some(date),
+                    $crate::hawl::HawlStatus::Unknown => self.hawl_status = Some(status),
+                }
+                self
+            }
+
+            /// Returns the effective Hawl status, deriving it for data without an explicit one.
+            pub fn resolved_hawl_status(&self) -> $crate::hawl::HawlStatus {
+                match (self.hawl_status, self.acquisition_date) {
+                    (Some(status), _) => status,
+                    (None, Some(date)) => $crate::hawl::HawlStatus::DerivedFrom(date),
+                    (None, None) => self.hawl_satisfied.into(),
+                }
+            }
+
+            /// Whether the Hawl has passed on `as_of`, usually [`ZakatConfig::as_of_date`]($crate::config::ZakatConfig::as_of_date).
+            /// `Unknown` counts as not satisfied.
+            pub fn hawl_is_satisfied(&self, as_of: chrono::NaiveDate) -> bool {
+                self.resolved_hawl_status()
+                    .is_satisfied_on(as_of)
+                    .unwrap_or(false)
+            }
+
             pub fn label(mut self, val: impl Into<String>) -> Self {
                 self.label = Some(val.into());
                 self
             }
 
+            /// Sets the acquisition date the Hawl is derived from, replacing an earlier
+            /// [`Self::hawl_status`] of `Unknown`.
             pub fn acquired_on(mut self, date: chrono::NaiveDate) -> Self {
                 self.acquisition_date = Some(date);
+                self.hawl_status = None;
                 self
             }
 
@@ -210,14 +377,30 @@ macro_rules! zakat_asset {
             
             /// Validates the asset and returns any input errors.
             ///
+            /// Combines errors collected by the builder setters with the asset's
+            /// own field checks (`field_errors()`), so every bad field is reported.
+            ///
             /// - If no errors, returns `Ok(())`.
             /// - If 1 error, returns `Err(that_error)`.
             /// - If >1 errors, returns `Err(ZakatError::MultipleErrors(...))`.
             pub fn validate(&self) -> Result<(), $crate::types::ZakatError> {
-                match self._input_errors.len() {
+                let mut errors = self._input_errors.clone();
+                errors.extend(self.field_errors());
+                if self.hawl_status == Some($crate::hawl::HawlStatus::Unknown) {
+                    errors.push($crate::types::ZakatError::InvalidInput(Box::new($crate::types::InvalidInputDetails {
+                        field: "hawl_status".to_string(),
+                        value: "unknown".to_string(),
+                        reason_key: "error-hawl-unknown".to_string(),
+                        source_label: self.label.clone(),
+                        asset_id: Some(self.id),
+                        suggestion: Some("Confirm whether a lunar year has passed, or set the acquisition date.".to_string()),
+                        ..Default::default()
+                    })));
+                }
+                match errors.len() {
                     0 => Ok(()),
-                    1 => Err(self._input_errors[0].clone()),
-                    _ => Err($crate::types::ZakatError::MultipleErrors(self._input_errors.clone())),
+                    1 => Err(errors.remove(0)),
+                    _ => Err($crate::types::ZakatError::MultipleErrors(errors)),
                 }
             }
             
@@ -236,14 +419,12 @@ macro_rules! zakat_asset {
         }
 
         impl $crate::traits::TemporalAsset for $name {
-            fn with_acquisition_date(mut self, date: chrono::NaiveDate) -> Self {
-                self.acquisition_date = Some(date);
-                self
+            fn with_acquisition_date(self, date: chrono::NaiveDate) -> Self {
+                self.acquired_on(date)
             }
 
-            fn with_hawl_satisfied(mut self, satisfied: bool) -> Self {
-                self.hawl_satisfied = satisfied;
-                self
+            fn with_hawl_satisfied(self, satisfied: bool) -> Self {
+                self.hawl(satisfied)
             }
         }
     };
@@ -420,18 +601,24 @@ macro_rules! zakat_ffi_export {
                 impl $name {
                     $(
                         #[wasm_bindgen(setter)]
-                        pub fn [<set_ $field>](&mut self, val: &str) -> Result<(), JsError> {
+                        pub fn [<set_ $field>](&mut self, val: &str) -> Result<(), JsValue> {
                             self.inner.$field = <$ty as FromFfiString>::from_ffi_string(val)
-                                .map_err(|e| JsError::new(&format!("Invalid {}: {}", stringify!($field), e)))?;
+                                .map_err(|e| crate::types::FfiZakatError::invalid_field(
+                                    stringify!($field),
+                                    format!("Invalid {}: {}", stringify!($field), e),
+                                ))?;
                             Ok(())
                         }
                     )*
                     
                     #[wasm_bindgen(setter)]
                     #[allow(deprecated)]
-                    pub fn set_liabilities_due_now(&mut self, val: &str) -> Result<(), JsError> {
+                    pub fn set_liabilities_due_now(&mut self, val: &str) -> Result<(), JsValue> {
                          self.inner.liabilities_due_now = Decimal::from_str(val)
-                            .map_err(|e| JsError::new(&format!("Invalid liabilities: {}", e)))?;
+                            .map_err(|e| crate::types::FfiZakatError::invalid_field(
+                                "liabilities_due_now",
+                                format!("Invalid liabilities: {}", e),
+                            ))?;
                          Ok(())
                     }
                 }
@@ -468,10 +655,10 @@ macro_rules! zakat_ffi_export {
 
                 // --- Calculation ---
                 pub fn calculate(&self, config_js: JsValue) -> Result<JsValue, JsValue> {
-                    let config: crate::config::ZakatConfig = serde_wasm_bindgen::from_value(config_js)?;
+                    let config: crate::config::ZakatConfig = serde_wasm_bindgen::from_value(config_js)
+                        .map_err(|e| crate::types::FfiZakatError::invalid_field("config", e.to_string()))?;
                     use crate::traits::CalculateZakat;
-                    let res = self.inner.calculate_zakat(&config)
-                        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+                    let res = self.inner.calculate_zakat(&config)?;
                     Ok(serde_wasm_bindgen::to_value(&res)?)
                 }
             }
@@ -542,6 +729,7 @@ macro_rules! zakat_ffi_export {
                          id: <uuid::Uuid as FromFfiString>::from_ffi_string(&asset.id)
                               .unwrap_or_else(|_| uuid::Uuid::new_v4()),
                          acquisition_date: None,
+                         hawl_status: None,
                          _input_errors: Vec::new(),
                      };
                      
@@ -619,6 +807,7 @@ macro_rules! zakat_ffi_export {
                          id: <uuid::Uuid as FromFfiString>::from_ffi_string(&self.id)
                               .unwrap_or_else(|_| uuid::Uuid::new_v4()),
                          acquisition_date: None,
+                         hawl_status: None,
                          _input_errors: Vec::new(),
                      })
                 }
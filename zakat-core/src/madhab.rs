@@ -10,10 +10,7 @@
 use serde::{Deserialize, Serialize};
 
 
-use crate::types::ZakatError; // Removed ErrorDetails as it is unused in the snippet or I will use simple implementation
-
-
-// ... rest of file (Actually I should probably implement it properly)
+use crate::types::{ZakatError, ZakatRecommendation};
 
 /// Nisab standard for calculating the Zakat threshold on monetary wealth.
 /// - `Gold`: Use the gold Nisab (85g × gold_price).
@@ -74,6 +71,38 @@ impl std::str::FromStr for Madhab {
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
+/// Interpretation of the sheep tiers above 300 head.
+///
+/// The hadith of Anas gives 1 sheep for 40-120, 2 for 121-200, 3 for 201-300,
+/// then "in every hundred, one sheep". Scholars differ on what 301 owes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[typeshare::typeshare]
+#[serde(rename_all = "camelCase")]
+pub enum SheepTierRuling {
+    /// Majority view: 3 sheep until 399, then one per full hundred (400 -> 4).
+    #[default]
+    PerFullHundred,
+    /// Minority view (al-Nakha'i, al-Hasan ibn Salih): exceeding 300 adds a
+    /// fourth sheep, then one per further hundred (301 -> 4, 400 -> 5).
+    ExtraAbove300,
+}
+
+impl SheepTierRuling {
+    /// Returns the number of sheep due for a flock of `count` head.
+    pub fn sheep_due(&self, count: u32) -> u32 {
+        match count {
+            0..=39 => 0,
+            40..=120 => 1,
+            121..=200 => 2,
+            201..=300 => 3,
+            _ => match self {
+                SheepTierRuling::PerFullHundred => count / 100,
+                SheepTierRuling::ExtraAbove300 => count / 100 + 1,
+            },
+        }
+    }
+}
+
 /// Rules that govern Zakat calculations for a specific Madhab.
 /// Contains Nisab standard, jewelry exemption policy, and Zakat rates.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -96,6 +125,9 @@ pub struct ZakatRules {
     /// Zakat rate for general savings/monetary assets.
     #[typeshare(serialized_as = "string")]
     pub savings_rate: Decimal,
+    /// Which reading of the sheep tiers above 300 head to apply.
+    #[serde(default)]
+    pub sheep_tiers: SheepTierRuling,
 }
 
 use crate::inputs::IntoZakatDecimal;
@@ -109,6 +141,7 @@ impl Default for ZakatRules {
             agriculture_rates: (dec!(0.10), dec!(0.05), dec!(0.075)),
             pension_zakat_on_vested: false,
             savings_rate: dec!(0.025),
+            sheep_tiers: SheepTierRuling::default(),
         }
     }
 }
@@ -126,6 +159,12 @@ impl ZakatRules {
         self
     }
 
+    /// Sets the interpretation of the sheep tiers above 300 head.
+    pub fn with_sheep_tiers(mut self, ruling: SheepTierRuling) -> Self {
+        self.sheep_tiers = ruling;
+        self
+    }
+
     /// Sets the trade goods Zakat rate using a semantic decimal type.
     /// Accepts literals like `0.025` directly.
     pub fn with_trade_goods_rate(mut self, rate: impl IntoZakatDecimal) -> Self {
@@ -154,6 +193,33 @@ impl ZakatRules {
     }
 }
 
+/// Classifies `net_assets` against the gold and silver Nisab values (in currency).
+///
+/// - `Obligatory` at or above the higher Nisab, where every view agrees.
+/// - `Recommended` from the lower Nisab up to the higher one: obligatory for those
+///   who apply the lower threshold, voluntary Sadaqah for everyone else.
+/// - `None` below the lower Nisab.
+///
+/// A zero Nisab (unpriced metal) is ignored; with neither priced, returns `None`.
+/// Calculators combine this with their own payability: wealth that is already
+/// payable is `Obligatory` regardless of the band.
+pub fn recommendation_for(net_assets: Decimal, gold_nisab: Decimal, silver_nisab: Decimal) -> ZakatRecommendation {
+    let (lower, upper) = match (gold_nisab > Decimal::ZERO, silver_nisab > Decimal::ZERO) {
+        (true, true) => (gold_nisab.min(silver_nisab), gold_nisab.max(silver_nisab)),
+        (true, false) => (gold_nisab, gold_nisab),
+        (false, true) => (silver_nisab, silver_nisab),
+        (false, false) => return ZakatRecommendation::None,
+    };
+
+    if net_assets >= upper {
+        ZakatRecommendation::Obligatory
+    } else if net_assets >= lower {
+        ZakatRecommendation::Recommended
+    } else {
+        ZakatRecommendation::None
+    }
+}
+
 /// Trait for providing Zakat calculation rules.
 /// 
 /// Implement this trait to create custom Zakat strategies beyond the standard Madhabs.
@@ -163,6 +229,14 @@ pub trait ZakatStrategy: std::fmt::Debug + Send + Sync {
     fn get_rules(&self) -> ZakatRules;
 }
 
+/// A fully built rule set is its own strategy, so it can be injected directly
+/// with [`ZakatConfig::with_strategy`](crate::config::ZakatConfig::with_strategy).
+impl ZakatStrategy for ZakatRules {
+    fn get_rules(&self) -> ZakatRules {
+        self.clone()
+    }
+}
+
 // ============ Implement ZakatStrategy for Madhab enum (preset helper) ============
 
 impl ZakatStrategy for Madhab {
@@ -226,3 +300,29 @@ impl ZakatStrategy for HanbaliStrategy {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recommendation_band_boundaries() {
+        // Gold Nisab 8500, silver Nisab 595
+        let at = |net| recommendation_for(net, dec!(8500), dec!(595));
+
+        assert_eq!(at(dec!(594.99)), ZakatRecommendation::None);
+        assert_eq!(at(dec!(595)), ZakatRecommendation::Recommended);
+        assert_eq!(at(dec!(8499.99)), ZakatRecommendation::Recommended);
+        assert_eq!(at(dec!(8500)), ZakatRecommendation::Obligatory);
+        assert_eq!(at(Decimal::ZERO), ZakatRecommendation::None);
+    }
+
+    #[test]
+    fn test_recommendation_band_with_missing_or_equal_prices() {
+        // Only one metal priced: no band, just the one threshold
+        assert_eq!(recommendation_for(dec!(594), Decimal::ZERO, dec!(595)), ZakatRecommendation::None);
+        assert_eq!(recommendation_for(dec!(595), Decimal::ZERO, dec!(595)), ZakatRecommendation::Obligatory);
+        assert_eq!(recommendation_for(dec!(8500), dec!(8500), dec!(8500)), ZakatRecommendation::Obligatory);
+        assert_eq!(recommendation_for(dec!(100), Decimal::ZERO, Decimal::ZERO), ZakatRecommendation::None);
+    }
+}
@@ -8,10 +8,10 @@ use rust_decimal::Decimal;
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use chrono::{DateTime, Utc};
 
-use crate::traits::CalculateZakat;
+use crate::traits::{CalculateZakat, DynCalculateZakat};
 #[cfg(feature = "async")]
 use crate::traits::AsyncCalculateZakat;
 use crate::types::{ZakatDetails, ZakatError, ErrorDetails, InvalidInputDetails};
@@ -63,6 +63,9 @@ pub struct PortfolioSnapshot {
     pub metadata: HashMap<String, String>,
     /// Version of the snapshot format (for future compatibility).
     pub version: String,
+    /// SHA-256 (hex) over the canonical JSON of every other field, set by `to_json`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
 }
 
 impl PortfolioSnapshot {
@@ -80,6 +83,7 @@ impl PortfolioSnapshot {
             result,
             metadata: HashMap::new(),
             version: "1.0.0".to_string(),
+            content_hash: None,
         }
     }
 
@@ -105,9 +109,57 @@ impl PortfolioSnapshot {
         self.result.total_assets
     }
 
-    /// Returns the snapshot as a pretty-printed JSON string.
+    /// Returns the snapshot as a pretty-printed JSON string, stamped with a fresh `content_hash`.
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
-        serde_json::to_string_pretty(self)
+        serde_json::to_string_pretty(&self.stamped()?)
+    }
+
+    /// Like [`PortfolioSnapshot::to_json`], but compact.
+    pub fn to_json_compact(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&self.stamped()?)
+    }
+
+    fn stamped(&self) -> Result<Self, serde_json::Error> {
+        let mut stamped = self.clone();
+        stamped.content_hash = Some(self.compute_content_hash()?);
+        Ok(stamped)
+    }
+
+    /// Computes the SHA-256 of the snapshot content, excluding `content_hash`.
+    ///
+    /// The content is canonicalized through `serde_json::Value`, whose object keys
+    /// are sorted, so field and `HashMap` ordering do not affect the hash.
+    pub fn compute_content_hash(&self) -> Result<String, serde_json::Error> {
+        use sha2::{Digest, Sha256};
+        let mut value = serde_json::to_value(self)?;
+        if let Some(obj) = value.as_object_mut() {
+            obj.remove("contentHash");
+        }
+        let digest = Sha256::digest(serde_json::to_string(&value)?.as_bytes());
+        Ok(digest.iter().map(|b| format!("{:02x}", b)).collect())
+    }
+
+    /// Suggested file name, `zakat_snapshot_<YYYY-MM-DD>_<hash>.json`, built from the
+    /// snapshot date and the first 8 hex digits of its content hash.
+    ///
+    /// The name depends only on the snapshot, so re-exporting it yields the same name.
+    pub fn suggested_filename(&self) -> String {
+        let hash = self.compute_content_hash().unwrap_or_default();
+        format!(
+            "zakat_snapshot_{}_{}.json",
+            self.timestamp.format("%Y-%m-%d"),
+            &hash[..hash.len().min(8)]
+        )
+    }
+
+    /// Returns true if the stored `content_hash` matches the snapshot content.
+    ///
+    /// Snapshots without a hash (e.g. written before hashing was added) fail verification.
+    pub fn verify_integrity(&self) -> bool {
+        match (&self.content_hash, self.compute_content_hash()) {
+            (Some(stored), Ok(actual)) => *stored == actual,
+            _ => false,
+        }
     }
 
     /// Creates a snapshot from a JSON string.
@@ -149,10 +201,23 @@ pub enum PortfolioItemResult {
         asset_id: Uuid,
         source: String, // Label or Index
         error: ZakatError,
+        /// i18n key of a fix hint (e.g. `hint-set-prices`), from [`ZakatError::hint_key`].
+        #[serde(default)]
+        suggestion: Option<String>,
     },
 }
 
 impl PortfolioItemResult {
+    /// Builds a failure result, deriving the fix suggestion from the error.
+    pub fn failure(asset_id: Uuid, source: impl Into<String>, error: ZakatError) -> Self {
+        Self::Failure {
+            asset_id,
+            source: source.into(),
+            suggestion: Some(error.hint_key().to_string()),
+            error,
+        }
+    }
+
     pub fn asset_id(&self) -> Uuid {
         match self {
             Self::Success { asset_id, .. } => *asset_id,
@@ -172,6 +237,15 @@ pub enum PortfolioStatus {
     Failed,
 }
 
+/// Headline counts of a portfolio calculation, see [`PortfolioResult::summary_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PortfolioSummaryStats {
+    pub total_zakat_due: Decimal,
+    pub asset_count: usize,
+    pub payable_count: usize,
+    pub failed_count: usize,
+}
+
 /// Result of a portfolio calculation, including successes and partial failures.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PortfolioResult {
@@ -184,9 +258,76 @@ pub struct PortfolioResult {
     pub total_zakat_due: Decimal,
     pub items_attempted: usize,
     pub items_failed: usize,
+    /// The configuration (including prices) this result was calculated with,
+    /// embedded by [`ZakatPortfolio::calculate_total_replayable`].
+    ///
+    /// Custom strategies are not serialized; a deserialized config falls back to
+    /// the default strategy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_used: Option<crate::config::ZakatConfig>,
+    /// The input assets this result was calculated from, embedded by
+    /// [`ZakatPortfolio::calculate_total_replayable`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inputs: Vec<PortfolioItem>,
+    /// Group of each input asset, keyed by asset id.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub input_groups: BTreeMap<Uuid, String>,
+    /// Per-asset Madhab overrides the inputs were calculated with.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub madhab_overrides: BTreeMap<Uuid, crate::madhab::Madhab>,
+    /// Aggregated view of all monetary assets, present when Dam' al-Amwal applied
+    /// (their combined net value reached the monetary Nisab).
+    ///
+    /// Its `zakat_due` is the sum of the per-asset monetary dues, so both views agree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub combined_monetary: Option<ZakatDetails>,
+    /// Successes and failures as [`PortfolioItemResult`]s, built on first use so
+    /// [`Self::assets_requiring_attention`] can lend them out.
+    #[serde(skip)]
+    item_results: std::sync::OnceLock<Vec<PortfolioItemResult>>,
 }
 
 impl PortfolioResult {
+    /// Attaches the config, inputs, groups and Madhab overrides used, making the
+    /// result reproducible.
+    fn with_provenance(mut self, config: &crate::config::ZakatConfig, portfolio: &ZakatPortfolio) -> Self {
+        self.config_used = Some(config.clone());
+        self.inputs = portfolio.items.clone();
+        self.input_groups = portfolio.groups.clone();
+        self.madhab_overrides = portfolio.madhab_overrides.clone();
+        self
+    }
+
+    /// Re-runs the calculation using the embedded config, inputs, groups and
+    /// Madhab overrides.
+    ///
+    /// Results without an embedded config (from [`ZakatPortfolio::calculate_total`]
+    /// or older versions) cannot be reproduced and are returned unchanged.
+    pub fn recompute(&self) -> PortfolioResult {
+        match &self.config_used {
+            Some(config) => ZakatPortfolio {
+                items: self.inputs.clone(),
+                groups: self.input_groups.clone(),
+                madhab_overrides: self.madhab_overrides.clone(),
+            }.calculate_total_replayable(config),
+            None => self.clone(),
+        }
+    }
+
+    /// Serializes the result as pretty-printed JSON, like [`PortfolioSnapshot::to_json`].
+    pub fn to_json(&self) -> Result<String, ZakatError> {
+        serde_json::to_string_pretty(self).map_err(Self::serialization_error)
+    }
+
+    /// Serializes the result as compact JSON.
+    pub fn to_json_compact(&self) -> Result<String, ZakatError> {
+        serde_json::to_string(self).map_err(Self::serialization_error)
+    }
+
+    fn serialization_error(e: serde_json::Error) -> ZakatError {
+        ZakatError::serialization("PortfolioResult", e)
+    }
+
     /// Returns a list of failed calculations.
     pub fn failures(&self) -> &Vec<PortfolioItemResult> {
         &self.failures
@@ -210,6 +351,111 @@ impl PortfolioResult {
         list
     }
 
+    /// Returns the results that need action: payable assets with a positive due,
+    /// largest first, followed by failed calculations.
+    ///
+    /// Exempt assets (below Nisab, Hawl not met, nothing due) are left out.
+    pub fn assets_requiring_attention(&self) -> Vec<&PortfolioItemResult> {
+        let results = self.item_results.get_or_init(|| self.results());
+        let due = |result: &PortfolioItemResult| match result {
+            PortfolioItemResult::Success { details, .. } if details.is_payable => Some(details.zakat_due),
+            _ => None,
+        };
+
+        let mut payable: Vec<&PortfolioItemResult> = results.iter()
+            .filter(|r| due(r).is_some_and(|d| d > Decimal::ZERO))
+            .collect();
+        payable.sort_by_key(|r| std::cmp::Reverse(due(r)));
+
+        payable.extend(results.iter().filter(|r| matches!(r, PortfolioItemResult::Failure { .. })));
+        payable
+    }
+
+    /// Returns every warning raised across the portfolio, paired with the label of
+    /// the asset that raised it, in the order the assets were calculated.
+    ///
+    /// Structured warnings come first, followed by legacy string warnings that
+    /// have no structured counterpart. Unlabeled assets are named by their
+    /// position ("Asset #n").
+    #[allow(deprecated)] // Reads the deprecated `warnings` field so no warning is dropped
+    pub fn all_warnings(&self) -> Vec<(String, String)> {
+        self.successes.iter()
+            .enumerate()
+            .flat_map(|(position, details)| {
+                let label = details.label.clone().filter(|label| !label.is_empty()).unwrap_or_else(|| {
+                    let index = details.asset_id
+                        .and_then(|id| self.inputs.iter().position(|item| CalculateZakat::get_id(item) == id))
+                        .unwrap_or(position);
+                    crate::traits::indexed_label(index)
+                });
+                let mut messages: Vec<String> = details.structured_warnings.iter()
+                    .map(|warning| warning.message.clone())
+                    .collect();
+                for warning in &details.warnings {
+                    if !messages.contains(warning) {
+                        messages.push(warning.clone());
+                    }
+                }
+                messages.into_iter().map(move |message| (label.clone(), message))
+            })
+            .collect()
+    }
+
+    /// Returns the independent per-asset results, in input order when the inputs are known.
+    ///
+    /// Pair with [`PortfolioResult::combined_monetary`] for the aggregated monetary view.
+    pub fn per_asset(&self) -> Vec<PortfolioItemResult> {
+        let mut list = self.results();
+        if !self.inputs.is_empty() {
+            let order: HashMap<Uuid, usize> = self.inputs.iter()
+                .enumerate()
+                .map(|(i, item)| (CalculateZakat::get_id(item), i))
+                .collect();
+            list.sort_by_key(|r| order.get(&r.asset_id()).copied().unwrap_or(usize::MAX));
+        }
+        list
+    }
+
+    /// Returns the total Zakat due as structured [`crate::types::Money`].
+    pub fn money_due(&self, config: &crate::config::ZakatConfig) -> crate::types::Money {
+        crate::types::Money::new(self.total_zakat_due, config)
+    }
+
+    /// Returns the headline totals and counts of this result.
+    pub fn summary_stats(&self) -> PortfolioSummaryStats {
+        PortfolioSummaryStats {
+            total_zakat_due: self.total_zakat_due,
+            asset_count: self.items_attempted,
+            payable_count: self.successes.iter().filter(|d| d.is_payable).count(),
+            failed_count: self.items_failed,
+        }
+    }
+
+    /// Returns a one-line summary for notifications and logs.
+    ///
+    /// Format: "Zakat due: $1234.56 across 5 assets (3 payable)"
+    pub fn to_summary_line(&self, config: &crate::config::ZakatConfig) -> String {
+        let stats = self.summary_stats();
+        format!(
+            "Zakat due: {} across {} assets ({} payable)",
+            config.format_currency(stats.total_zakat_due),
+            stats.asset_count,
+            stats.payable_count
+        )
+    }
+
+    /// Localized variant of [`PortfolioResult::to_summary_line`] using the
+    /// `report-summary-line` key with `total`, `assets` and `payable` arguments.
+    pub fn to_summary_line_localized(&self, config: &crate::config::ZakatConfig, translator: &impl crate::traits::Translator) -> String {
+        let stats = self.summary_stats();
+        let args = HashMap::from([
+            ("total".to_string(), config.format_currency(stats.total_zakat_due)),
+            ("assets".to_string(), stats.asset_count.to_string()),
+            ("payable".to_string(), stats.payable_count.to_string()),
+        ]);
+        translator.translate("report-summary-line", Some(&args))
+    }
+
     /// Returns true if there were no failures.
     pub fn is_clean(&self) -> bool {
         self.status == PortfolioStatus::Complete
@@ -241,18 +487,67 @@ impl PortfolioResult {
     }
 }
 
+/// Ordering applied by [`ZakatPortfolio::sort_by`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortKey {
+    /// Group by asset type (in `PortfolioItem` declaration order).
+    Type,
+    /// Highest estimated value first.
+    ValueDesc,
+    /// Alphabetical by label (case-insensitive); unlabeled assets last.
+    Label,
+}
+
+fn asset_not_found(id: Uuid) -> ZakatError {
+    ZakatError::InvalidInput(Box::new(InvalidInputDetails {
+        code: crate::types::ZakatErrorCode::AssetNotFound,
+        field: "asset_id".to_string(),
+        value: id.to_string(),
+        reason_key: "error-asset-not-found".to_string(),
+        suggestion: Some("Asset with this ID does not exist in the portfolio.".to_string()),
+        ..Default::default()
+    }))
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ZakatPortfolio {
     items: Vec<PortfolioItem>,
+    /// Group name of each grouped asset (e.g. "Family", "Shop"), keyed by asset id.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    groups: BTreeMap<Uuid, String>,
+    /// Madhab applied to individual assets instead of the config's, keyed by asset id.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    madhab_overrides: BTreeMap<Uuid, crate::madhab::Madhab>,
 }
 
 impl ZakatPortfolio {
     pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty portfolio with room for `capacity` assets.
+    ///
+    /// Use with [`ZakatPortfolio::add_many`] when loading large portfolios
+    /// to avoid repeated reallocation.
+    pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            items: Vec::new(),
+            items: Vec::with_capacity(capacity),
+            groups: BTreeMap::new(),
+            madhab_overrides: BTreeMap::new(),
         }
     }
 
+    /// Appends many assets in one go, preserving their order.
+    pub fn add_many<I, T>(&mut self, items: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<PortfolioItem>,
+    {
+        let items = items.into_iter();
+        self.items.reserve(items.size_hint().0);
+        self.items.extend(items.map(Into::into));
+    }
+
     #[allow(clippy::should_implement_trait)]
     pub fn add<T: Into<PortfolioItem>>(mut self, item: T) -> Self {
          self.items.push(item.into());
@@ -307,26 +602,64 @@ impl ZakatPortfolio {
     /// Removes an asset by its UUID. Returns the removed item if found.
     pub fn remove(&mut self, id: Uuid) -> Option<PortfolioItem> {
         if let Some(pos) = self.items.iter().position(|c| CalculateZakat::get_id(c) == id) {
+            self.groups.remove(&id);
+            self.madhab_overrides.remove(&id);
             Some(self.items.remove(pos))
         } else {
             None
         }
     }
 
+    /// Removes an asset by its UUID, keeping the order of the rest.
+    ///
+    /// Unlike [`Self::remove`], a missing id is reported as an error.
+    pub fn remove_by_id(&mut self, id: Uuid) -> Result<PortfolioItem, ZakatError> {
+        self.remove(id).ok_or_else(|| asset_not_found(id))
+    }
+
+    /// Removes the asset at `index` (in portfolio order), keeping the order of the rest.
+    pub fn remove_at(&mut self, index: usize) -> Result<PortfolioItem, ZakatError> {
+        if index >= self.items.len() {
+            return Err(ZakatError::InvalidInput(Box::new(InvalidInputDetails {
+                code: crate::types::ZakatErrorCode::AssetNotFound,
+                field: "index".to_string(),
+                value: index.to_string(),
+                reason_key: "error-asset-index-out-of-range".to_string(),
+                args: Some(HashMap::from([("count".to_string(), self.items.len().to_string())])),
+                suggestion: Some(format!("The portfolio has {} assets.", self.items.len())),
+                ..Default::default()
+            })));
+        }
+        let item = self.items.remove(index);
+        self.groups.remove(&CalculateZakat::get_id(&item));
+        self.madhab_overrides.remove(&CalculateZakat::get_id(&item));
+        Ok(item)
+    }
+
     /// Replaces an asset by its UUID.
+    #[deprecated(since = "1.5.0", note = "Use `try_replace`, which returns the replaced item")]
     pub fn replace<T: Into<PortfolioItem>>(&mut self, id: Uuid, new_item: T) -> Result<(), ZakatError> {
+        self.try_replace(id, new_item).map(|_| ())
+    }
+
+    /// Replaces an asset by its UUID, returning the item that was there.
+    ///
+    /// The returned item can be pushed onto an undo stack or written to an audit log.
+    ///
+    /// The asset's group and Madhab override move to the new item.
+    pub fn try_replace<T: Into<PortfolioItem>>(&mut self, id: Uuid, new_item: T) -> Result<PortfolioItem, ZakatError> {
         if let Some(pos) = self.items.iter().position(|c| CalculateZakat::get_id(c) == id) {
-            self.items[pos] = new_item.into();
-            Ok(())
+            let new_item = new_item.into();
+            let new_id = CalculateZakat::get_id(&new_item);
+            if let Some(group) = self.groups.remove(&id) {
+                self.groups.insert(new_id, group);
+            }
+            if let Some(madhab) = self.madhab_overrides.remove(&id) {
+                self.madhab_overrides.insert(new_id, madhab);
+            }
+            Ok(std::mem::replace(&mut self.items[pos], new_item))
         } else {
-            Err(ZakatError::InvalidInput(Box::new(InvalidInputDetails {
-                code: crate::types::ZakatErrorCode::AssetNotFound,
-                field: "asset_id".to_string(),
-                value: id.to_string(),
-                reason_key: "error-asset-not-found".to_string(),
-                suggestion: Some("Asset with this ID does not exist in the portfolio.".to_string()),
-                ..Default::default()
-            })))
+            Err(asset_not_found(id))
         }
     }
 
@@ -381,22 +714,169 @@ impl ZakatPortfolio {
         if let Some(pos) = self.items.iter().position(|item| {
             CalculateZakat::get_label(item).as_deref() == Some(label)
         }) {
-            Some(self.items.remove(pos))
+            let item = self.items.remove(pos);
+            self.groups.remove(&CalculateZakat::get_id(&item));
+            self.madhab_overrides.remove(&CalculateZakat::get_id(&item));
+            Some(item)
         } else {
             None
         }
     }
 
+    /// Puts the asset with `id` in `group`, replacing any previous group.
+    ///
+    /// Returns an error if no asset has this id.
+    pub fn set_group(&mut self, id: Uuid, group: impl Into<String>) -> Result<(), ZakatError> {
+        if self.get(id).is_none() {
+            return Err(asset_not_found(id));
+        }
+        self.groups.insert(id, group.into());
+        Ok(())
+    }
+
+    /// Takes the asset with `id` out of its group, returning the group name.
+    pub fn clear_group(&mut self, id: Uuid) -> Option<String> {
+        self.groups.remove(&id)
+    }
+
+    /// Returns the group the asset with `id` belongs to, if any.
+    pub fn group_of(&self, id: Uuid) -> Option<&str> {
+        self.groups.get(&id).map(String::as_str)
+    }
+
+    /// Returns the distinct group names, sorted alphabetically.
+    pub fn groups(&self) -> Vec<String> {
+        let names: std::collections::BTreeSet<&String> = self.groups.values().collect();
+        names.into_iter().cloned().collect()
+    }
+
+    /// Returns the assets in `group`, in portfolio order.
+    pub fn group_items(&self, group: &str) -> Vec<&PortfolioItem> {
+        self.items
+            .iter()
+            .filter(|item| self.group_of(CalculateZakat::get_id(*item)) == Some(group))
+            .collect()
+    }
+
+    /// Renames `old` to `new` on every member asset. Returns how many assets moved.
+    ///
+    /// Renaming onto an existing group merges the two.
+    pub fn rename_group(&mut self, old: &str, new: impl Into<String>) -> usize {
+        let new = new.into();
+        let mut renamed = 0;
+        for group in self.groups.values_mut().filter(|g| g.as_str() == old) {
+            group.clone_from(&new);
+            renamed += 1;
+        }
+        renamed
+    }
+
+    /// Dissolves `name`, moving its assets to `reassign_to` or leaving them ungrouped.
+    ///
+    /// The assets themselves stay in the portfolio. Returns how many were affected.
+    pub fn remove_group(&mut self, name: &str, reassign_to: Option<String>) -> usize {
+        match reassign_to {
+            Some(target) => self.rename_group(name, target),
+            None => {
+                let before = self.groups.len();
+                self.groups.retain(|_, group| group != name);
+                before - self.groups.len()
+            }
+        }
+    }
+
+    /// Calculates the asset with `id` under `madhab` instead of the config's Madhab,
+    /// e.g. personal jewelry under Shafi'i rules in an otherwise Hanafi portfolio.
+    ///
+    /// Returns an error if no asset has this id.
+    pub fn set_madhab_override(&mut self, id: Uuid, madhab: crate::madhab::Madhab) -> Result<(), ZakatError> {
+        if self.get(id).is_none() {
+            return Err(asset_not_found(id));
+        }
+        self.madhab_overrides.insert(id, madhab);
+        Ok(())
+    }
+
+    /// Removes the override for `id`, returning the Madhab it used.
+    pub fn clear_madhab_override(&mut self, id: Uuid) -> Option<crate::madhab::Madhab> {
+        self.madhab_overrides.remove(&id)
+    }
+
+    /// Returns the Madhab override for the asset with `id`, if any.
+    pub fn madhab_override(&self, id: Uuid) -> Option<crate::madhab::Madhab> {
+        self.madhab_overrides.get(&id).copied()
+    }
+
     /// Returns a slice of all items in the portfolio.
     pub fn get_items(&self) -> &[PortfolioItem] {
         &self.items
     }
 
+    /// Returns a fingerprint of the portfolio for cheap change detection.
+    ///
+    /// Assets, groups and Madhab overrides are canonicalized through
+    /// `serde_json::Value` (sorted keys) with assets in portfolio order, and hashed
+    /// with SHA-256, so the value is stable across runs and processes. Any edit to
+    /// an asset, the asset order, a group or an override changes it.
+    pub fn checksum(&self) -> u64 {
+        crate::utils::stable_checksum(self)
+    }
+
+    /// Reorders assets for display. The sort is stable and asset ids are untouched.
+    ///
+    /// `config` supplies the prices used by [`SortKey::ValueDesc`].
+    pub fn sort_by(&mut self, key: SortKey, config: &crate::config::ZakatConfig) {
+        match key {
+            SortKey::Type => self.items.sort_by_key(PortfolioItem::type_rank),
+            // A value too large to compute sorts first, as the largest.
+            SortKey::ValueDesc => self.items.sort_by_cached_key(|item| {
+                std::cmp::Reverse(item.estimated_value(config).unwrap_or(Decimal::MAX))
+            }),
+            SortKey::Label => self.items.sort_by_cached_key(|item| {
+                let label = CalculateZakat::get_label(item).map(|l| l.to_lowercase());
+                (label.is_none(), label)
+            }),
+        }
+    }
+
+    /// Returns true if any asset in the portfolio depends on market prices.
+    ///
+    /// Cash still needs prices, since its Nisab is valued in gold or silver. Only
+    /// portfolios of price-free assets (crops, Rikaz, custom assets with their own
+    /// Nisab) return `false`, so live price fetching can be skipped.
+    pub fn needs_prices(&self) -> bool {
+        self.items.iter().any(CalculateZakat::requires_prices)
+    }
+
+    /// Validates every asset without calculating, returning the errors keyed by asset ID.
+    ///
+    /// An empty vector means every item passed `validate_input()`.
+    pub fn validate_all(&self) -> Vec<(Uuid, ZakatError)> {
+        self.items
+            .iter()
+            .filter_map(|item| item.validate_input().err().map(|e| (CalculateZakat::get_id(item), e)))
+            .collect()
+    }
+
+    /// Returns a copy of this portfolio with every amount zeroed.
+    ///
+    /// Useful for reusing last year's asset structure: labels, types and ids
+    /// are kept; see [`PortfolioItem::to_template`] for what is cleared.
+    pub fn to_template(&self) -> ZakatPortfolio {
+        ZakatPortfolio {
+            items: self.items.iter().map(PortfolioItem::to_template).collect(),
+            groups: self.groups.clone(),
+            madhab_overrides: self.madhab_overrides.clone(),
+        }
+    }
+
     /// Merges another portfolio into this one.
     ///
     /// Consumes the `other` portfolio and moves all its items into `self`.
     pub fn merge(mut self, other: ZakatPortfolio) -> Self {
         self.items.extend(other.items);
+        self.groups.extend(other.groups);
+        self.madhab_overrides.extend(other.madhab_overrides);
         self
     }
 
@@ -404,20 +884,23 @@ impl ZakatPortfolio {
     #[instrument(skip(self, config), fields(items_count = self.items.len()))]
     pub fn calculate_total(&self, config: &crate::config::ZakatConfig) -> PortfolioResult {
         info!("Starting portfolio calculation");
+        config.warn_if_unpriced();
         // Fail Fast: Validate config before processing any items
         if let Err(e) = config.validate() {
             return PortfolioResult {
                 status: PortfolioStatus::Failed,
                 successes: Vec::new(),
-                failures: vec![PortfolioItemResult::Failure {
-                    asset_id: Uuid::nil(), // No specific asset
-                    source: "Configuration".to_string(),
-                    error: e,
-                }],
+                failures: vec![PortfolioItemResult::failure(Uuid::nil(), "Configuration", e)],
                 total_assets: Decimal::ZERO,
                 total_zakat_due: Decimal::ZERO,
                 items_attempted: self.items.len(),
                 items_failed: self.items.len(),
+                config_used: None,
+                inputs: Vec::new(),
+                input_groups: BTreeMap::new(),
+                madhab_overrides: BTreeMap::new(),
+                combined_monetary: None,
+                item_results: Default::default(),
             };
         }
 
@@ -425,25 +908,17 @@ impl ZakatPortfolio {
 
         // 1. Initial calculation for all assets
         for (index, item) in self.items.iter().enumerate() {
-            match item.calculate_zakat(config) {
+            match self.calculate_item(item, config) {
                 Ok(detail) => results.push(PortfolioItemResult::Success {
                      asset_id: CalculateZakat::get_id(item),
                      details: detail 
                 }),
                 Err(e) => {
                     let mut err = e;
-                    let source = if let Some(lbl) = CalculateZakat::get_label(item) {
-                        lbl
-                    } else {
-                        format!("Item {}", index + 1)
-                    };
+                    let source = CalculateZakat::label_or_indexed(item, index);
                     warn!(error = ?err, source = %source, "Asset calculation failed");
                     err = err.with_source(source.clone());
-                    results.push(PortfolioItemResult::Failure {
-                        asset_id: CalculateZakat::get_id(item),
-                        source,
-                        error: err,
-                    });
+                    results.push(PortfolioItemResult::failure(CalculateZakat::get_id(item), source, err));
                 },
             }
         }
@@ -451,6 +926,60 @@ impl ZakatPortfolio {
         aggregate_and_summarize(results, config)
     }
 
+    /// Like [`Self::calculate_total`], but embeds the config and inputs so the
+    /// result can be saved and [re-run](PortfolioResult::recompute) later.
+    ///
+    /// The embedded copies roughly double the result's size, so only ask for
+    /// them when the result is kept.
+    pub fn calculate_total_replayable(&self, config: &crate::config::ZakatConfig) -> PortfolioResult {
+        self.calculate_total(config).with_provenance(config, self)
+    }
+
+    /// Calculates one asset, applying its Madhab override (if any) to a copy of `config`.
+    ///
+    /// Overridden results open with an `info-madhab-override` step naming the Madhab used.
+    fn calculate_item(&self, item: &PortfolioItem, config: &crate::config::ZakatConfig) -> Result<ZakatDetails, ZakatError> {
+        let Some(madhab) = self.madhab_override(CalculateZakat::get_id(item)) else {
+            return item.calculate_zakat(config);
+        };
+        item.calculate_zakat(&config.clone().with_madhab(madhab))
+            .map(|details| note_madhab_override(details, madhab))
+    }
+
+    /// Net value of the monetary assets pooled for Dam' al-Amwal: metals, cash,
+    /// business and investments, plus income when `include_income_in_combination`
+    /// is set. Uses the same rule as aggregation, so it is zero when
+    /// `combine_monetary_assets` is off. Assets that fail to calculate are skipped.
+    ///
+    /// For a quick "above Nisab overall?" check; use `calculate_total` for dues.
+    pub fn monetary_total(&self, config: &crate::config::ZakatConfig) -> Decimal {
+        self.items.iter()
+            .filter_map(|item| self.calculate_item(item, config).ok())
+            .filter(|details| config.joins_monetary_pool(&details.wealth_type) && !details.is_in_kind())
+            .map(|details| details.net_assets)
+            .sum()
+    }
+
+    /// True when [`Self::monetary_total`] reaches the monetary Nisab, even if no
+    /// single asset does.
+    pub fn above_nisab(&self, config: &crate::config::ZakatConfig) -> bool {
+        let total = self.monetary_total(config);
+        total > Decimal::ZERO && total >= config.get_monetary_nisab_threshold()
+    }
+
+    /// Converts the config's metal prices into its currency, then calculates.
+    ///
+    /// Lets a USD price feed drive, say, an IDR calculation. Fails with a
+    /// `ConfigurationError` if `converter` has no rate for the currency pair.
+    pub fn calculate_total_converted(
+        &self,
+        config: &crate::config::ZakatConfig,
+        converter: &dyn crate::traits::CurrencyConverter,
+    ) -> Result<PortfolioResult, ZakatError> {
+        let config = config.convert_prices(converter)?;
+        Ok(self.calculate_total(&config))
+    }
+
     /// Retries failed items from a previous calculation using a new (presumably fixed) configuration.
     pub fn retry_failures(&self, previous_result: &PortfolioResult, config: &crate::config::ZakatConfig) -> PortfolioResult {
         // If config is still invalid, fail immediately
@@ -458,15 +987,17 @@ impl ZakatPortfolio {
              return PortfolioResult {
                 status: PortfolioStatus::Failed,
                 successes: Vec::new(),
-                failures: vec![PortfolioItemResult::Failure {
-                    asset_id: Uuid::nil(),
-                    source: "Configuration".to_string(),
-                    error: e,
-                }],
+                failures: vec![PortfolioItemResult::failure(Uuid::nil(), "Configuration", e)],
                 total_assets: Decimal::ZERO,
                 total_zakat_due: Decimal::ZERO,
                 items_attempted: self.items.len(),
                 items_failed: self.items.len(),
+                config_used: None,
+                inputs: Vec::new(),
+                input_groups: BTreeMap::new(),
+                madhab_overrides: BTreeMap::new(),
+                combined_monetary: None,
+                item_results: Default::default(),
             };
         }
 
@@ -485,7 +1016,7 @@ impl ZakatPortfolio {
             if let PortfolioItemResult::Failure { asset_id, source, .. } = failure {
                  // Try to find the calculator with this ID
                  if let Some(calc) = self.get(*asset_id) {
-                     match calc.calculate_zakat(config) {
+                     match self.calculate_item(calc, config) {
                          Ok(detail) => new_results.push(PortfolioItemResult::Success { 
                              asset_id: *asset_id, 
                              details: detail 
@@ -493,11 +1024,7 @@ impl ZakatPortfolio {
                          Err(new_err) => {
                              let mut e = new_err;
                              e = e.with_source(source.clone());
-                             new_results.push(PortfolioItemResult::Failure {
-                                 asset_id: *asset_id,
-                                 source: source.clone(),
-                                 error: e,
-                             });
+                             new_results.push(PortfolioItemResult::failure(*asset_id, source.clone(), e));
                          }
                      }
                  } else {
@@ -555,7 +1082,7 @@ impl ZakatPortfolio {
         
         for (idx, item) in self.items.iter().enumerate() {
             let item_id = crate::traits::CalculateZakat::get_id(item);
-            let label = crate::traits::CalculateZakat::get_label(item).unwrap_or_else(|| format!("Asset #{}", idx + 1));
+            let label = crate::traits::CalculateZakat::label_or_indexed(item, idx);
             
             // Find in success
             if let Some(details) = result.successes.iter().find(|s| s.asset_id == Some(item_id)) {
@@ -633,20 +1160,23 @@ impl AsyncZakatPortfolio {
     #[instrument(skip(self, config), fields(items_count = self.items.len()))]
     pub async fn calculate_total_async(&self, config: &crate::config::ZakatConfig) -> PortfolioResult {
         info!("Starting async portfolio calculation");
+        config.warn_if_unpriced();
         // Fail Fast: Validate config before processing any items
         if let Err(e) = config.validate() {
             return PortfolioResult {
                 status: PortfolioStatus::Failed,
                 successes: Vec::new(),
-                failures: vec![PortfolioItemResult::Failure {
-                    asset_id: Uuid::nil(),
-                    source: "Configuration".to_string(),
-                    error: e,
-                }],
+                failures: vec![PortfolioItemResult::failure(Uuid::nil(), "Configuration", e)],
                 total_assets: Decimal::ZERO,
                 total_zakat_due: Decimal::ZERO,
                 items_attempted: self.items.len(),
                 items_failed: self.items.len(),
+                config_used: None,
+                inputs: Vec::new(),
+                input_groups: BTreeMap::new(),
+                madhab_overrides: BTreeMap::new(),
+                combined_monetary: None,
+                item_results: Default::default(),
             };
         }
 
@@ -673,17 +1203,9 @@ impl AsyncZakatPortfolio {
                 })),
                 Err(e) => {
                     let mut err = e;
-                    let source = if let Some(lbl) = CalculateZakat::get_label(&item) {
-                        lbl
-                    } else {
-                        format!("Item {}", index + 1)
-                    };
+                    let source = CalculateZakat::label_or_indexed(&item, index);
                     err = err.with_source(source.clone());
-                    temp_results.push((index, PortfolioItemResult::Failure {
-                        asset_id: CalculateZakat::get_id(&item),
-                        source,
-                        error: err,
-                    }));
+                    temp_results.push((index, PortfolioItemResult::failure(CalculateZakat::get_id(&item), source, err)));
                 },
             }
         }
@@ -703,17 +1225,31 @@ impl Default for AsyncZakatPortfolio {
     }
 }
 
+/// Opens the trace with an `info-madhab-override` step naming the Madhab used.
+fn note_madhab_override(mut details: ZakatDetails, madhab: crate::madhab::Madhab) -> ZakatDetails {
+    details.calculation_breakdown.0.insert(0, crate::types::CalculationStep::info(
+        "info-madhab-override",
+        format!("Calculated under the {:?} Madhab (asset override)", madhab),
+    ).with_args(HashMap::from([("madhab".to_string(), format!("{:?}", madhab))])));
+    details
+}
+
 /// Shared logic to aggregate results and apply Dam' al-Amwal (Wealth Aggregation).
 #[allow(clippy::collapsible_if)]
 fn aggregate_and_summarize(mut results: Vec<PortfolioItemResult>, config: &crate::config::ZakatConfig) -> PortfolioResult {
     // 2. Aggregation Logic (Dam' al-Amwal)
-    // Filter monetary assets (Gold, Silver, Cash, Business, Investments) from SUCCESSFUL results
+    // Filter monetary assets (Gold, Silver, Cash, Business, Investments) from SUCCESSFUL results.
+    // Income only joins when `include_income_in_combination` is set; in-kind
+    // results (livestock, crops) keep their own count/weight Nisab and never join.
+    let joins_pool = |details: &ZakatDetails| {
+        config.joins_monetary_pool(&details.wealth_type) && !details.is_in_kind()
+    };
     let mut monetary_net_assets = Decimal::ZERO;
     let mut monetary_indices = Vec::new();
 
     for (i, result) in results.iter().enumerate() {
         if let PortfolioItemResult::Success { details, .. } = result {
-             if details.wealth_type.is_monetary() {
+             if joins_pool(details) {
                 monetary_net_assets += details.net_assets;
                 monetary_indices.push(i);
              }
@@ -722,9 +1258,10 @@ fn aggregate_and_summarize(mut results: Vec<PortfolioItemResult>, config: &crate
     
     // Check against the global monetary Nisab
     let global_nisab = config.get_monetary_nisab_threshold();
-    
-    if monetary_net_assets >= global_nisab && monetary_net_assets > Decimal::ZERO {
-        let standard_rate = config.strategy.get_rules().trade_goods_rate;
+    let standard_rate = config.strategy.get_rules().trade_goods_rate;
+    let is_combined = monetary_net_assets >= global_nisab && monetary_net_assets > Decimal::ZERO;
+
+    if is_combined {
 
         for i in monetary_indices {
             // We need to mutate the result.
@@ -732,17 +1269,24 @@ fn aggregate_and_summarize(mut results: Vec<PortfolioItemResult>, config: &crate
                 if !details.is_payable {
                     details.is_payable = true;
                     details.status_reason = Some("Payable via Aggregation (Dam' al-Amwal)".to_string());
-                    
-                    // Recalculate zakat due
-                    if details.net_assets > Decimal::ZERO {
-                        details.zakat_due = details.net_assets * standard_rate;
-                    }
+                    details.status_reason_key = Some("reason-aggregation-payable".to_string());
                     
                     // Add trace step explaining aggregation
                     details.calculation_breakdown.push(crate::types::CalculationStep::info(
                         "info-aggregation-payable",
                         "Aggregated Monetary Wealth > Nisab -> Payable (Dam' al-Amwal)"
                     ));
+
+                    // Recalculate zakat due, rounded like any other due
+                    if details.net_assets > Decimal::ZERO {
+                        let adjustment = crate::maal::calculator::DueAdjustment::new(
+                            details.net_assets * standard_rate,
+                            config.rounding_for(&details.wealth_type),
+                            config.minimum_payment,
+                        );
+                        details.calculation_breakdown.0.extend(adjustment.steps());
+                        adjustment.apply_to(details);
+                    }
                     details.calculation_breakdown.push(crate::types::CalculationStep::result(
                         "step-recalculated-zakat",
                         "Recalculated Zakat Due", details.zakat_due
@@ -752,6 +1296,45 @@ fn aggregate_and_summarize(mut results: Vec<PortfolioItemResult>, config: &crate
         }
     }
 
+    let combined_monetary = is_combined.then(|| {
+        let mut monetary_total_assets = Decimal::ZERO;
+        let mut monetary_zakat_due = Decimal::ZERO;
+        let mut pooled_steps = Vec::new();
+        for (i, result) in results.iter().enumerate() {
+            if let PortfolioItemResult::Success { details, .. } = result {
+                if joins_pool(details) {
+                    monetary_total_assets += details.total_assets;
+                    monetary_zakat_due += details.zakat_due;
+                    let label = details.label.clone()
+                        .filter(|l| !l.is_empty())
+                        .unwrap_or_else(|| crate::traits::indexed_label(i));
+                    pooled_steps.push(
+                        crate::types::CalculationStep::add("step-pooled-asset", format!("Pooled: {}", label), details.net_assets)
+                            .with_args(std::collections::HashMap::from([("label".to_string(), label)])),
+                    );
+                }
+            }
+        }
+        let mut combined = ZakatDetails::new(
+            monetary_total_assets,
+            monetary_total_assets - monetary_net_assets,
+            global_nisab,
+            standard_rate,
+            crate::types::WealthType::Other("Monetary".to_string()),
+        );
+        // Portfolio-level trace: each pooled net amount, then the single Nisab comparison
+        pooled_steps.push(crate::types::CalculationStep::info(
+            "info-pooled-nisab",
+            "Monetary wealth pooled against one Nisab (Dam' al-Amwal)",
+        ));
+        pooled_steps.append(&mut combined.calculation_breakdown.0);
+        combined.calculation_breakdown.0 = pooled_steps;
+        combined.label = Some("Combined Monetary Wealth".to_string());
+        combined.zakat_due = monetary_zakat_due;
+        combined.payload = crate::types::PaymentPayload::Monetary(monetary_zakat_due);
+        combined
+    });
+
     // 3. Final Summation (only successes)
     let mut total_assets = Decimal::ZERO;
     let mut total_zakat_due = Decimal::ZERO;
@@ -778,7 +1361,10 @@ fn aggregate_and_summarize(mut results: Vec<PortfolioItemResult>, config: &crate
 
     for result in results {
         match result {
-            PortfolioItemResult::Success { details, .. } => successes.push(details),
+            PortfolioItemResult::Success { asset_id, mut details } => {
+                details.asset_id = Some(asset_id);
+                successes.push(details);
+            }
             r @ PortfolioItemResult::Failure { .. } => failures.push(r),
         }
     }
@@ -791,6 +1377,96 @@ fn aggregate_and_summarize(mut results: Vec<PortfolioItemResult>, config: &crate
         total_zakat_due,
         items_attempted,
         items_failed,
+        config_used: None,
+        inputs: Vec::new(),
+        input_groups: BTreeMap::new(),
+        madhab_overrides: BTreeMap::new(),
+        combined_monetary,
+        item_results: Default::default(),
+    }
+}
+
+// =============================================================================
+// Dynamic Portfolio
+// =============================================================================
+
+/// A portfolio of arbitrary boxed assets, for user-defined types that are not
+/// variants of [`PortfolioItem`].
+///
+/// Aggregation (including Dam' al-Amwal) matches [`ZakatPortfolio::calculate_total`],
+/// but the result carries no replayable `inputs`, so `recompute()` is a no-op.
+#[derive(Debug, Clone, Default)]
+pub struct DynPortfolio {
+    items: Vec<Box<dyn DynCalculateZakat>>,
+}
+
+impl DynPortfolio {
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Adds any cloneable asset implementing [`CalculateZakat`].
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<T>(mut self, item: T) -> Self
+    where
+        T: CalculateZakat + Clone + Send + Sync + 'static,
+    {
+        self.items.push(item.clone_box());
+        self
+    }
+
+    /// Adds an already boxed asset.
+    pub fn push(&mut self, item: Box<dyn DynCalculateZakat>) {
+        self.items.push(item);
+    }
+
+    pub fn items(&self) -> &[Box<dyn DynCalculateZakat>] {
+        &self.items
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Calculates Zakat for all boxed assets.
+    pub fn calculate_total(&self, config: &crate::config::ZakatConfig) -> PortfolioResult {
+        config.warn_if_unpriced();
+        if let Err(e) = config.validate() {
+            return PortfolioResult {
+                status: PortfolioStatus::Failed,
+                successes: Vec::new(),
+                failures: vec![PortfolioItemResult::failure(Uuid::nil(), "Configuration", e)],
+                total_assets: Decimal::ZERO,
+                total_zakat_due: Decimal::ZERO,
+                items_attempted: self.items.len(),
+                items_failed: self.items.len(),
+                config_used: Some(config.clone()),
+                inputs: Vec::new(),
+                input_groups: BTreeMap::new(),
+                madhab_overrides: BTreeMap::new(),
+                combined_monetary: None,
+                item_results: Default::default(),
+            };
+        }
+
+        let results = self.items.iter().enumerate().map(|(index, item)| {
+            match item.calculate_dyn(config) {
+                Ok(details) => PortfolioItemResult::Success { asset_id: item.id_dyn(), details },
+                Err(e) => {
+                    let source = item.label_dyn().unwrap_or_else(|| crate::traits::indexed_label(index));
+                    let err = e.with_source(source.clone());
+                    PortfolioItemResult::failure(item.id_dyn(), source, err)
+                }
+            }
+        }).collect();
+
+        let mut result = aggregate_and_summarize(results, config);
+        result.config_used = Some(config.clone());
+        result
     }
 }
 
@@ -846,4 +1522,719 @@ mod tests {
         assert_eq!(portfolio.items.len(), original_count, "Original portfolio should not be modified");
         assert_eq!(sim_res.items_attempted, 2, "Simulated result should have 2 items");
     }
+
+    #[test]
+    fn test_result_recompute_from_embedded_config() {
+        let config = ZakatConfig::test_default().with_gold_price(dec!(100));
+        let portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::new().cash(10000).label("Shop").hawl(true))
+            .add(BusinessZakat::new().cash(500).label("Wallet").hawl(true));
+
+        let plain = portfolio.calculate_total(&config);
+        assert!(plain.config_used.is_none() && plain.inputs.is_empty(), "The replay payload is opt-in");
+
+        let result = portfolio.calculate_total_replayable(&config);
+        assert_eq!(result.total_zakat_due, plain.total_zakat_due);
+        let json = serde_json::to_string(&result).unwrap();
+        let restored: PortfolioResult = serde_json::from_str(&json).unwrap();
+
+        assert!(restored.config_used.is_some());
+        assert_eq!(restored.inputs.len(), 2);
+
+        let recomputed = restored.recompute();
+        assert_eq!(
+            serde_json::to_value(&recomputed).unwrap(),
+            serde_json::to_value(&result).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_pooled_recompute_applies_rounding_and_minimum_payment() {
+        use crate::config::{RoundingMode, RoundingRule};
+
+        // Gold Nisab is 8500; each account is below it, together they are above.
+        let config = ZakatConfig::new()
+            .with_gold_price(dec!(100))
+            .with_silver_price(dec!(1))
+            .with_rounding(RoundingRule::new(2, RoundingMode::HalfUp))
+            .with_minimum_payment(dec!(1));
+        let portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::new().cash(dec!(8490.25)).label("Savings").hawl(true))
+            .add(BusinessZakat::new().cash(dec!(20)).label("Wallet").hawl(true));
+
+        let result = portfolio.calculate_total(&config);
+
+        // 8490.25 x 2.5% = 212.25625 -> 212.26; 20 x 2.5% = 0.5 -> minimum 1.
+        let dues: Vec<Decimal> = result.successes.iter().map(|d| d.zakat_due).collect();
+        assert_eq!(dues, vec![dec!(212.26), dec!(1)]);
+        for details in &result.successes {
+            assert!(details.is_payable);
+            assert_eq!(details.payload, crate::types::PaymentPayload::Monetary(details.zakat_due));
+        }
+        assert!(result.successes[0].calculation_breakdown.iter().any(|s| s.key == "info-rounding"));
+        assert!(result.successes[1].calculation_breakdown.iter().any(|s| s.key == "info-minimum-payment"));
+        assert_eq!(result.total_zakat_due, dec!(213.26));
+    }
+
+    #[test]
+    fn test_per_asset_and_combined_monetary_views() {
+        let config = ZakatConfig::test_default();
+        let portfolio = ZakatPortfolio::new()
+            .add(crate::maal::precious_metals::PreciousMetals::gold(50).label("Ring"))
+            .add(BusinessZakat::new().cash(5000).label("Wallet").hawl(true));
+
+        let result = portfolio.calculate_total(&config);
+
+        let per_asset = result.per_asset();
+        assert_eq!(per_asset.len(), 2);
+        assert_eq!(per_asset[0].asset_id(), CalculateZakat::get_id(&portfolio.get_items()[0]));
+
+        let combined = result.combined_monetary.as_ref().expect("monetary assets should be combined");
+        let monetary_due: Decimal = result.successes.iter()
+            .filter(|d| d.wealth_type.is_monetary())
+            .map(|d| d.zakat_due)
+            .sum();
+        assert!(combined.is_payable);
+        assert_eq!(combined.net_assets, dec!(9250));
+        assert_eq!(combined.zakat_due, monetary_due);
+        assert_eq!(combined.zakat_due, dec!(231.25));
+        let pooled: Vec<_> = combined.calculation_breakdown.0.iter()
+            .filter(|step| step.key == "step-pooled-asset")
+            .map(|step| step.amount)
+            .collect();
+        assert_eq!(pooled, vec![Some(dec!(4250)), Some(dec!(5000))]);
+        assert!(combined.calculation_breakdown.0.iter().any(|step| step.key == "step-nisab-check"));
+
+        let below = ZakatPortfolio::new().add_cash(100, "Pocket").calculate_total(&config);
+        assert!(below.combined_monetary.is_none());
+    }
+
+    #[test]
+    fn test_monetary_total_crosses_nisab_when_combined() {
+        // Nisab = 85g * 85 = 7225
+        let config = ZakatConfig::test_default();
+        let portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::new().cash(5000).label("Wallet").hawl(true))
+            .add(crate::maal::precious_metals::PreciousMetals::gold(30).label("Coins").hawl(true))
+            .add(crate::maal::income::IncomeZakatCalculator::new().income(3000).label("Salary"));
+
+        for item in portfolio.get_items() {
+            let details = item.calculate_zakat(&config).unwrap();
+            assert!(details.net_assets < dec!(7225), "{:?} alone is below Nisab", details.label);
+        }
+
+        // Income stays out of the pool by default: 5000 + 30 * 85
+        assert_eq!(portfolio.monetary_total(&config), dec!(7550));
+        assert!(portfolio.above_nisab(&config));
+        assert!(!ZakatPortfolio::new().add_cash(100, "Pocket").above_nisab(&config));
+
+        // With pooling off, aggregation pools nothing and neither does this.
+        let separate = config.clone().with_monetary_aggregation(false);
+        assert_eq!(portfolio.monetary_total(&separate), Decimal::ZERO);
+        assert!(!portfolio.above_nisab(&separate));
+        assert!(portfolio.calculate_total(&separate).combined_monetary.is_none());
+    }
+
+    #[test]
+    fn test_add_many_preserves_order_and_ids() {
+        let assets: Vec<PortfolioItem> = (0..100)
+            .map(|i| BusinessZakat::cash_only(i).label(format!("Till {}", i)).into())
+            .collect();
+
+        let mut portfolio = ZakatPortfolio::with_capacity(assets.len());
+        portfolio.add_many(assets);
+
+        let items = portfolio.get_items();
+        assert_eq!(items.len(), 100);
+        assert_eq!(CalculateZakat::get_label(&items[0]).as_deref(), Some("Till 0"));
+        assert_eq!(CalculateZakat::get_label(&items[99]).as_deref(), Some("Till 99"));
+
+        let ids: std::collections::HashSet<Uuid> = items.iter().map(CalculateZakat::get_id).collect();
+        assert_eq!(ids.len(), 100);
+    }
+
+    #[test]
+    fn test_missing_price_failure_suggests_setting_prices() {
+        let config = ZakatConfig::new().with_silver_price(dec!(1));
+        let portfolio = ZakatPortfolio::new().add_gold(100, 24);
+
+        let result = portfolio.calculate_total(&config);
+
+        match &result.failures[0] {
+            PortfolioItemResult::Failure { suggestion, error, .. } => {
+                assert_eq!(suggestion.as_deref(), Some("hint-set-prices"));
+                assert_eq!(error.hint_key(), "hint-set-prices");
+            }
+            other => panic!("Expected failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_needs_prices() {
+        let cash_only = ZakatPortfolio::new()
+            .add_cash(5000, "Savings")
+            .add(BusinessZakat::new().cash(1000));
+        assert!(cash_only.needs_prices(), "The cash Nisab is priced in gold/silver");
+        assert!(cash_only.get_items().iter().all(|item| item.preflight(&ZakatConfig::new()).is_err()),
+            "Agrees with preflight, which rejects unpriced cash");
+
+        let treasure = crate::maal::mining::MiningAssets::new().value(1000).kind(crate::maal::mining::MiningType::Rikaz);
+        assert!(treasure.calculate_zakat(&ZakatConfig::new()).is_ok(), "Rikaz has no Nisab to price");
+        let rikaz = ZakatPortfolio::new().add(treasure);
+        assert!(!rikaz.needs_prices());
+
+        let with_gold = rikaz.add_gold(100, 24);
+        assert!(with_gold.needs_prices());
+    }
+
+    #[test]
+    fn test_sort_by_label() {
+        let config = ZakatConfig::test_default();
+        let mut portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::new().cash(100).label("zakah fund"))
+            .add(BusinessZakat::new().cash(300))
+            .add(crate::maal::precious_metals::PreciousMetals::gold(10).label("Bangles"))
+            .add(BusinessZakat::new().cash(200).label("Shop"));
+        let ids: Vec<Uuid> = portfolio.items.iter().map(CalculateZakat::get_id).collect();
+
+        portfolio.sort_by(SortKey::Label, &config);
+
+        let labels: Vec<Option<String>> = portfolio.items.iter().map(CalculateZakat::get_label).collect();
+        assert_eq!(labels, vec![
+            Some("Bangles".to_string()),
+            Some("Shop".to_string()),
+            Some("zakah fund".to_string()),
+            None,
+        ]);
+        assert_eq!(CalculateZakat::get_id(&portfolio.items[0]), ids[2]);
+        assert_eq!(CalculateZakat::get_id(&portfolio.items[3]), ids[1]);
+
+        portfolio.sort_by(SortKey::ValueDesc, &config);
+        assert_eq!(CalculateZakat::get_id(&portfolio.items[0]), ids[2]); // 10g * 85 = 850
+        assert_eq!(CalculateZakat::get_id(&portfolio.items[1]), ids[1]);
+    }
+
+    #[test]
+    fn test_estimated_value_overflow_is_an_error() {
+        let config = ZakatConfig::test_default().with_gold_price(Decimal::MAX);
+        let hoard = PortfolioItem::from(crate::maal::precious_metals::PreciousMetals::gold(1_000));
+        assert!(matches!(hoard.estimated_value(&config), Err(ZakatError::Overflow { .. })));
+
+        let mut portfolio = ZakatPortfolio::new().add(BusinessZakat::new().cash(100)).add(hoard.clone());
+        portfolio.sort_by(SortKey::ValueDesc, &config);
+        assert_eq!(CalculateZakat::get_id(&portfolio.items[0]), CalculateZakat::get_id(&hoard));
+    }
+
+    #[derive(Clone)]
+    struct RentalProperty {
+        id: Uuid,
+        net_rent: Decimal,
+    }
+
+    impl CalculateZakat for RentalProperty {
+        fn calculate_zakat<C: crate::traits::ZakatConfigArgument>(&self, _config: C) -> Result<ZakatDetails, ZakatError> {
+            Ok(ZakatDetails::new(self.net_rent, Decimal::ZERO, Decimal::ZERO, dec!(0.025), crate::types::WealthType::Other("Rental".to_string()))
+                .with_label("Flat".to_string()))
+        }
+        fn get_label(&self) -> Option<String> { Some("Flat".to_string()) }
+        fn get_id(&self) -> Uuid { self.id }
+    }
+
+    #[test]
+    fn test_dyn_portfolio_calculates_heterogeneous_boxed_assets() {
+        let config = ZakatConfig::test_default();
+        let rental = RentalProperty { id: Uuid::new_v4(), net_rent: dec!(4000) };
+        let portfolio = DynPortfolio::new()
+            .add(BusinessZakat::cash_only(10000).label("Shop"))
+            .add(rental.clone());
+
+        let boxed: Box<dyn DynCalculateZakat> = rental.clone_box();
+        assert_eq!(boxed.clone().id_dyn(), rental.id);
+
+        let result = portfolio.clone().calculate_total(&config);
+        assert_eq!(result.status, PortfolioStatus::Complete);
+        assert_eq!(result.successes.len(), 2);
+        assert_eq!(result.total_zakat_due, dec!(250) + dec!(100));
+    }
+
+    #[test]
+    fn test_to_template_keeps_structure_and_zeros_amounts() {
+        let portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(5000).inventory(2000).label("Shop"))
+            .add(crate::maal::precious_metals::PreciousMetals::gold(100).purity(18).label("Ring"))
+            .add(crate::maal::agriculture::AgricultureAssets::new().harvest_weight(900).price(2).label("Wheat"));
+
+        let template = portfolio.to_template();
+        let items = template.get_items();
+
+        assert_eq!(items.len(), 3);
+        for (original, blank) in portfolio.get_items().iter().zip(items) {
+            assert_eq!(CalculateZakat::get_label(original), CalculateZakat::get_label(blank));
+            assert_eq!(original.type_rank(), blank.type_rank());
+        }
+        match (&items[0], &items[1], &items[2]) {
+            (PortfolioItem::Business(b), PortfolioItem::PreciousMetals(pm), PortfolioItem::Agriculture(a)) => {
+                assert_eq!(b.cash_on_hand, Decimal::ZERO);
+                assert_eq!(b.inventory_value, Decimal::ZERO);
+                assert_eq!(pm.weight_grams, Decimal::ZERO);
+                assert_eq!(pm.purity, dec!(18), "Non-financial settings are preserved");
+                assert_eq!(a.harvest_weight_kg, Decimal::ZERO);
+                assert_eq!(a.price_per_kg, Decimal::ZERO);
+            }
+            other => panic!("Unexpected variants: {:?}", other),
+        }
+        assert_eq!(template.calculate_total(&ZakatConfig::test_default()).total_zakat_due, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_income_joins_combination_only_when_enabled() {
+        // Nisab = 85g * 85 = 7225. Cash alone (5000) is below it; with income (3000) it is above.
+        let portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(5000).label("Savings").hawl(true))
+            .add(crate::maal::income::IncomeZakatCalculator::new().income(3000).label("Salary").hawl(true));
+
+        let excluded = portfolio.calculate_total(&ZakatConfig::test_default());
+        assert!(excluded.combined_monetary.is_none(), "Income must not be pooled by default");
+        assert_eq!(excluded.total_zakat_due, Decimal::ZERO);
+
+        let included = portfolio.calculate_total(&ZakatConfig::test_default().with_income_in_combination(true));
+        let combined = included.combined_monetary.expect("income should join the pool");
+        assert_eq!(combined.net_assets, dec!(8000));
+        assert_eq!(included.total_zakat_due, dec!(200));
+    }
+
+    #[test]
+    fn test_snapshot_integrity_detects_edited_total() {
+        let config = ZakatConfig::test_default();
+        let portfolio = ZakatPortfolio::new().add_cash(10000, "Savings");
+        let result = portfolio.calculate_total(&config);
+        let snapshot = portfolio.snapshot(&config, &result).with_metadata("year", "1446");
+        let json = snapshot.to_json().unwrap();
+
+        let restored = PortfolioSnapshot::from_json(&json).unwrap();
+        assert!(restored.content_hash.is_some());
+        assert!(restored.verify_integrity(), "An untouched snapshot must verify");
+        let compact = PortfolioSnapshot::from_json(&snapshot.to_json_compact().unwrap()).unwrap();
+        assert_eq!(compact.content_hash, restored.content_hash);
+
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["result"]["total_zakat_due"] = serde_json::json!("1.00");
+        let tampered = PortfolioSnapshot::from_json(&value.to_string()).unwrap();
+        assert!(!tampered.verify_integrity(), "Editing the total must break verification");
+    }
+
+    #[test]
+    fn test_suggested_filename_embeds_date_and_hash_prefix() {
+        let config = ZakatConfig::test_default();
+        let portfolio = ZakatPortfolio::new().add_cash(10000, "Savings");
+        let result = portfolio.calculate_total(&config);
+        let mut snapshot = portfolio.snapshot(&config, &result);
+        snapshot.timestamp = "2025-03-01T10:00:00Z".parse().unwrap();
+
+        let name = snapshot.suggested_filename();
+        let hash = snapshot.compute_content_hash().unwrap();
+        assert_eq!(name, format!("zakat_snapshot_2025-03-01_{}.json", &hash[..8]));
+
+        let restored = PortfolioSnapshot::from_json(&snapshot.to_json().unwrap()).unwrap();
+        assert_eq!(restored.suggested_filename(), name, "The stored hash must not change the name");
+    }
+
+    #[test]
+    fn test_summary_line_includes_total_and_payable_count() {
+        let config = ZakatConfig::test_default();
+        let result = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(10000).label("Savings").hawl(true))
+            .add(BusinessZakat::cash_only(100).label("Pocket").hawl(true))
+            .calculate_total(&config);
+
+        let line = result.to_summary_line(&config);
+        assert!(line.contains("$252.50"), "{}", line);
+        assert!(line.contains("across 2 assets"), "{}", line);
+        assert!(line.contains("(2 payable)"), "{}", line);
+
+        struct ArgsTranslator;
+        impl crate::traits::Translator for ArgsTranslator {
+            fn translate(&self, key: &str, args: Option<&HashMap<String, String>>) -> String {
+                let args = args.expect("summary line passes args");
+                format!("{}|{}|{}|{}", key, args["total"], args["assets"], args["payable"])
+            }
+        }
+        assert_eq!(result.to_summary_line_localized(&config, &ArgsTranslator), "report-summary-line|$252.50|2|2");
+    }
+
+    #[test]
+    fn test_asset_visitor_counts_assets_per_type() {
+        use crate::assets::AssetVisitor;
+        use crate::maal::precious_metals::PreciousMetals;
+
+        #[derive(Default)]
+        struct TypeCounter {
+            business: usize,
+            metals: usize,
+            other: usize,
+        }
+        impl AssetVisitor for TypeCounter {
+            fn visit_business(&mut self, _asset: &BusinessZakat) { self.business += 1; }
+            fn visit_precious_metals(&mut self, _asset: &PreciousMetals) { self.metals += 1; }
+            fn visit_asset(&mut self, _asset: &dyn DynCalculateZakat) { self.other += 1; }
+        }
+
+        let portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(5000).label("Shop"))
+            .add(BusinessZakat::cash_only(100).label("Kiosk"))
+            .add(PreciousMetals::gold(100).label("Ring"))
+            .add(crate::maal::agriculture::AgricultureAssets::new().harvest_weight(900).price(2));
+
+        let mut counter = TypeCounter::default();
+        for item in portfolio.get_items() {
+            item.visit(&mut counter);
+        }
+
+        assert_eq!((counter.business, counter.metals, counter.other), (2, 1, 1));
+    }
+
+    #[test]
+    fn test_livestock_stays_out_of_combined_monetary_pool() {
+        use crate::maal::livestock::{LivestockAssets, LivestockPrices, LivestockType};
+        use crate::maal::precious_metals::PreciousMetals;
+
+        // Nisab = 85g * 85 = 7225. Cash (5000) + gold (30g * 85 = 2550) = 7550 combines above it.
+        // 30 sheep are worth 30,000 but below their own 40-head Nisab.
+        let portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(5000).label("Savings").hawl(true))
+            .add(PreciousMetals::gold(30).label("Coins").hawl(true))
+            .add(LivestockAssets::new()
+                .count(30)
+                .animal_type(LivestockType::Sheep)
+                .prices(LivestockPrices::new().sheep_price(1000))
+                .hawl(true)
+                .label("Flock"));
+        let config = ZakatConfig::test_default();
+        assert!(config.combine_monetary_assets);
+
+        let result = portfolio.calculate_total(&config);
+
+        let combined = result.combined_monetary.as_ref().expect("monetary pool reaches Nisab");
+        assert_eq!(combined.net_assets, dec!(7550));
+        let flock = result.successes.iter().find(|d| d.label.as_deref() == Some("Flock")).unwrap();
+        assert!(!flock.is_payable, "Livestock is judged on its own head-count Nisab");
+        assert_eq!(flock.zakat_due, Decimal::ZERO);
+
+        let separate = portfolio.calculate_total(&config.with_pooling(false));
+        assert!(separate.combined_monetary.is_none());
+        assert_eq!(separate.total_zakat_due, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_assets_requiring_attention_lists_payable_then_failed() {
+        use crate::maal::livestock::{LivestockAssets, LivestockPrices, LivestockType};
+
+        let portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(10_000).label("Savings").hawl(true))
+            .add(BusinessZakat::cash_only(50_000).label("Shop").hawl(true))
+            .add(LivestockAssets::new()
+                .count(10)
+                .animal_type(LivestockType::Sheep)
+                .prices(LivestockPrices::new().sheep_price(100))
+                .hawl(true)
+                .label("Flock"))
+            .add(BusinessZakat::cash_only(dec!(-100)).label("Broken").hawl(true));
+
+        let result = portfolio.calculate_total(&ZakatConfig::test_default());
+        let todo = result.assets_requiring_attention();
+
+        let labels: Vec<String> = todo.iter()
+            .map(|r| match r {
+                PortfolioItemResult::Success { details, .. } => details.label.clone().unwrap_or_default(),
+                PortfolioItemResult::Failure { source, .. } => source.clone(),
+            })
+            .collect();
+        assert_eq!(labels, vec!["Shop", "Savings", "Broken"], "Exempt flock must be left out");
+
+        let ids: Vec<Uuid> = todo.iter().map(|r| r.asset_id()).collect();
+        let input_ids: Vec<Uuid> = portfolio.get_items().iter().map(CalculateZakat::get_id).collect();
+        assert_eq!(ids, vec![input_ids[1], input_ids[0], input_ids[3]], "Results keep their real asset ids");
+    }
+
+    #[test]
+    fn test_portfolio_item_try_from_json_value() {
+        let gold = serde_json::json!({
+            "type": "preciousMetals",
+            "data": { "weightGrams": "100", "metalType": { "type": "gold" }, "purity": "24", "label": "Bar" }
+        });
+        let item = PortfolioItem::try_from(&gold).expect("valid gold object");
+        match &item {
+            PortfolioItem::PreciousMetals(pm) => assert_eq!(pm.weight_grams, dec!(100)),
+            other => panic!("Unexpected variant: {:?}", other),
+        }
+
+        let missing = serde_json::json!({
+            "type": "preciousMetals",
+            "data": { "metalType": { "type": "gold" }, "purity": "24" }
+        });
+        match PortfolioItem::try_from(&missing) {
+            Err(ZakatError::InvalidInput(details)) => {
+                assert_eq!(details.field, "weightGrams");
+                assert_eq!(details.reason_key, "error-field-missing");
+            }
+            other => panic!("Expected InvalidInput naming weightGrams, got {:?}", other),
+        }
+
+        // A business holding only inventory needs no cash field.
+        let stock_only = serde_json::json!({ "type": "business", "data": { "inventoryValue": "5000" } });
+        match PortfolioItem::try_from(&stock_only).expect("inventory-only business") {
+            PortfolioItem::Business(b) => assert_eq!(b.inventory_value, dec!(5000)),
+            other => panic!("Unexpected variant: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_try_replace_returns_previous_item() {
+        let mut portfolio = ZakatPortfolio::new();
+        let original: PortfolioItem = BusinessZakat::cash_only(500).label("Till").into();
+        let id = portfolio.push(original.clone());
+
+        let recounted = BusinessZakat::cash_only(900).label("Till (recounted)");
+        let new_id = recounted.id;
+        let old = portfolio.try_replace(id, recounted).unwrap();
+
+        assert_eq!(serde_json::to_value(&old).unwrap(), serde_json::to_value(&original).unwrap());
+        assert_eq!(
+            portfolio.get(new_id).and_then(CalculateZakat::get_label).as_deref(),
+            Some("Till (recounted)")
+        );
+
+        // Undo by swapping the old item back in.
+        portfolio.try_replace(new_id, old).unwrap();
+        assert!(portfolio.get(id).is_some());
+        assert!(portfolio.try_replace(Uuid::new_v4(), BusinessZakat::cash_only(1)).is_err());
+    }
+
+    #[test]
+    fn test_checksum_tracks_edits() {
+        let mut portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(500).label("Till"))
+            .add(crate::maal::precious_metals::PreciousMetals::gold(100).label("Savings"));
+        let copy = portfolio.clone();
+        assert_eq!(portfolio.checksum(), copy.checksum());
+
+        let id = CalculateZakat::get_id(&portfolio.get_items()[0]);
+        if let Some(PortfolioItem::Business(b)) = portfolio.get_mut(id) {
+            b.cash_on_hand += Decimal::ONE;
+        }
+        assert_ne!(portfolio.checksum(), copy.checksum());
+
+        let before_group = portfolio.checksum();
+        portfolio.set_group(id, "Shop").unwrap();
+        assert_ne!(portfolio.checksum(), before_group, "Groups are part of the fingerprint");
+    }
+
+    #[test]
+    fn test_result_pretty_json_round_trips() {
+        let config = ZakatConfig::test_default();
+        let result = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(10_000).hawl(true).label("Shop"))
+            .calculate_total(&config);
+
+        let pretty = result.to_json().unwrap();
+        assert!(pretty.contains('\n'));
+        let parsed: PortfolioResult = serde_json::from_str(&pretty).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), serde_json::to_value(&result).unwrap());
+
+        let compact = result.to_json_compact().unwrap();
+        assert!(!compact.contains('\n'));
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&compact).unwrap(), serde_json::to_value(&result).unwrap());
+    }
+
+    #[test]
+    fn test_unlabeled_failure_is_named_by_its_position() {
+        let portfolio = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(10_000).label("Shop").hawl(true))
+            .add(BusinessZakat::cash_only(dec!(-100)).hawl(true));
+
+        let result = portfolio.calculate_total(&ZakatConfig::test_default());
+
+        match &result.failures[0] {
+            PortfolioItemResult::Failure { source, .. } => assert_eq!(source, "Asset #2"),
+            other => panic!("Expected a failure, got {:?}", other),
+        }
+        assert_eq!(CalculateZakat::label_or_indexed(&BusinessZakat::cash_only(1), 0), "Asset #1");
+        assert_eq!(CalculateZakat::label_or(&BusinessZakat::cash_only(1).label("Shop"), "Wallet"), "Shop");
+    }
+
+    #[test]
+    fn test_madhab_override_applies_to_one_asset() {
+        use crate::madhab::Madhab;
+        use crate::maal::precious_metals::{JewelryUsage, PreciousMetals};
+
+        let config = ZakatConfig::test_default().with_madhab(Madhab::Hanafi);
+        let mut portfolio = ZakatPortfolio::new();
+        portfolio.push(BusinessZakat::cash_only(10_000).label("Shop").hawl(true));
+        let jewelry = portfolio.push(PreciousMetals::gold(100).usage(JewelryUsage::PersonalUse).label("Jewelry").hawl(true));
+        assert!(portfolio.set_madhab_override(Uuid::new_v4(), Madhab::Shafi).is_err());
+
+        let details_of = |result: &PortfolioResult, label: &str| result.successes().iter()
+            .find(|details| details.label.as_deref() == Some(label))
+            .cloned()
+            .unwrap();
+
+        let hanafi = portfolio.calculate_total(&config);
+        assert_eq!(details_of(&hanafi, "Jewelry").zakat_due, dec!(212.5), "Hanafi zakats personal jewelry");
+
+        portfolio.set_madhab_override(jewelry, Madhab::Shafi).unwrap();
+        assert_eq!(portfolio.madhab_override(jewelry), Some(Madhab::Shafi));
+        let mixed = portfolio.calculate_total_replayable(&config);
+
+        let jewelry_details = details_of(&mixed, "Jewelry");
+        assert_eq!(jewelry_details.zakat_due, Decimal::ZERO, "The Shafi'i override exempts personal jewelry");
+        let first = &jewelry_details.calculation_breakdown.0[0];
+        assert_eq!(first.key, "info-madhab-override");
+        assert_eq!(first.args.as_ref().unwrap()["madhab"], "Shafi");
+
+        let shop_details = details_of(&mixed, "Shop");
+        assert_eq!(shop_details.zakat_due, dec!(250));
+        assert!(shop_details.calculation_breakdown.0.iter().all(|step| step.key != "info-madhab-override"));
+
+        let recomputed = mixed.recompute();
+        assert_eq!(details_of(&recomputed, "Jewelry").zakat_due, Decimal::ZERO, "recompute keeps the override");
+        assert_eq!(recomputed.total_zakat_due, mixed.total_zakat_due);
+
+        let bangles = PreciousMetals::gold(100).usage(JewelryUsage::PersonalUse).label("Bangles").hawl(true);
+        let bangles_id = CalculateZakat::get_id(&bangles);
+        portfolio.try_replace(jewelry, bangles).unwrap();
+        assert_eq!(portfolio.madhab_override(jewelry), None);
+        assert_eq!(portfolio.madhab_override(bangles_id), Some(Madhab::Shafi), "The override follows the replacement");
+
+        portfolio.remove(bangles_id);
+        assert_eq!(portfolio.madhab_override(bangles_id), None, "A removed asset drops its override");
+    }
+
+    #[test]
+    fn test_replace_moves_group_to_new_item() {
+        let mut portfolio = ZakatPortfolio::new();
+        let old = portfolio.push(BusinessZakat::cash_only(100).label("Wallet"));
+        portfolio.set_group(old, "Family").unwrap();
+
+        let new_wallet = BusinessZakat::cash_only(250).label("Wallet");
+        let new_id = new_wallet.id;
+        portfolio.try_replace(old, new_wallet).unwrap();
+
+        assert_eq!(portfolio.group_of(old), None, "No ghost entry for the old id");
+        assert_eq!(portfolio.group_of(new_id), Some("Family"));
+        assert_eq!(portfolio.group_items("Family").len(), 1);
+        assert_eq!(portfolio.remove_group("Family", None), 1);
+    }
+
+    #[test]
+    fn test_rename_group_updates_every_member() {
+        let mut portfolio = ZakatPortfolio::new();
+        let wallet = portfolio.push(BusinessZakat::cash_only(100).label("Wallet"));
+        let savings = portfolio.push(BusinessZakat::cash_only(5_000).label("Savings"));
+        let shop = portfolio.push(BusinessZakat::cash_only(20_000).label("Shop"));
+        portfolio.set_group(wallet, "Family").unwrap();
+        portfolio.set_group(savings, "Family").unwrap();
+        portfolio.set_group(shop, "Business").unwrap();
+        assert!(portfolio.set_group(Uuid::new_v4(), "Family").is_err());
+
+        assert_eq!(portfolio.rename_group("Family", "Household"), 2);
+
+        assert_eq!(portfolio.group_of(wallet), Some("Household"));
+        assert_eq!(portfolio.group_of(savings), Some("Household"));
+        assert_eq!(portfolio.group_of(shop), Some("Business"));
+        assert_eq!(portfolio.groups(), vec!["Business".to_string(), "Household".to_string()]);
+        assert_eq!(portfolio.group_items("Household").len(), 2);
+
+        assert_eq!(portfolio.remove_group("Business", Some("Household".to_string())), 1);
+        assert_eq!(portfolio.groups(), vec!["Household".to_string()]);
+        assert_eq!(portfolio.remove_group("Household", None), 3);
+        assert!(portfolio.groups().is_empty());
+        assert_eq!(portfolio.get_items().len(), 3);
+    }
+
+    #[test]
+    fn test_remove_by_id_and_remove_at_keep_order() {
+        let labels = |p: &ZakatPortfolio| -> Vec<String> {
+            p.get_items().iter().filter_map(CalculateZakat::get_label).collect()
+        };
+        let mut portfolio = ZakatPortfolio::new();
+        let first = portfolio.push(BusinessZakat::cash_only(1).label("A"));
+        portfolio.push(BusinessZakat::cash_only(2).label("B"));
+        let middle = portfolio.push(BusinessZakat::cash_only(3).label("C"));
+        portfolio.push(BusinessZakat::cash_only(4).label("D"));
+        let last = portfolio.push(BusinessZakat::cash_only(5).label("E"));
+        portfolio.set_group(middle, "Family").unwrap();
+
+        assert_eq!(CalculateZakat::get_id(&portfolio.remove_by_id(first).unwrap()), first);
+        assert_eq!(labels(&portfolio), ["B", "C", "D", "E"]);
+
+        portfolio.remove_by_id(middle).unwrap();
+        assert_eq!(labels(&portfolio), ["B", "D", "E"]);
+        assert!(portfolio.groups().is_empty(), "A removed asset leaves its group");
+
+        portfolio.remove_by_id(last).unwrap();
+        assert_eq!(labels(&portfolio), ["B", "D"]);
+
+        let missing = portfolio.remove_by_id(Uuid::new_v4()).unwrap_err();
+        assert_eq!(missing.error_code(), crate::types::ZakatErrorCode::AssetNotFound);
+        assert!(portfolio.remove_by_id(first).is_err(), "Removing twice fails");
+
+        assert_eq!(CalculateZakat::get_label(&portfolio.remove_at(1).unwrap()).as_deref(), Some("D"));
+        assert!(portfolio.remove_at(1).is_err());
+        assert_eq!(labels(&portfolio), ["B"]);
+    }
+
+    #[test]
+    fn test_unknown_hawl_is_reported_not_exempt() {
+        use crate::hawl::HawlStatus;
+        let (portfolio, unsure) = ZakatPortfolio::new()
+            .add(BusinessZakat::cash_only(20_000).label("Shop").hawl(true))
+            .add_with_id(BusinessZakat::cash_only(20_000).label("Inherited").hawl_status(HawlStatus::Unknown));
+
+        let preflight = portfolio.validate_all();
+        assert_eq!(preflight.len(), 1);
+        assert_eq!(preflight[0].0, unsure);
+        assert!(matches!(&preflight[0].1, ZakatError::InvalidInput(d) if d.reason_key == "error-hawl-unknown"));
+
+        let result = portfolio.calculate_total(&ZakatConfig::test_default());
+        assert_eq!(result.successes.len(), 1, "The unknown asset must not come back as a confident exempt result");
+        assert_eq!(result.failures[0].asset_id(), unsure);
+
+        let resolved = BusinessZakat::cash_only(1).hawl_status(HawlStatus::Unknown).hawl(true);
+        assert_eq!(resolved.resolved_hawl_status(), HawlStatus::Satisfied);
+        let dated = chrono::NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        assert_eq!(BusinessZakat::cash_only(1).acquired_on(dated).resolved_hawl_status(), HawlStatus::DerivedFrom(dated));
+
+        // The later builder call wins in either order.
+        let found_receipt = BusinessZakat::cash_only(1).hawl_status(HawlStatus::Unknown).acquired_on(dated);
+        assert_eq!(found_receipt.resolved_hawl_status(), HawlStatus::DerivedFrom(dated));
+        assert!(found_receipt.validate_input().is_ok());
+        let lost_receipt = BusinessZakat::cash_only(1).acquired_on(dated).hawl_status(HawlStatus::Unknown);
+        assert_eq!(lost_receipt.resolved_hawl_status(), HawlStatus::Unknown);
+    }
+
+    #[test]
+    fn test_all_warnings_collects_every_asset() {
+        let ignored_expenses = "Expenses are ignored when using the Gross calculation method.";
+        let portfolio = ZakatPortfolio::new()
+            .add(crate::maal::income::IncomeZakatCalculator::new()
+                .income(20_000)
+                .expenses(5_000)
+                .method(crate::maal::income::IncomeCalculationMethod::Gross)
+                .label("Salary")
+                .hawl(true))
+            .add(BusinessZakat::cash_only(10_000).label("Shop").hawl(true))
+            .add(crate::maal::income::IncomeZakatCalculator::new()
+                .income(30_000)
+                .expenses(1_000)
+                .method(crate::maal::income::IncomeCalculationMethod::Gross)
+                .hawl(true));
+
+        let result = portfolio.calculate_total(&ZakatConfig::test_default());
+        let warnings = result.all_warnings();
+
+        assert_eq!(warnings, vec![
+            ("Salary".to_string(), ignored_expenses.to_string()),
+            ("Asset #3".to_string(), ignored_expenses.to_string()),
+        ]);
+    }
 }
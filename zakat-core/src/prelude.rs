@@ -9,27 +9,27 @@
 //! ```
 
 // Core exports
-pub use crate::config::ZakatConfig;
-pub use crate::madhab::{Madhab, NisabStandard, ZakatStrategy, ZakatRules};
-pub use crate::portfolio::{ZakatPortfolio, PortfolioResult, PortfolioItemResult, PortfolioSnapshot};
+pub use crate::config::{ZakatConfig, NisabPreset};
+pub use crate::madhab::{Madhab, NisabStandard, SheepTierRuling, ZakatStrategy, ZakatRules};
+pub use crate::portfolio::{ZakatPortfolio, DynPortfolio, PortfolioResult, PortfolioItemResult, SortKey, PortfolioSnapshot};
 #[cfg(feature = "async")]
 pub use crate::portfolio::AsyncZakatPortfolio;
 
-pub use crate::traits::CalculateZakat;
+pub use crate::traits::{CalculateZakat, CurrencyConverter, DynCalculateZakat};
 #[cfg(feature = "async")]
 pub use crate::traits::AsyncCalculateZakat;
-pub use crate::types::{WealthType, ZakatDetails, ZakatError, ZakatRecommendation};
+pub use crate::types::{wealth_type_meta, DebtDeductionPolicy, WealthCategory, WealthType, WealthTypeMeta, ZakatDetails, ZakatError, ZakatRecommendation};
 pub use crate::inputs::IntoZakatDecimal;
 
 // Hawl types (Feature 1: Fuzzy Dates)
-pub use crate::hawl::{HawlTracker, AcquisitionDate, FuzzyDate};
+pub use crate::hawl::{HawlTracker, HawlMode, HawlStatus, AcquisitionDate, FuzzyDate};
 
 // Re-export specific calculators and types
 pub use crate::maal::business::BusinessZakat;
 pub use crate::maal::income::{IncomeZakatCalculator, IncomeCalculationMethod};
 pub use crate::maal::investments::{InvestmentAssets, InvestmentType};
 pub use crate::maal::precious_metals::PreciousMetals;
-pub use crate::maal::agriculture::{AgricultureAssets, IrrigationMethod};
-pub use crate::maal::livestock::{LivestockAssets, LivestockType, LivestockPrices};
+pub use crate::maal::agriculture::{AgricultureAssets, CropCategory, IrrigationMethod};
+pub use crate::maal::livestock::{LivestockAssets, LivestockType, LivestockPrices, MixedHerd};
 pub use crate::maal::mining::{MiningAssets, MiningType};
 pub use crate::fitrah::calculate_fitrah;
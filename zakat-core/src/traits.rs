@@ -25,6 +25,22 @@ impl CalculationObserver for NoOpObserver {
     fn on_error(&self, _error: &ZakatError) {}
 }
 
+/// Supplies exchange rates so metal prices quoted in one currency can feed a
+/// calculation configured for another (see [`ZakatConfig::convert_prices`]).
+pub trait CurrencyConverter {
+    /// Units of `to` per one unit of `from`, or `None` if the pair is unknown.
+    fn rate(&self, from: &str, to: &str) -> Option<rust_decimal::Decimal>;
+}
+
+impl<F> CurrencyConverter for F
+where
+    F: Fn(&str, &str) -> Option<rust_decimal::Decimal>,
+{
+    fn rate(&self, from: &str, to: &str) -> Option<rust_decimal::Decimal> {
+        self(from, to)
+    }
+}
+
 /// Trait for handling internationalization of messages.
 pub trait Translator {
     /// Translates a key with optional arguments.
@@ -41,7 +57,9 @@ impl ZakatConfigArgument for &ZakatConfig {
 // 2. Support passing () for default config
 impl ZakatConfigArgument for () {
     fn resolve_config(&self) -> Cow<'_, ZakatConfig> {
-        Cow::Owned(ZakatConfig::default())
+        let config = ZakatConfig::default();
+        config.warn_if_unpriced();
+        Cow::Owned(config)
     }
 }
 
@@ -50,7 +68,7 @@ impl ZakatConfigArgument for Option<&ZakatConfig> {
     fn resolve_config(&self) -> Cow<'_, ZakatConfig> {
         match self {
             Some(c) => Cow::Borrowed(c),
-            None => Cow::Owned(ZakatConfig::default()),
+            None => ().resolve_config(),
         }
     }
 }
@@ -65,6 +83,12 @@ pub trait CalculateZakat {
         self.calculate_zakat(())
     }
 
+    /// Calculates and returns the one-line [`ZakatDetails::summary`],
+    /// e.g. for logging each asset's outcome.
+    fn summary<C: ZakatConfigArgument>(&self, config: C) -> Result<String, ZakatError> {
+        self.calculate_zakat(config).map(|details| details.summary())
+    }
+
     /// Check if the asset has valid inputs.
     fn is_valid(&self) -> bool {
         self.validate_input().is_ok()
@@ -77,8 +101,112 @@ pub trait CalculateZakat {
         Ok(())
     }
 
+    /// Checks everything `calculate_zakat` would reject under `config`, without
+    /// calculating: input errors plus the config prices the asset depends on.
+    ///
+    /// Forms can call this to show an inline error before adding the asset.
+    /// Defaults to [`Self::validate_input`]; assets valued from config prices
+    /// override it to require those prices too.
+    fn preflight(&self, _config: &ZakatConfig) -> Result<(), ZakatError> {
+        self.validate_input()
+    }
+
+    /// Returns true if calculating this asset depends on market prices: metal
+    /// weights or livestock heads that must be valued, or a Nisab priced in
+    /// gold/silver (cash, business, income, investments, mines).
+    ///
+    /// Callers can use this to skip fetching live prices when nothing needs them,
+    /// e.g. a portfolio of only crops or Rikaz.
+    fn requires_prices(&self) -> bool { false }
+
+    /// Returns the rate that calculating this asset would apply under `config`.
+    ///
+    /// Defaults to the strategy's trade-goods rate (2.5% for all presets).
+    /// Assets whose due is a count rather than a share of value (livestock,
+    /// Fitrah) return zero.
+    fn zakat_rate(&self, config: &ZakatConfig) -> rust_decimal::Decimal {
+        config.strategy.get_rules().trade_goods_rate
+    }
+
     fn get_label(&self) -> Option<String> { None }
     fn get_id(&self) -> uuid::Uuid;
+
+    /// Returns the label, or `fallback` when the asset is unlabeled.
+    fn label_or(&self, fallback: &str) -> String {
+        self.get_label().unwrap_or_else(|| fallback.to_string())
+    }
+
+    /// Returns the label, or `"Asset #n"` (1-based) for the asset at `index`.
+    ///
+    /// Reports, the TUI and error sources all use this, so an unlabeled asset
+    /// is named the same way everywhere. Failure sources used to read
+    /// `"Item n"` and the TUI `"Item #n"`; match on the asset id, not the name.
+    fn label_or_indexed(&self, index: usize) -> String {
+        self.get_label().unwrap_or_else(|| indexed_label(index))
+    }
+
+    /// Boxes a clone of this asset behind the object-safe [`DynCalculateZakat`].
+    ///
+    /// `CalculateZakat` itself is not object-safe (its config argument is generic),
+    /// so heterogeneous collections such as `DynPortfolio` store this instead.
+    fn clone_box(&self) -> Box<dyn DynCalculateZakat>
+    where
+        Self: Clone + Send + Sync + 'static,
+    {
+        Box::new(self.clone())
+    }
+}
+
+/// Object-safe view of [`CalculateZakat`] for `Box<dyn ...>` storage.
+///
+/// Implemented automatically for every `CalculateZakat + Clone` type.
+pub trait DynCalculateZakat: Send + Sync {
+    fn calculate_dyn(&self, config: &ZakatConfig) -> Result<ZakatDetails, ZakatError>;
+    fn validate_dyn(&self) -> Result<(), ZakatError>;
+    fn label_dyn(&self) -> Option<String>;
+    fn id_dyn(&self) -> uuid::Uuid;
+    fn clone_dyn(&self) -> Box<dyn DynCalculateZakat>;
+}
+
+/// Default name for the unlabeled asset at `index` (0-based) in a portfolio.
+pub(crate) fn indexed_label(index: usize) -> String {
+    format!("Asset #{}", index + 1)
+}
+
+impl<T> DynCalculateZakat for T
+where
+    T: CalculateZakat + Clone + Send + Sync + 'static,
+{
+    fn calculate_dyn(&self, config: &ZakatConfig) -> Result<ZakatDetails, ZakatError> {
+        self.calculate_zakat(config)
+    }
+    fn validate_dyn(&self) -> Result<(), ZakatError> {
+        self.validate_input()
+    }
+    fn label_dyn(&self) -> Option<String> {
+        CalculateZakat::get_label(self)
+    }
+    fn id_dyn(&self) -> uuid::Uuid {
+        CalculateZakat::get_id(self)
+    }
+    fn clone_dyn(&self) -> Box<dyn DynCalculateZakat> {
+        self.clone_box()
+    }
+}
+
+impl Clone for Box<dyn DynCalculateZakat> {
+    fn clone(&self) -> Self {
+        (**self).clone_dyn()
+    }
+}
+
+impl std::fmt::Debug for dyn DynCalculateZakat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynCalculateZakat")
+            .field("id", &self.id_dyn())
+            .field("label", &self.label_dyn())
+            .finish()
+    }
 }
 
 #[cfg(feature = "async")]
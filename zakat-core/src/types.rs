@@ -88,6 +88,35 @@ impl LiabilityType {
     }
 }
 
+/// How much of a long-term debt (Dayn al-Mu'ajjal) is deducted before the Nisab check.
+///
+/// Immediate debts are always deducted in full; the schools differ on deferred ones.
+/// Selected with [`ZakatConfig::with_debt_policy`](crate::config::ZakatConfig::with_debt_policy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, schemars::JsonSchema)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[typeshare::typeshare]
+#[serde(rename_all = "camelCase")]
+pub enum DebtDeductionPolicy {
+    /// Long-term debts are not deducted until their instalments fall due.
+    ImmediateOnly,
+    /// Long-term debts deduct up to twelve monthly payments (AAOIFI and most contemporary fatwas).
+    #[default]
+    OneYearPortion,
+    /// The whole outstanding balance is deducted (classical Hanbali and Maliki view for monetary wealth).
+    FullOutstanding,
+}
+
+impl DebtDeductionPolicy {
+    /// Returns a human-readable description used in calculation traces.
+    pub fn description(&self) -> &'static str {
+        match self {
+            DebtDeductionPolicy::ImmediateOnly => "Debt policy: long-term debts not deducted (immediate debts only)",
+            DebtDeductionPolicy::OneYearPortion => "Debt policy: long-term debts deducted up to 12 monthly payments",
+            DebtDeductionPolicy::FullOutstanding => "Debt policy: full outstanding balance of long-term debts deducted",
+        }
+    }
+}
+
 
 /// Represents a named liability that can be deducted from Zakat calculations.
 /// 
@@ -114,6 +143,12 @@ pub struct Liability {
     /// For LongTerm debts: Monthly payment amount to calculate annual cap.
     #[typeshare(serialized_as = "Option<string>")]
     pub monthly_payment: Option<Decimal>,
+    /// When the debt falls due. Undated debts are treated as due now; dated
+    /// immediate debts are only deducted if they fall due within the coming
+    /// lunar year. Long-term debts follow the [`DebtDeductionPolicy`] instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[typeshare(serialized_as = "Option<string>")]
+    pub due_date: Option<chrono::NaiveDate>,
 }
 
 impl Liability {
@@ -127,6 +162,7 @@ impl Liability {
             amount,
             kind: LiabilityType::Immediate,
             monthly_payment: None,
+            due_date: None,
         }
     }
 
@@ -140,6 +176,7 @@ impl Liability {
             amount: total_amount,
             kind: LiabilityType::LongTerm,
             monthly_payment: Some(monthly_payment),
+            due_date: None,
         }
     }
     
@@ -150,8 +187,26 @@ impl Liability {
             amount: amount.into_zakat_decimal()?,
             kind: LiabilityType::Immediate,
             monthly_payment: None,
+            due_date: None,
         })
     }
+
+    /// Sets the date the debt falls due.
+    pub fn due_on(mut self, date: chrono::NaiveDate) -> Self {
+        self.due_date = Some(date);
+        self
+    }
+
+    /// True if the debt is due on or before `date`. Undated debts are always due.
+    pub fn is_due_by(&self, date: chrono::NaiveDate) -> bool {
+        self.due_date.is_none_or(|due| due <= date)
+    }
+}
+
+/// Last day a dated debt may fall due and still be deducted: one lunar year
+/// (354 days) from `as_of`, i.e. the coming Hawl.
+pub(crate) fn liability_window_end(as_of: chrono::NaiveDate) -> chrono::NaiveDate {
+    as_of + chrono::Duration::days(354)
 }
 
 // =============================================================================
@@ -385,6 +440,7 @@ pub enum PaymentPayload {
         #[typeshare(serialized_as = "string")]
         harvest_weight: Decimal,
         irrigation_method: String,
+        /// Cash value of the crop due (`harvest_weight * price_per_kg * rate`, net of costs).
         #[typeshare(serialized_as = "string")]
         crop_value: Decimal,
     },
@@ -464,6 +520,9 @@ pub struct CalculationStep {
     pub args: Option<std::collections::HashMap<String, String>>,
     /// Fiqh reference source (e.g. "Sahih Bukhari 1454").
     pub reference: Option<String>,
+    /// Contextual footnote for this step (e.g. "clamped per majority view").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
 }
 
 impl CalculationStep {
@@ -475,6 +534,7 @@ impl CalculationStep {
             operation: Operation::Initial,
             args: None,
             reference: None,
+            note: None,
         }
     }
 
@@ -486,6 +546,7 @@ impl CalculationStep {
             operation: Operation::Add,
             args: None,
             reference: None,
+            note: None,
         }
     }
 
@@ -497,6 +558,7 @@ impl CalculationStep {
             operation: Operation::Subtract,
             args: None,
             reference: None,
+            note: None,
         }
     }
 
@@ -508,6 +570,7 @@ impl CalculationStep {
             operation: Operation::Multiply,
             args: None,
             reference: None,
+            note: None,
         }
     }
 
@@ -519,6 +582,7 @@ impl CalculationStep {
             operation: Operation::Compare,
             args: None,
             reference: None,
+            note: None,
         }
     }
 
@@ -530,6 +594,7 @@ impl CalculationStep {
             operation: Operation::Rate,
             args: None,
             reference: None,
+            note: None,
         }
     }
 
@@ -541,6 +606,7 @@ impl CalculationStep {
             operation: Operation::Result,
             args: None,
             reference: None,
+            note: None,
         }
     }
 
@@ -552,6 +618,7 @@ impl CalculationStep {
             operation: Operation::Info,
             args: None,
             reference: None,
+            note: None,
         }
     }
 
@@ -564,6 +631,12 @@ impl CalculationStep {
         self.args = Some(args);
         self
     }
+
+    /// Attaches a footnote shown under the step in text output and exported with it.
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
 }
 
 /// A collection of calculation steps that can be displayed or serialized.
@@ -640,11 +713,25 @@ impl std::fmt::Display for CalculationBreakdown {
                     width = max_desc_len
                  )?;
             }
+
+            if let Some(note) = &step.note {
+                writeln!(f, "    Note: {}", note)?;
+            }
         }
         Ok(())
     }
 }
 
+/// Returns the JSON Schema of [`ZakatDetails`] as pretty-printed JSON.
+///
+/// `PaymentPayload`, `CalculationStep` and the other nested types appear under
+/// `definitions`, following the serde attributes, so the schema validates what
+/// `serde_json` writes (e.g. payloads as `{"type": "monetary", "content": ...}`).
+pub fn json_schema() -> String {
+    let schema = schemars::schema_for!(ZakatDetails);
+    serde_json::to_string_pretty(&schema).unwrap_or_default()
+}
+
 /// Represents the detailed breakdown of the Zakat calculation.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, schemars::JsonSchema)]
 
@@ -667,6 +754,10 @@ pub struct ZakatDetails {
     /// The Nisab threshold applicable for this type of wealth.
     #[typeshare(serialized_as = "string")]
     pub nisab_threshold: Decimal,
+    /// Metal whose price set `nisab_threshold`, for gold, silver and other
+    /// monetary wealth. `None` for Nisabs counted in heads or harvest weight.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nisab_metal: Option<WealthType>,
     /// Whether Zakat is due (net_assets >= nisab_threshold).
     pub is_payable: bool,
     /// The final Zakat amount due.
@@ -676,6 +767,9 @@ pub struct ZakatDetails {
     pub wealth_type: WealthType,
     /// Reason for the status, if not payable (e.g. "Hawl not met").
     pub status_reason: Option<String>,
+    /// i18n key for `status_reason` when the library set it (e.g. `"reason-hawl-not-met"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_reason_key: Option<String>,
     /// Optional label for the asset (e.g. "Main Store", "Gold Necklace").
     pub label: Option<String>,
     /// Unique identifier of the asset (v1.2+).
@@ -699,6 +793,36 @@ pub struct ZakatDetails {
     pub notes: Vec<String>,
 }
 
+/// A monetary amount paired with its currency and display string.
+///
+/// Returned by [`ZakatDetails::money_due`] so clients don't need to re-parse
+/// formatted strings to recover the number.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[typeshare::typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct Money {
+    /// The amount, rounded to 2 decimal places.
+    #[typeshare(serialized_as = "string")]
+    pub amount: Decimal,
+    /// ISO currency code (e.g., "USD").
+    pub currency: String,
+    /// The amount formatted for display (e.g., "$12.50").
+    pub formatted: String,
+}
+
+impl Money {
+    /// Creates a `Money` value using the config's currency and formatting.
+    pub fn new(amount: Decimal, config: &crate::config::ZakatConfig) -> Self {
+        use rust_decimal::RoundingStrategy;
+        let amount = amount.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+        Self {
+            amount,
+            currency: config.currency_code.clone(),
+            formatted: config.format_currency(amount),
+        }
+    }
+}
+
 /// Structured representation of a Zakat calculation for API consumers.
 ///
 /// This struct allows frontend applications (e.g., React, Vue) to render their
@@ -731,6 +855,10 @@ pub struct ZakatExplanation {
     pub warnings: Vec<String>,
     /// Additional notes (e.g., exemption reason).
     pub notes: Vec<String>,
+    /// Signed per-component amounts, see [`ZakatDetails::breakdown_by_component`].
+    #[serde(default)]
+    #[typeshare(skip)]
+    pub components: Vec<(String, Decimal)>,
 }
 
 impl std::fmt::Display for ZakatExplanation {
@@ -833,10 +961,12 @@ impl ZakatDetails {
             liabilities: Vec::new(),
             net_assets,
             nisab_threshold,
+            nisab_metal: None,
             is_payable,
             zakat_due,
             wealth_type,
             status_reason: clamped_msg.map(|s| s.to_string()),
+            status_reason_key: clamped_msg.map(|_| "reason-net-negative".to_string()),
             label: None,
             asset_id: None,
             payload: PaymentPayload::Monetary(zakat_due),
@@ -851,8 +981,11 @@ impl ZakatDetails {
     /// Calculates the recommendation status based on net assets and Nisab.
     /// 
     /// - If payable: `Obligatory`
-    /// - If net_assets >= 90% of Nisab but < 100%: `Recommended` (Sadaqah encouraged)
+    /// - If net_assets >= 90% of Nisab but not payable: `Recommended` (Sadaqah encouraged)
     /// - Otherwise: `None`
+    ///
+    /// Monetary calculators may further mark wealth between the silver and gold
+    /// Nisab as `Recommended` via [`crate::madhab::recommendation_for`].
     fn calculate_recommendation(is_payable: bool, net_assets: Decimal, nisab_threshold: Decimal) -> ZakatRecommendation {
         if is_payable {
             return ZakatRecommendation::Obligatory;
@@ -920,10 +1053,12 @@ impl ZakatDetails {
             liabilities: Vec::new(),
             net_assets,
             nisab_threshold,
+            nisab_metal: None,
             is_payable,
             zakat_due,
             wealth_type,
             status_reason: None,
+            status_reason_key: None,
             label: None,
             asset_id: None,
             payload: PaymentPayload::Monetary(zakat_due),
@@ -948,10 +1083,12 @@ impl ZakatDetails {
             liabilities: Vec::new(),
             net_assets: Decimal::ZERO,
             nisab_threshold,
+            nisab_metal: None,
             is_payable: false,
             zakat_due: Decimal::ZERO,
             wealth_type,
             status_reason: Some(reason.to_string()),
+            status_reason_key: None,
             label: None,
             asset_id: None,
             payload: PaymentPayload::Monetary(Decimal::ZERO),
@@ -973,6 +1110,18 @@ impl ZakatDetails {
         self
     }
 
+    /// Records the i18n key for the `status_reason` text (e.g. `"reason-hawl-not-met"`).
+    pub fn with_status_reason_key(mut self, key: impl Into<String>) -> Self {
+        self.status_reason_key = Some(key.into());
+        self
+    }
+
+    /// Records which metal's price set the Nisab.
+    pub fn with_nisab_metal(mut self, metal: WealthType) -> Self {
+        self.nisab_metal = Some(metal);
+        self
+    }
+
 
 
     /// Returns the Zakat due formatted as a string with 2 decimal places.
@@ -983,6 +1132,20 @@ impl ZakatDetails {
         format!("{:.2}", rounded)
     }
 
+    /// Returns the Zakat due as structured [`Money`] (amount, currency, formatted string).
+    pub fn money_due(&self, config: &crate::config::ZakatConfig) -> Money {
+        Money::new(self.zakat_due, config)
+    }
+
+    /// Returns the i18n key for `status_reason`, if it is one the library sets
+    /// (e.g. `"reason-hawl-not-met"`).
+    ///
+    /// Reasons set by callers have no key; show `status_reason` as-is for those.
+    pub fn status_reason_key(&self) -> Option<&str> {
+        self.status_reason.as_ref()?;
+        self.status_reason_key.as_deref()
+    }
+
     /// Returns a concise status string (basic, non-localized).
     /// Format: "{Label}: {Payable/Exempt} - Due: {Amount}"
     /// 
@@ -1033,15 +1196,106 @@ impl ZakatDetails {
             steps: self.calculation_breakdown.0.clone(),
             warnings: self.warnings.clone(),
             notes,
+            components: self.breakdown_by_component(),
         }
     }
 
+    /// Returns true when the Zakat is paid in kind (animals or crops) rather than currency.
+    ///
+    /// `zakat_due` still carries the cash-equivalent value for totals.
+    pub fn is_in_kind(&self) -> bool {
+        matches!(self.payload, PaymentPayload::Livestock { .. } | PaymentPayload::Agriculture { .. })
+    }
+
+    /// Returns the zakatable components and deductions as signed amounts.
+    ///
+    /// Components are read from the trace: `Initial`/`Add` steps count as positive,
+    /// `Subtract` steps (liabilities, costs) as negative. When the trace does not
+    /// reconcile to `net_assets` (e.g. weight/price traces), a two-line
+    /// "Total Assets"/"Deductions" view is returned instead, so the amounts always
+    /// sum to `net_assets`.
+    pub fn breakdown_by_component(&self) -> Vec<(String, Decimal)> {
+        let components: Vec<(String, Decimal)> = self.calculation_breakdown.0.iter()
+            .filter_map(|step| {
+                let amount = step.amount?;
+                match step.operation {
+                    Operation::Initial | Operation::Add => Some((step.description.clone(), amount)),
+                    Operation::Subtract => Some((step.description.clone(), -amount)),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        let reconciles = components.iter()
+            .try_fold(Decimal::ZERO, |acc, (_, amount)| acc.checked_add(*amount))
+            .is_some_and(|sum| sum == self.net_assets);
+        if !components.is_empty() && reconciles {
+            return components;
+        }
+
+        let mut fallback = vec![("Total Assets".to_string(), self.total_assets)];
+        if self.total_assets != self.net_assets {
+            fallback.push(("Deductions".to_string(), self.net_assets - self.total_assets));
+        }
+        fallback
+    }
+
     /// Generates a basic human-readable explanation of the Zakat calculation.
     ///
     /// For localized output, use `zakat-i18n` crate.
     pub fn explain(&self) -> String {
         format!("{}", self)
     }
+
+    /// Renders the calculation as Markdown for issues, wikis and docs.
+    ///
+    /// Produces a `Description | Operation | Amount` table of the breakdown,
+    /// a status line with the amount due and, if any, a list of warnings.
+    /// Amounts use two decimals like [`Self::format_amount`]; rates are shown
+    /// as percentages. Pipes in labels are escaped so the table stays intact.
+    pub fn to_markdown(&self) -> String {
+        use std::fmt::Write;
+
+        let mut md = String::new();
+        let label = markdown_cell(self.label.as_deref().unwrap_or("Asset"));
+        writeln!(md, "### {} ({:?})\n", label, self.wealth_type).ok();
+        writeln!(md, "| Description | Operation | Amount |").ok();
+        writeln!(md, "|---|:---:|---:|").ok();
+        for step in self.calculation_breakdown.iter() {
+            let amount = match (step.amount, &step.operation) {
+                (Some(rate), Operation::Rate) => format!("{}%", markdown_amount(rate * Decimal::from(100))),
+                (Some(amount), _) => markdown_amount(amount),
+                (None, _) => String::new(),
+            };
+            writeln!(md, "| {} | {} | {} |", markdown_cell(&step.description), step.operation.to_string().trim(), amount).ok();
+        }
+
+        let status = if self.is_payable { "PAYABLE" } else { "EXEMPT" };
+        write!(md, "\n**Status:** {} | **Zakat Due:** {}", status, self.format_amount()).ok();
+        match self.status_reason.as_deref() {
+            Some(reason) if !self.is_payable => writeln!(md, " ({})", markdown_cell(reason)).ok(),
+            _ => writeln!(md).ok(),
+        };
+
+        if !self.structured_warnings.is_empty() {
+            writeln!(md, "\n**Warnings:**").ok();
+            for warning in &self.structured_warnings {
+                writeln!(md, "- {}", markdown_cell(&warning.message)).ok();
+            }
+        }
+        md
+    }
+}
+
+/// Two-decimal amount, rounded like [`ZakatDetails::format_amount`].
+fn markdown_amount(amount: Decimal) -> String {
+    let rounded = amount.round_dp_with_strategy(2, rust_decimal::RoundingStrategy::MidpointAwayFromZero);
+    format!("{:.2}", rounded)
+}
+
+/// Escapes text for a Markdown table cell: pipes are escaped and line breaks flattened.
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
 }
 
 impl std::fmt::Display for ZakatDetails {
@@ -1182,11 +1436,92 @@ pub enum ZakatError {
     #[error("Multiple validation errors occurred")]
     MultipleErrors(Vec<ZakatError>),
 
-    #[error("Network error: {0}")]
-    NetworkError(String),
+    #[error("Network error: {message}")]
+    NetworkError {
+        message: String,
+        /// HTTP status code, when the failure came from an HTTP response.
+        status: Option<u16>,
+        /// Whether repeating the same request may succeed (e.g. timeouts, 429, 5xx).
+        retryable: bool,
+    },
 }
 
 impl ZakatError {
+    /// A transport-level failure (connection, DNS, timeout) with no HTTP status.
+    ///
+    /// These are treated as retryable.
+    pub fn network(message: impl Into<String>) -> Self {
+        ZakatError::NetworkError {
+            message: message.into(),
+            status: None,
+            retryable: true,
+        }
+    }
+
+    /// A failure signalled by an HTTP status code.
+    ///
+    /// 408 (timeout), 429 (rate limited) and 5xx responses are retryable;
+    /// other statuses (e.g. 401, 404) will fail the same way again.
+    pub fn from_http_status(status: u16, message: impl Into<String>) -> Self {
+        ZakatError::NetworkError {
+            message: message.into(),
+            status: Some(status),
+            retryable: matches!(status, 408 | 429 | 500..=599),
+        }
+    }
+
+    /// A failure to serialize `source_label`'s data to JSON.
+    pub(crate) fn serialization(source_label: &str, e: impl std::fmt::Display) -> Self {
+        ZakatError::CalculationError(Box::new(ErrorDetails {
+            code: ZakatErrorCode::CalculationError,
+            reason_key: "error-serialize-json".to_string(),
+            args: Some(std::collections::HashMap::from([("details".to_string(), e.to_string())])),
+            source_label: Some(source_label.to_string()),
+            ..Default::default()
+        }))
+    }
+
+    /// An upstream response that arrived but could not be understood
+    /// (unparseable body, missing fields).
+    ///
+    /// Not retryable: the same request will most likely return the same body.
+    pub fn malformed_response(message: impl Into<String>) -> Self {
+        ZakatError::NetworkError {
+            message: message.into(),
+            status: None,
+            retryable: false,
+        }
+    }
+
+    /// Returns true if repeating the operation may succeed.
+    ///
+    /// Only network errors can be retryable; validation and calculation errors never are.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ZakatError::NetworkError { retryable: true, .. })
+    }
+
+    /// Returns the HTTP status a server should answer with for this error.
+    ///
+    /// Bad input or config is the caller's fault (400), arithmetic failures are
+    /// ours (500) and upstream price-feed failures are a bad gateway (502).
+    /// `MultipleErrors` answers with the most severe status among its errors.
+    ///
+    /// Not to be confused with [`ZakatError::from_http_status`], which builds a
+    /// network error *from* an upstream status.
+    pub fn http_status(&self) -> u16 {
+        match self {
+            ZakatError::InvalidInput(_)
+            | ZakatError::ConfigurationError(_)
+            | ZakatError::MissingConfig { .. } => 400,
+            ZakatError::Overflow { .. } | ZakatError::CalculationError(_) => 500,
+            ZakatError::NetworkError { .. } => 502,
+            ZakatError::MultipleErrors(errors) => errors.iter()
+                .map(ZakatError::http_status)
+                .max()
+                .unwrap_or(400),
+        }
+    }
+
     /// Returns the structured error code enum for programmatic handling.
     ///
     /// This is preferred over `code()` for new code as it provides type safety
@@ -1208,7 +1543,7 @@ impl ZakatError {
             ZakatError::MissingConfig { .. } => ZakatErrorCode::ConfigMissing,
             ZakatError::Overflow { .. } => ZakatErrorCode::CalculationOverflow,
             ZakatError::MultipleErrors(_) => ZakatErrorCode::MultipleErrors,
-            ZakatError::NetworkError(_) => ZakatErrorCode::NetworkError,
+            ZakatError::NetworkError { .. } => ZakatErrorCode::NetworkError,
         }
     }
 
@@ -1226,7 +1561,7 @@ impl ZakatError {
             ZakatError::MissingConfig { .. } => "MISSING_CONFIG",
             ZakatError::Overflow { .. } => "OVERFLOW",
             ZakatError::MultipleErrors(_) => "MULTIPLE_ERRORS",
-            ZakatError::NetworkError(_) => "NETWORK_ERROR",
+            ZakatError::NetworkError { .. } => "NETWORK_ERROR",
         }
     }
 
@@ -1257,7 +1592,7 @@ impl ZakatError {
             ZakatError::MultipleErrors(errors) => ZakatError::MultipleErrors(
                 errors.into_iter().map(|e| e.with_source(source.clone())).collect()
             ),
-            ZakatError::NetworkError(msg) => ZakatError::NetworkError(msg),
+            err @ ZakatError::NetworkError { .. } => err,
         }
     }
 
@@ -1289,7 +1624,7 @@ impl ZakatError {
             ZakatError::MultipleErrors(errors) => ZakatError::MultipleErrors(
                 errors.into_iter().map(|e| e.with_asset_id(id)).collect()
             ),
-            ZakatError::NetworkError(msg) => ZakatError::NetworkError(msg),
+            err @ ZakatError::NetworkError { .. } => err,
         }
     }
 
@@ -1321,7 +1656,7 @@ impl ZakatError {
                 let msgs: Vec<String> = errs.iter().map(|e| e.report()).collect();
                 msgs.join("; ")
             },
-            ZakatError::NetworkError(msg) => msg.clone(),
+            ZakatError::NetworkError { message, .. } => message.clone(),
         };
         
         // Append suggestion if present
@@ -1344,25 +1679,37 @@ impl ZakatError {
         self.report()
     }
 
+    /// Returns the i18n key of a short, actionable fix hint for this error
+    /// (e.g. `hint-set-prices`), so UIs can show a suggestion without parsing messages.
+    pub fn hint_key(&self) -> &'static str {
+        self.hint().0
+    }
+
     fn get_hint(&self) -> &'static str {
+        self.hint().1
+    }
+
+    /// Pairs each hint's i18n key with its default English text.
+    fn hint(&self) -> (&'static str, &'static str) {
          match self {
             ZakatError::ConfigurationError(details) => {
-                if details.reason_key.contains("gold-price") || details.reason_key.contains("silver-price") {
-                    "Suggestion: Set prices in ZakatConfig using .with_gold_price() / .with_silver_price()"
+                let key = &details.reason_key;
+                if key.contains("gold-") || key.contains("silver-") || key.contains("price") {
+                    ("hint-set-prices", "Suggestion: Set prices in ZakatConfig using .with_gold_price() / .with_silver_price()")
                 } else {
-                    "Suggestion: Check ZakatConfig setup."
+                    ("hint-check-config", "Suggestion: Check ZakatConfig setup.")
                 }
             },
             ZakatError::MissingConfig { field, .. } => {
                 if field.contains("price") {
-                     "Suggestion: Set missing price in ZakatConfig."
+                     ("hint-set-missing-price", "Suggestion: Set missing price in ZakatConfig.")
                 } else {
-                     "Suggestion: Ensure all required configuration fields are set."
+                     ("hint-set-required-config", "Suggestion: Ensure all required configuration fields are set.")
                 }
             },
-            ZakatError::InvalidInput(_) => "Suggestion: Ensure all input values are non-negative and correct.",
-            ZakatError::NetworkError(_) => "Suggestion: Check internet connection or API availability.",
-            _ => "Suggestion: Check input data accuracy."
+            ZakatError::InvalidInput(_) => ("hint-check-inputs", "Suggestion: Ensure all input values are non-negative and correct."),
+            ZakatError::NetworkError { .. } => ("hint-check-network", "Suggestion: Check internet connection or API availability."),
+            _ => ("hint-check-data", "Suggestion: Check input data accuracy.")
         }
     }
 
@@ -1409,9 +1756,11 @@ impl ZakatError {
                  "message": "Multiple validation errors occurred",
                  "errors": errors.iter().map(|e| e.context()).collect::<Vec<_>>()
              }),
-             ZakatError::NetworkError(msg) => json!({
+             ZakatError::NetworkError { message, status, retryable } => json!({
                  "code": "NETWORK_ERROR",
-                 "message": msg,
+                 "message": message,
+                 "status": status,
+                 "retryable": retryable,
                  "hint": self.get_hint()
              })
         }
@@ -1450,6 +1799,67 @@ impl WealthType {
     }
 }
 
+/// Broad family a [`WealthType`] belongs to, for grouping in reports and UIs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WealthCategory {
+    /// Gold, silver, cash-like and trade wealth pooled under Dam' al-Amwal.
+    Monetary,
+    /// Crops and harvests (Zakat al-Zuru').
+    Agricultural,
+    /// Grazing animals counted by head.
+    Livestock,
+    /// Mined minerals and buried treasure.
+    Extracted,
+    /// Obligations on the person rather than on wealth (Zakat al-Fitr).
+    Personal,
+    /// User-defined assets.
+    Custom,
+}
+
+/// Frontend-neutral description of a [`WealthType`]: a display name, a short
+/// rate hint and a category. Icons and colors are left to each frontend.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WealthTypeMeta {
+    /// English name, used when no translation is available.
+    pub display_name: String,
+    /// Fluent key for the localized name, e.g. `wealth-type-gold`.
+    /// `Other` types share `wealth-type-other`; show `display_name` for them
+    /// when the user gave the type a name.
+    pub display_name_key: String,
+    /// Human-readable rate, e.g. "2.5%"; not used in calculations.
+    pub default_rate_hint: String,
+    pub category: WealthCategory,
+}
+
+/// Returns the display metadata for `wt`, shared by the CLI, TUI and bindings.
+///
+/// `Other` types are shown under their own name.
+pub fn wealth_type_meta(wt: &WealthType) -> WealthTypeMeta {
+    let (display_name, display_name_key, default_rate_hint, category) = match wt {
+        WealthType::Fitrah => ("Zakat al-Fitr", "wealth-type-fitrah", "1 sa' of staple food per person", WealthCategory::Personal),
+        WealthType::Gold => ("Gold", "wealth-type-gold", "2.5%", WealthCategory::Monetary),
+        WealthType::Silver => ("Silver", "wealth-type-silver", "2.5%", WealthCategory::Monetary),
+        WealthType::Business => ("Business", "wealth-type-business", "2.5%", WealthCategory::Monetary),
+        WealthType::Agriculture => ("Agriculture", "wealth-type-agriculture", "10% rain-fed, 5% irrigated", WealthCategory::Agricultural),
+        WealthType::Livestock => ("Livestock", "wealth-type-livestock", "Per head-count table", WealthCategory::Livestock),
+        WealthType::Income => ("Income", "wealth-type-income", "2.5%", WealthCategory::Monetary),
+        WealthType::Investment => ("Investments", "wealth-type-investment", "2.5%", WealthCategory::Monetary),
+        WealthType::Mining => ("Mining", "wealth-type-mining", "2.5%", WealthCategory::Extracted),
+        WealthType::Rikaz => ("Rikaz (Buried Treasure)", "wealth-type-rikaz", "20%", WealthCategory::Extracted),
+        WealthType::Other(name) => {
+            let display_name = if name.trim().is_empty() { "Other" } else { name.as_str() };
+            (display_name, "wealth-type-other", "Custom rate", WealthCategory::Custom)
+        }
+    };
+    WealthTypeMeta {
+        display_name: display_name.to_string(),
+        display_name_key: display_name_key.to_string(),
+        default_rate_hint: default_rate_hint.to_string(),
+        category,
+    }
+}
+
 impl crate::inputs::ToFfiString for WealthType {
     fn to_ffi_string(&self) -> String {
         self.to_string()
@@ -1530,6 +1940,19 @@ pub struct FfiZakatError {
     pub source_label: Option<String>,
 }
 
+impl FfiZakatError {
+    /// Error for a value that could not be parsed into `field`.
+    pub fn invalid_field(field: &str, message: impl Into<String>) -> Self {
+        FfiZakatError {
+            code: ZakatErrorCode::InvalidInput.to_string(),
+            message: message.into(),
+            field: Some(field.to_string()),
+            hint: None,
+            source_label: None,
+        }
+    }
+}
+
 impl From<ZakatError> for FfiZakatError {
     fn from(err: ZakatError) -> Self {
         // Use default translator for message
@@ -1580,7 +2003,7 @@ impl From<ZakatError> for FfiZakatError {
                 hint: None,
                 source_label: None,
             },
-            ZakatError::NetworkError(_) => FfiZakatError {
+            ZakatError::NetworkError { .. } => FfiZakatError {
                 code,
                 message,
                 field: None,
@@ -1680,4 +2103,160 @@ mod tests {
         // We test the logic used in the zakat_asset! macro indirectly by looking at how it's calculated.
         // Since the macro is in another crate/module, we can just verify the struct fields here.
     }
+
+    #[test]
+    fn test_status_reason_key_is_stored_not_matched_from_text() {
+        let custom = ZakatDetails::below_threshold(dec!(100), WealthType::Business, "Hawl (1 lunar year) not met");
+        assert_eq!(custom.status_reason_key(), None);
+
+        let keyed = ZakatDetails::below_threshold(dec!(100), WealthType::Business, "Hawl belum tercapai")
+            .with_status_reason_key("reason-hawl-not-met");
+        assert_eq!(keyed.status_reason_key(), Some("reason-hawl-not-met"));
+
+        let json = serde_json::to_value(&keyed).unwrap();
+        assert_eq!(json["statusReasonKey"], "reason-hawl-not-met");
+    }
+
+    #[test]
+    fn test_recommendation_near_and_above_nisab() {
+        let nisab = dec!(1000);
+        assert_eq!(ZakatDetails::calculate_recommendation(true, dec!(1000), nisab), ZakatRecommendation::Obligatory);
+        assert_eq!(ZakatDetails::calculate_recommendation(false, dec!(899), nisab), ZakatRecommendation::None);
+        assert_eq!(ZakatDetails::calculate_recommendation(false, dec!(900), nisab), ZakatRecommendation::Recommended);
+        // Not payable for another reason (e.g. Hawl unmet) while at or above Nisab.
+        assert_eq!(ZakatDetails::calculate_recommendation(false, dec!(5000), nisab), ZakatRecommendation::Recommended);
+        assert_eq!(ZakatDetails::calculate_recommendation(false, dec!(5000), Decimal::ZERO), ZakatRecommendation::None);
+    }
+
+    #[test]
+    fn test_money_due_fields_agree() {
+        use crate::traits::CalculateZakat;
+        let config = crate::config::ZakatConfig::test_default()
+            .with_gold_price(100)
+            .with_currency_code("EUR");
+        let details = crate::maal::business::BusinessZakat::new()
+            .cash(dec!(10000.50))
+            .hawl(true)
+            .calculate_zakat(&config)
+            .unwrap();
+
+        let money = details.money_due(&config);
+        assert_eq!(money.amount, dec!(250.01));
+        assert_eq!(money.currency, "EUR");
+        assert_eq!(money.formatted, "€250.01");
+        assert_eq!(money.formatted, config.format_currency(money.amount));
+    }
+
+    #[test]
+    fn test_step_note_appears_in_text_and_structured_output() {
+        let step = CalculationStep::info("warn-negative-clamped", "Net Assets clamped to zero")
+            .with_note("clamped per majority view");
+        let breakdown = CalculationBreakdown(vec![
+            CalculationStep::initial("step-total-assets", "Total Assets", dec!(100)),
+            step,
+        ]);
+
+        let text = breakdown.to_string();
+        assert!(text.contains("Note: clamped per majority view"), "{}", text);
+
+        let json = serde_json::to_value(&breakdown).unwrap();
+        assert!(json[0].get("note").is_none(), "Unset notes stay out of the export");
+        assert_eq!(json[1]["note"], "clamped per majority view");
+
+        let restored: CalculationBreakdown = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, breakdown);
+    }
+
+    #[test]
+    fn test_from_http_status_retryability() {
+        let unavailable = ZakatError::from_http_status(503, "Service Unavailable");
+        assert!(unavailable.is_retryable());
+        assert!(matches!(unavailable, ZakatError::NetworkError { status: Some(503), .. }));
+
+        let unauthorized = ZakatError::from_http_status(401, "Unauthorized");
+        assert!(!unauthorized.is_retryable());
+        assert_eq!(unauthorized.context()["retryable"], false);
+
+        assert!(ZakatError::network("connection reset").is_retryable());
+        assert!(!ZakatError::malformed_response("unexpected body").is_retryable());
+        assert!(!ZakatError::MultipleErrors(vec![]).is_retryable());
+    }
+
+    #[test]
+    fn test_wealth_type_meta_covers_every_variant() {
+        let all = [
+            WealthType::Fitrah, WealthType::Gold, WealthType::Silver, WealthType::Business,
+            WealthType::Agriculture, WealthType::Livestock, WealthType::Income, WealthType::Investment,
+            WealthType::Mining, WealthType::Rikaz, WealthType::Other("Art".to_string()),
+        ];
+        for wt in &all {
+            let meta = wealth_type_meta(wt);
+            assert!(!meta.display_name.is_empty(), "{:?}", wt);
+            assert!(meta.display_name_key.starts_with("wealth-type-"), "{:?}", wt);
+            assert!(!meta.default_rate_hint.is_empty(), "{:?}", wt);
+            assert_eq!(meta.category == WealthCategory::Monetary, wt.is_monetary(), "{:?}", wt);
+        }
+
+        assert_eq!(wealth_type_meta(&WealthType::Agriculture).category, WealthCategory::Agricultural);
+        assert_eq!(wealth_type_meta(&WealthType::Rikaz).category, WealthCategory::Extracted);
+        assert_eq!(wealth_type_meta(&WealthType::Fitrah).category, WealthCategory::Personal);
+        assert_eq!(wealth_type_meta(&WealthType::Other("Art".to_string())).display_name, "Art");
+        assert_eq!(wealth_type_meta(&WealthType::Other(String::new())).display_name, "Other");
+    }
+
+    #[test]
+    fn test_json_schema_matches_serde_payload_tags() {
+        let schema: serde_json::Value = serde_json::from_str(&json_schema()).unwrap();
+        let definitions = &schema["definitions"];
+        assert!(definitions.get("CalculationStep").is_some());
+
+        let tags: Vec<String> = definitions["PaymentPayload"]["oneOf"].as_array().unwrap().iter()
+            .flat_map(|variant| variant["properties"]["type"]["enum"].as_array().unwrap().clone())
+            .map(|tag| tag.as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(tags, ["monetary", "livestock", "agriculture"]);
+
+        let serialized = serde_json::to_value(PaymentPayload::Monetary(Decimal::ONE)).unwrap();
+        assert_eq!(serialized["type"], "monetary");
+        assert!(serialized.get("content").is_some());
+    }
+
+    #[test]
+    fn test_http_status_per_variant() {
+        let details = || Box::new(ErrorDetails::default());
+        let missing = ZakatError::MissingConfig { field: "gold_price_per_gram".to_string(), source_label: None, asset_id: None };
+        let overflow = ZakatError::Overflow { operation: "mul".to_string(), source_label: None, asset_id: None };
+
+        assert_eq!(ZakatError::InvalidInput(Box::default()).http_status(), 400);
+        assert_eq!(ZakatError::ConfigurationError(details()).http_status(), 400);
+        assert_eq!(missing.http_status(), 400);
+        assert_eq!(overflow.http_status(), 500);
+        assert_eq!(ZakatError::CalculationError(details()).http_status(), 500);
+        assert_eq!(ZakatError::from_http_status(401, "Unauthorized").http_status(), 502);
+        assert_eq!(ZakatError::network("connection reset").http_status(), 502);
+
+        let mixed = ZakatError::MultipleErrors(vec![ZakatError::InvalidInput(Box::default()), ZakatError::CalculationError(details())]);
+        assert_eq!(mixed.http_status(), 500);
+        assert_eq!(ZakatError::MultipleErrors(vec![]).http_status(), 400);
+    }
+
+    #[test]
+    fn test_markdown_report_escapes_pipes_and_lists_steps() {
+        use crate::traits::CalculateZakat;
+        let details = crate::maal::business::BusinessZakat::new()
+            .cash(dec!(10000.505))
+            .label("Shop | Main St")
+            .hawl(true)
+            .calculate_zakat(&crate::config::ZakatConfig::test_default())
+            .unwrap();
+
+        let md = details.to_markdown();
+
+        assert!(md.starts_with("### Shop \\| Main St (Business)"), "{}", md);
+        assert!(md.contains("| Description | Operation | Amount |"));
+        assert!(md.contains("| Net Assets | = | 10000.51 |"), "{}", md);
+        assert!(md.contains("| Rate Applied | x | 2.50% |"), "{}", md);
+        assert!(md.contains("**Status:** PAYABLE | **Zakat Due:** 250.01"), "{}", md);
+        assert!(!md.contains("**Warnings:**"));
+    }
 }
@@ -26,6 +26,21 @@ impl WeightUnit {
     }
 }
 
+/// Hashes the canonical JSON form of `value` into a `u64` that is stable across runs.
+///
+/// `serde_json::Value` sorts object keys, so field and `HashMap` ordering do not
+/// affect the result. Values that fail to serialize all hash the same.
+pub(crate) fn stable_checksum<T: serde::Serialize + ?Sized>(value: &T) -> u64 {
+    use sha2::{Digest, Sha256};
+    let canonical = serde_json::to_value(value)
+        .map(|value| value.to_string())
+        .unwrap_or_default();
+    let digest = Sha256::digest(canonical.as_bytes());
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&digest[..8]);
+    u64::from_le_bytes(prefix)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -17,19 +17,29 @@ impl Validator {
         checks: &[(&str, Decimal)], 
         source_label: Option<String>
     ) -> Result<(), ZakatError> {
-        for (field, val) in checks {
-            if *val < Decimal::ZERO {
-                return Err(ZakatError::InvalidInput(Box::new(InvalidInputDetails {
-                    field: field.to_string(),
-                    value: "negative".to_string(),
-                    reason_key: "error-negative-value".to_string(),
-                    source_label: source_label.clone(),
-                    suggestion: Some("Zakat inputs must be positive. Did you mean to subtract this value manually?".to_string()),
-                    ..Default::default()
-                })));
-            }
+        match Self::collect_negative(checks, source_label).into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
         }
-        Ok(())
+    }
+
+    /// Like [`Self::ensure_non_negative`], but returns an error for *every*
+    /// negative field instead of stopping at the first one.
+    pub fn collect_negative(
+        checks: &[(&str, Decimal)],
+        source_label: Option<String>
+    ) -> Vec<ZakatError> {
+        checks.iter()
+            .filter(|(_, val)| *val < Decimal::ZERO)
+            .map(|(field, _)| ZakatError::InvalidInput(Box::new(InvalidInputDetails {
+                field: field.to_string(),
+                value: "negative".to_string(),
+                reason_key: "error-negative-value".to_string(),
+                source_label: source_label.clone(),
+                suggestion: Some("Zakat inputs must be positive. Did you mean to subtract this value manually?".to_string()),
+                ..Default::default()
+            })))
+            .collect()
     }
 
     /// Ensures a required Option field is present.
@@ -16,6 +16,8 @@ class BusinessZakat:
     @property
     def receivables_list(self) -> builtins.str: ...
     @property
+    def inaccessible_fraction(self) -> builtins.str: ...
+    @property
     def liabilities_due_now(self) -> builtins.str: ...
     @property
     def hawl_satisfied(self) -> builtins.bool: ...
@@ -40,6 +42,10 @@ class IncomeZakatCalculator:
     @property
     def method(self) -> builtins.str: ...
     @property
+    def hawl_mode(self) -> builtins.str: ...
+    @property
+    def streams(self) -> builtins.str: ...
+    @property
     def liabilities_due_now(self) -> builtins.str: ...
     @property
     def hawl_satisfied(self) -> builtins.bool: ...
@@ -66,6 +72,10 @@ class InvestmentAssets:
     @property
     def strategy(self) -> builtins.str: ...
     @property
+    def dividends_received(self) -> builtins.str: ...
+    @property
+    def zakatable_ratio(self) -> builtins.str: ...
+    @property
     def liabilities_due_now(self) -> builtins.str: ...
     @property
     def hawl_satisfied(self) -> builtins.bool: ...
@@ -118,6 +128,10 @@ class PreciousMetals:
     @property
     def gender(self) -> builtins.str: ...
     @property
+    def numismatic_value(self) -> builtins.str: ...
+    @property
+    def numismatic_valuation(self) -> builtins.str: ...
+    @property
     def liabilities_due_now(self) -> builtins.str: ...
     @property
     def hawl_satisfied(self) -> builtins.bool: ...
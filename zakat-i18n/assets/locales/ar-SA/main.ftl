@@ -32,6 +32,7 @@ step-liabilities = الخصوم
 step-gross-assets = إجمالي الأصول
 step-short-term-liabilities = التزامات قصيرة الأجل
 step-debts-due-now = ديون مستحقة الآن
+step-liability = دين: { $label }
 step-net-business-assets = صافي أصول الأعمال
 step-cash-on-hand = النقد في اليد
 step-inventory-value = قيمة المخزون
@@ -50,3 +51,16 @@ camel-age-hiqqah = حقة
 camel-age-jazaah = جذعة
 cow-age-tabi = تبيع
 cow-age-musinnah = مسنة
+
+# Wealth Types
+wealth-type-fitrah = زكاة الفطر
+wealth-type-gold = الذهب
+wealth-type-silver = الفضة
+wealth-type-business = عروض التجارة
+wealth-type-agriculture = الزروع والثمار
+wealth-type-livestock = الأنعام
+wealth-type-income = الدخل
+wealth-type-investment = الاستثمارات
+wealth-type-mining = المعادن
+wealth-type-rikaz = الركاز (الكنز المدفون)
+wealth-type-other = أخرى
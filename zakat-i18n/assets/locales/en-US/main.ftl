@@ -4,6 +4,30 @@ status-payable = PAYABLE
 status-exempt = EXEMPT
 status-due = Amount Due
 
+# Recommendations
+recommendation-obligatory = Zakat is obligatory
+recommendation-sadaqah = Voluntary Sadaqah is recommended (near Nisab)
+recommendation-none = No Zakat due
+
+# Status Reasons
+reason-hawl-not-met = Hawl (1 lunar year) not met
+reason-count-zero = Count is zero
+reason-working-animals = Working animals (Awamil) are exempt
+reason-not-saimah = Not Sa'imah (naturally grazed)
+reason-jewelry-exempt = Exempt per Madhab (Huliyy al-Mubah)
+reason-aggregation-payable = Payable via Aggregation (Dam' al-Amwal)
+reason-net-negative = You are in debt (Net Assets Negative). Zakat is not due.
+
+# Calculation Warnings
+warning-negative-assets-clamped = Net assets were negative and clamped to zero.
+warning-gross-method-expenses-ignored = Expenses are ignored when using the Gross calculation method.
+warning-livestock-below-nisab = Livestock count is below the Nisab.
+warning-metal-below-nisab = Metal weight is below the Nisab.
+warning-price-data-stale = Price data may be stale or unavailable.
+warning-hawl-not-met = The Hawl (one lunar year) has not been completed.
+warning-partial-calculation = Calculation is partial because some data is missing.
+warning-currency-conversion-applied = A currency conversion was applied.
+
 # Asset Labels
 asset-gold = Gold
 asset-silver = Silver
@@ -26,12 +50,15 @@ step-multiply = Multiply
 step-result = Result
 step-nisab-check = Nisab Threshold Check
 step-rate-applied = Rate Applied
+step-hawl-proration = Hawl Proration
+step-income-stream = Income Stream: { $label }
 step-net-assets = Net Assets
 step-total-assets = Total Assets
 step-liabilities = Liabilities Due
 step-gross-assets = Gross Assets
 step-short-term-liabilities = Short-term Liabilities
 step-debts-due-now = Debts Due Now
+step-liability = Debt: { $label }
 step-net-business-assets = Net Business Assets
 step-cash-on-hand = Cash on Hand
 step-inventory-value = Inventory Value
@@ -55,12 +82,17 @@ cow-age-musinnah = Musinnah
 error-config-missing = Missing configuration field: { $field }.
 error-config-gold-positive = Gold price must be strictly positive (> 0).
 error-config-silver-positive = Silver price must be strictly positive (> 0).
+error-config-ratio-positive = Gold:silver ratio must be strictly positive (> 0).
+error-config-currency-mismatch = Prices are quoted in { $from } but the calculation uses { $to }.
+error-config-no-fx-rate = No exchange rate from { $from } to { $to }.
 error-invalid-input = Input must be valid.
 error-negative-value = Value must be non-negative.
 error-gold-price-required = Gold Price is required for this calculation.
 error-silver-price-required = Silver Price is required for this calculation.
 error-parse-json = Failed to parse JSON.
+error-serialize-json = Failed to serialize to JSON: { $details }.
 error-read-file = Failed to read file.
+error-storage = Storage error ({ $context }): { $error }.
 error-env-var-missing = Environment variable { $name } is missing.
 error-env-var-invalid = Environment variable { $name } has invalid format.
 error-input-too-long = Input exceeds maximum length of { $max }.
@@ -71,6 +103,7 @@ error-invalid-purity = Purity must be between 1 and 1000.
 error-gold-purity = Gold purity must be between 1 and 24.
 error-type-required = Type must be specified.
 error-type-invalid = Type must be valid.
+error-field-missing = Required field { $field } is missing.
 error-price-required = Price must be set.
 error-price-zero = Price for { $animal } must be greater than zero.
 error-division-zero = Division by zero.
@@ -82,7 +115,27 @@ error-fitrah-overflow = Overflow calculating Fitrah total.
 error-portfolio-incomplete = Portfolio calculation incomplete. { $failed }/{ $attempted } items failed.
 error-portfolio-failed = Portfolio calculation failed completely.
 error-asset-not-found = Asset with ID not found.
+error-csv-missing-field = CSV row { $row }: missing required field "{ $field }".
+error-csv-unknown-type = CSV row { $row }: unknown asset type "{ $details }".
+error-csv-parse = CSV row { $row }: could not read "{ $field }": { $details }.
+error-asset-index-out-of-range = No asset at this position; the portfolio has { $count } assets.
 error-prices-negative = Prices must be non-negative.
+error-hawl-unknown = Hawl status is unknown; confirm whether a lunar year has passed before calculating.
+error-prorated-months = Prorated Hawl cannot exceed 12 months.
+error-zakatable-ratio = Zakatable ratio must be between 0 and 1.
+error-inaccessible-fraction = Inaccessible fraction must be between 0 and 1.
+
+# Hints
+hint-set-prices = Set gold and silver prices (e.g. --gold-price / --silver-price).
+hint-check-config = Check the Zakat configuration.
+hint-set-missing-price = Set the missing price in the configuration.
+hint-set-required-config = Make sure all required configuration fields are set.
+hint-check-inputs = Make sure all input values are non-negative and correct.
+hint-check-network = Check your internet connection or the price API availability.
+hint-check-data = Check the input data for mistakes.
+
+# Reports
+report-summary-line = Zakat due: { $total } across { $assets } assets ({ $payable } payable)
 
 # CLI Prompts and Messages
 cli-title = ZAKAT CALCULATOR CLI
@@ -152,3 +205,16 @@ result-failed = FAILED
 result-pay-rate = Pay 2.5%
 result-sadaqah = Sadaqah
 
+
+# Wealth Types
+wealth-type-fitrah = Zakat al-Fitr
+wealth-type-gold = Gold
+wealth-type-silver = Silver
+wealth-type-business = Business
+wealth-type-agriculture = Agriculture
+wealth-type-livestock = Livestock
+wealth-type-income = Income
+wealth-type-investment = Investments
+wealth-type-mining = Mining
+wealth-type-rikaz = Rikaz (Buried Treasure)
+wealth-type-other = Other
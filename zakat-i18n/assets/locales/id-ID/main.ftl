@@ -4,6 +4,30 @@ status-payable = WAJIB ZAKAT
 status-exempt = TIDAK WAJIB
 status-due = Jumlah Wajib
 
+# Recommendations
+recommendation-obligatory = Zakat wajib dibayarkan
+recommendation-sadaqah = Sedekah sukarela dianjurkan (mendekati Nisab)
+recommendation-none = Tidak ada zakat yang wajib
+
+# Status Reasons
+reason-hawl-not-met = Haul (satu tahun hijriah) belum terpenuhi
+reason-count-zero = Jumlah hewan nol
+reason-working-animals = Hewan pekerja (Awamil) dikecualikan
+reason-not-saimah = Bukan Sa'imah (tidak digembalakan di padang rumput)
+reason-jewelry-exempt = Dikecualikan menurut mazhab (perhiasan yang dipakai)
+reason-aggregation-payable = Wajib melalui penggabungan harta (Dam' al-Amwal)
+reason-net-negative = Anda berutang (aset bersih negatif). Zakat tidak wajib.
+
+# Calculation Warnings
+warning-negative-assets-clamped = Aset bersih bernilai negatif dan dianggap nol.
+warning-gross-method-expenses-ignored = Pengeluaran diabaikan pada metode perhitungan bruto.
+warning-livestock-below-nisab = Jumlah hewan ternak di bawah Nisab.
+warning-metal-below-nisab = Berat logam di bawah Nisab.
+warning-price-data-stale = Data harga mungkin usang atau tidak tersedia.
+warning-hawl-not-met = Haul (satu tahun hijriah) belum terpenuhi.
+warning-partial-calculation = Perhitungan belum lengkap karena ada data yang hilang.
+warning-currency-conversion-applied = Konversi mata uang telah diterapkan.
+
 # Asset Labels
 asset-gold = Emas
 asset-silver = Perak
@@ -32,6 +56,7 @@ step-liabilities = Kewajiban
 step-gross-assets = Aset Kotor
 step-short-term-liabilities = Kewajiban Jangka Pendek
 step-debts-due-now = Hutang Jatuh Tempo
+step-liability = Hutang: { $label }
 step-net-business-assets = Aset Bisnis Bersih
 step-cash-on-hand = Uang Tunai
 step-inventory-value = Nilai Inventaris
@@ -50,3 +75,16 @@ camel-age-hiqqah = Hiqqah
 camel-age-jazaah = Jaza'ah
 cow-age-tabi = Tabi'
 cow-age-musinnah = Musinnah
+
+# Wealth Types
+wealth-type-fitrah = Zakat Fitrah
+wealth-type-gold = Emas
+wealth-type-silver = Perak
+wealth-type-business = Perniagaan
+wealth-type-agriculture = Pertanian
+wealth-type-livestock = Peternakan
+wealth-type-income = Penghasilan
+wealth-type-investment = Investasi
+wealth-type-mining = Pertambangan
+wealth-type-rikaz = Rikaz (Harta Karun)
+wealth-type-other = Lainnya
@@ -533,4 +533,23 @@ balance = Ihr Guthaben beträgt: { $amount }
         assert!(result.is_err());
         assert!(matches!(result, Err(TranslatorError::InvalidLocaleCode(_))));
     }
+
+    #[test]
+    fn test_every_locale_names_every_wealth_type() {
+        use zakat_core::types::{wealth_type_meta, WealthType};
+        let translator = Translator::new();
+        let all = [
+            WealthType::Fitrah, WealthType::Gold, WealthType::Silver, WealthType::Business,
+            WealthType::Agriculture, WealthType::Livestock, WealthType::Income, WealthType::Investment,
+            WealthType::Mining, WealthType::Rikaz, WealthType::Other(String::new()),
+        ];
+        for locale in [ZakatLocale::EnUS, ZakatLocale::IdID, ZakatLocale::ArSA] {
+            let bundle = &translator.bundles[&locale];
+            for wt in &all {
+                let key = wealth_type_meta(wt).display_name_key;
+                assert!(bundle.has_message(&key), "{:?} is missing {}", locale, key);
+            }
+        }
+        assert_eq!(translator.translate(ZakatLocale::IdID, "wealth-type-gold", None), "Emas");
+    }
 }
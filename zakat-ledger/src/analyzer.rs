@@ -2,12 +2,14 @@
 //!
 //! Analyzes a timeline to determine Zakat eligibility based on Hawl rules.
 
-use chrono::NaiveDate;
+use std::collections::{BTreeMap, HashMap};
+use chrono::{Datelike, NaiveDate};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
-use super::timeline::DailyBalance;
+use super::timeline::{DailyBalance, Timeline};
 use serde::{Deserialize, Serialize};
 use tracing::info;
+use zakat_core::config::ZakatConfig;
 
 /// Result of Hawl analysis on a ledger timeline.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -131,3 +133,173 @@ pub fn analyze_hawl(timeline: &[DailyBalance]) -> LedgerZakatResult {
     }
 }
 
+/// A suspicious jump in recorded balance, likely a data-entry error.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Anomaly {
+    /// Date on which the new balance was first recorded.
+    pub date: NaiveDate,
+    /// Balance before the change.
+    pub previous_balance: Decimal,
+    /// Balance after the change.
+    pub new_balance: Decimal,
+    /// Relative change versus the previous balance, in percent (e.g. `900` for 10x).
+    pub change_pct: Decimal,
+}
+
+/// Flags balance changes larger than `threshold_pct` percent versus the prior value.
+///
+/// Only days where the balance actually changes are compared. Changes from a
+/// zero balance are skipped, since any first deposit would otherwise be flagged.
+pub fn detect_anomalies(timeline: &[DailyBalance], threshold_pct: Decimal) -> Vec<Anomaly> {
+    let mut anomalies = Vec::new();
+
+    for pair in timeline.windows(2) {
+        let (prev, curr) = (&pair[0], &pair[1]);
+        if curr.balance == prev.balance || prev.balance.is_zero() {
+            continue;
+        }
+
+        let change_pct = (curr.balance - prev.balance).abs() / prev.balance.abs() * dec!(100);
+        if change_pct > threshold_pct {
+            info!(date = %curr.date, change_pct = %change_pct, "Balance anomaly detected");
+            anomalies.push(Anomaly {
+                date: curr.date,
+                previous_balance: prev.balance,
+                new_balance: curr.balance,
+                change_pct,
+            });
+        }
+    }
+
+    anomalies
+}
+
+/// Computes back-zakat per calendar year, scaled to today's money using a CPI table.
+///
+/// Each year's base is the closing balance of that year (if above Nisab). The base is
+/// multiplied by `cpi[latest] / cpi[year]`, where `latest` is the most recent year in
+/// the table, before applying the strategy's rate. Years missing from the table are
+/// left unadjusted.
+///
+/// This is an optional convenience for users who want inflation-aware figures; it is
+/// not a fiqh ruling. See [`crate::qada_inflation`] for the gold-standard method.
+pub fn back_zakat_adjusted(
+    timeline: &[DailyBalance],
+    config: &ZakatConfig,
+    cpi: &HashMap<i32, Decimal>,
+) -> Vec<(i32, Decimal)> {
+    let rate = config.strategy.get_rules().trade_goods_rate;
+    let reference_cpi = cpi.iter().max_by_key(|(year, _)| **year).map(|(_, value)| *value);
+
+    // Closing balance per year (timeline is chronological)
+    let mut closing: BTreeMap<i32, &DailyBalance> = BTreeMap::new();
+    for day in timeline {
+        closing.insert(day.date.year(), day);
+    }
+
+    closing
+        .into_iter()
+        .map(|(year, day)| {
+            if !day.is_above_nisab {
+                return (year, Decimal::ZERO);
+            }
+            let ratio = match (reference_cpi, cpi.get(&year)) {
+                (Some(reference), Some(past)) if !past.is_zero() => reference / past,
+                _ => Decimal::ONE,
+            };
+            (year, day.balance * ratio * rate)
+        })
+        .collect()
+}
+
+/// Lists assets whose latest valuation is more than `max_age_days` before `as_of`,
+/// as `(asset_label, last_valuation_date)`, longest-unvalued first.
+///
+/// Only valuation events ([`TransactionType::is_valuation`](crate::events::TransactionType::is_valuation))
+/// count; deposits and withdrawals move money without restating its value.
+/// Assets never valued have no date to report and are left out.
+pub fn stale_assets(timeline: &Timeline, as_of: NaiveDate, max_age_days: i64) -> Vec<(String, NaiveDate)> {
+    let mut last_valued: HashMap<uuid::Uuid, (&str, NaiveDate)> = HashMap::new();
+    for entry in timeline.entries.iter().filter(|e| e.transaction_type.is_valuation()) {
+        let last = last_valued.entry(entry.asset_id).or_insert((&entry.asset_label, entry.date));
+        last.1 = last.1.max(entry.date);
+    }
+
+    let mut stale: Vec<(String, NaiveDate)> = last_valued
+        .into_values()
+        .filter(|(_, date)| (as_of - *date).num_days() > max_age_days)
+        .map(|(label, date)| (label.to_string(), date))
+        .collect();
+    stale.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    stale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(y: i32, m: u32, d: u32, balance: Decimal) -> DailyBalance {
+        DailyBalance {
+            date: NaiveDate::from_ymd_opt(y, m, d).unwrap(),
+            balance,
+            nisab_threshold: dec!(5000),
+            is_above_nisab: balance >= dec!(5000),
+        }
+    }
+
+    #[test]
+    fn test_detect_anomalies_flags_tenfold_jump() {
+        let timeline = vec![
+            day(2024, 1, 1, dec!(1000)),
+            day(2024, 1, 2, dec!(1000)),
+            day(2024, 1, 3, dec!(1500)),
+            day(2024, 1, 4, dec!(15000)),
+        ];
+
+        let anomalies = detect_anomalies(&timeline, dec!(200));
+
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].date, NaiveDate::from_ymd_opt(2024, 1, 4).unwrap());
+        assert_eq!(anomalies[0].change_pct, dec!(900));
+    }
+
+    #[test]
+    fn test_back_zakat_adjusted_scales_by_cpi() {
+        let timeline = vec![
+            day(2023, 6, 1, dec!(2000)),
+            day(2023, 12, 31, dec!(10000)),
+            day(2024, 12, 31, dec!(10000)),
+        ];
+        let cpi = HashMap::from([(2023, dec!(100)), (2024, dec!(110))]);
+
+        let adjusted = back_zakat_adjusted(&timeline, &ZakatConfig::default(), &cpi);
+
+        // 2023: 10000 * (110 / 100) * 2.5% = 275; 2024 is the reference year.
+        assert_eq!(adjusted, vec![(2023, dec!(275)), (2024, dec!(250))]);
+    }
+
+    #[test]
+    fn test_stale_assets_lists_only_outdated_valuations() {
+        use crate::assets::LedgerAsset;
+        use crate::events::{LedgerEvent, TransactionType};
+        use zakat_core::types::WealthType;
+
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let event = |on, kind| LedgerEvent::new(on, dec!(1000), WealthType::Business, kind, None);
+
+        let fresh = LedgerAsset::new("Gold", date(1, 1), date(12, 31))
+            .with_event(event(date(1, 1), TransactionType::Deposit))
+            .with_event(event(date(11, 20), TransactionType::Profit));
+        // A recent deposit is not a revaluation, so the shares are still stale.
+        let stale = LedgerAsset::new("Shares", date(1, 1), date(12, 31))
+            .with_event(event(date(3, 15), TransactionType::Loss))
+            .with_event(event(date(11, 25), TransactionType::Deposit));
+        let never_valued = LedgerAsset::new("Wallet", date(1, 1), date(12, 31))
+            .with_event(event(date(1, 1), TransactionType::Deposit));
+        let timeline = Timeline::from_asset(&fresh).with_asset(&stale).with_asset(&never_valued);
+
+        let reminders = stale_assets(&timeline, date(12, 1), 90);
+
+        assert_eq!(reminders, vec![("Shares".to_string(), date(3, 15))]);
+    }
+}
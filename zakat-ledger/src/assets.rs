@@ -9,7 +9,7 @@ use crate::analyzer::analyze_hawl;
 use zakat_core::types::{ZakatDetails, WealthType, ZakatError, CalculationStep};
 use zakat_core::traits::{CalculateZakat, ZakatConfigArgument};
 use rust_decimal::Decimal;
-use rust_decimal_macros::dec;
+use zakat_core::math::ZakatDecimal;
 use uuid::Uuid;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
@@ -50,7 +50,10 @@ impl LedgerAsset {
 }
 
 impl CalculateZakat for LedgerAsset {
-    fn calculate_zakat<C: ZakatConfigArgument>(&self, _config: C) -> Result<ZakatDetails, ZakatError> {
+    fn calculate_zakat<C: ZakatConfigArgument>(&self, config: C) -> Result<ZakatDetails, ZakatError> {
+        let config_cow = config.resolve_config();
+        let rate = self.zakat_rate(config_cow.as_ref());
+
         // Run simulation
         let timeline = simulate_timeline(self.events.clone(), &self.prices, self.start_date, self.end_date, None)?;
         
@@ -70,7 +73,7 @@ impl CalculateZakat for LedgerAsset {
         
         if result.is_due {
              final_trace.push(CalculationStep::info("info-hawl-met", format!("Hawl Met: {} days held since {}", result.current_streak_days, result.hawl_start_date.map(|d| d.to_string()).unwrap_or_default())));
-             final_trace.push(CalculationStep::rate("step-rate", "Zakat Rate", dec!(0.025)));
+             final_trace.push(CalculationStep::rate("step-rate", "Zakat Rate", rate));
              final_trace.push(CalculationStep::result("step-due", "Zakat Due", result.zakat_due));
         } else {
              if let Some(breach) = result.last_breach {
@@ -85,14 +88,18 @@ impl CalculateZakat for LedgerAsset {
             result.total_balance,
             Decimal::ZERO,
             final_nisab,
-            dec!(0.025),
+            rate,
             wealth_type.clone(),
             final_trace
         ).with_label(self.label.clone());
         
         // Force the payable status from analyzer results
         detailed_details.is_payable = result.is_due && result.total_balance >= final_nisab;
-        detailed_details.zakat_due = if detailed_details.is_payable { result.zakat_due } else { Decimal::ZERO };
+        detailed_details.zakat_due = if detailed_details.is_payable {
+            *ZakatDecimal::new(result.total_balance).checked_mul(rate)?
+        } else {
+            Decimal::ZERO
+        };
         if !detailed_details.is_payable {
             detailed_details.status_reason = Some(format!("Hawl not met: {}/354 days", result.current_streak_days));
         }
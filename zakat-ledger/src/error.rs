@@ -0,0 +1,18 @@
+//! Ledger Errors
+//!
+//! Errors raised while importing or exporting ledger data.
+
+use thiserror::Error;
+
+/// Error type for ledger import operations.
+#[derive(Debug, Error)]
+pub enum LedgerError {
+    /// The underlying reader failed; the import cannot continue past this line.
+    #[error("I/O error while reading line {line_number}: {source}")]
+    Io {
+        /// 1-based line number that could not be read.
+        line_number: usize,
+        #[source]
+        source: std::io::Error,
+    },
+}
@@ -20,6 +20,14 @@ pub enum TransactionType {
     Loss,
 }
 
+impl TransactionType {
+    /// True for events that restate the asset's market value (`Profit`, `Loss`)
+    /// rather than move money in or out of it.
+    pub fn is_valuation(&self) -> bool {
+        matches!(self, TransactionType::Profit | TransactionType::Loss)
+    }
+}
+
 /// A single event in the ledger representing a financial transaction.
 #[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
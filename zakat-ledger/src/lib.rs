@@ -10,6 +10,7 @@
 //! - Hawl analysis and tracking
 //! - Support for ledger-based Zakat calculations
 
+pub mod error;
 pub mod events;
 pub mod pricing;
 pub mod timeline;
@@ -20,10 +21,11 @@ pub mod qada;
 pub mod qada_inflation;
 
 // Re-exports for convenience
+pub use error::LedgerError;
 pub use events::{LedgerEvent, TransactionType, EventStream};
 pub use pricing::{HistoricalPriceProvider, InMemoryPriceHistory};
-pub use timeline::{DailyBalance, simulate_timeline};
-pub use analyzer::{LedgerZakatResult, analyze_hawl};
+pub use timeline::{DailyBalance, MalformedLine, Timeline, TimelineEntry, simulate_timeline};
+pub use analyzer::{LedgerZakatResult, Anomaly, analyze_hawl, back_zakat_adjusted, detect_anomalies};
 pub use assets::LedgerAsset;
 pub use hawl::HawlTracker;
 pub use qada::{QadaCalculator, QadaYearResult, QadaReport};
@@ -143,8 +143,8 @@ mod tests {
         // Setup mock prices:
         // Year 1 (2010): Gold $40/g, Silver $0.6/g (Nisab Gold (~$3400), Silver (~$357))
         // Year 2 (2011): Gold $50/g, Silver $1.0/g (Nisab Gold (~$4250), Silver (~$595))
-        let p1 = Prices { gold_per_gram: dec!(40.0), silver_per_gram: dec!(0.6) };
-        let p2 = Prices { gold_per_gram: dec!(50.0), silver_per_gram: dec!(1.0) };
+        let p1 = Prices { gold_per_gram: dec!(40.0), silver_per_gram: dec!(0.6), ..Default::default() };
+        let p2 = Prices { gold_per_gram: dec!(50.0), silver_per_gram: dec!(1.0), ..Default::default() };
         
         let provider = StaticHistoricalPriceProvider::new()
             .with_price(NaiveDate::from_ymd_opt(2010, 8, 11).unwrap(), p1)
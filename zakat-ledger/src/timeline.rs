@@ -2,11 +2,15 @@
 //!
 //! Simulates daily balances based on ledger events and price changes.
 
-use super::events::LedgerEvent;
+use super::assets::LedgerAsset;
+use super::error::LedgerError;
+use super::events::{LedgerEvent, TransactionType};
 use super::pricing::HistoricalPriceProvider;
 use chrono::{NaiveDate, Duration};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use tracing::warn;
+use uuid::Uuid;
 
 use zakat_core::types::{ZakatError, InvalidInputDetails};
 
@@ -33,7 +37,128 @@ impl LedgerSnapshot {
     }
 }
 
+/// One ledger event attributed to the asset it belongs to.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineEntry {
+    pub date: NaiveDate,
+    pub asset_id: Uuid,
+    #[serde(default)]
+    pub asset_label: String,
+    pub transaction_type: TransactionType,
+    pub amount: Decimal,
+    pub description: Option<String>,
+}
+
+/// A line from an imported JSONL timeline that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MalformedLine {
+    /// 1-based line number in the source.
+    pub line_number: usize,
+    /// Parser error message.
+    pub error: String,
+}
+
+/// A date-ordered list of wealth events across one or more ledger assets,
+/// for export and spreadsheet analysis.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Timeline {
+    pub entries: Vec<TimelineEntry>,
+    /// Lines skipped by [`Timeline::from_jsonl_reader`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub malformed_lines: Vec<MalformedLine>,
+}
+
+impl Timeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a timeline from the events of a single asset.
+    pub fn from_asset(asset: &LedgerAsset) -> Self {
+        Self::new().with_asset(asset)
+    }
+
+    /// Adds an asset's events, keeping entries sorted by date (stable for same-day events).
+    pub fn with_asset(mut self, asset: &LedgerAsset) -> Self {
+        self.entries.extend(asset.events.iter().map(|event| TimelineEntry {
+            date: event.date,
+            asset_id: asset.id,
+            asset_label: asset.label.clone(),
+            transaction_type: event.transaction_type,
+            amount: event.amount,
+            description: event.description.clone(),
+        }));
+        self.entries.sort_by_key(|entry| entry.date);
+        self
+    }
+
+    /// Parses a timeline incrementally from a JSON Lines reader, one entry per line.
+    ///
+    /// Blank lines are ignored. Lines that fail to deserialize are skipped and
+    /// recorded in `malformed_lines`, so one corrupt entry does not discard a
+    /// multi-megabyte log; only read failures return an error.
+    pub fn from_jsonl_reader<R: std::io::BufRead>(reader: R) -> Result<Timeline, LedgerError> {
+        let mut timeline = Self::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.map_err(|source| LedgerError::Io { line_number, source })?;
+
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            match serde_json::from_str::<TimelineEntry>(trimmed) {
+                Ok(entry) => timeline.entries.push(entry),
+                Err(e) => {
+                    warn!(line = line_number, error = %e, "Skipping malformed timeline entry");
+                    timeline.malformed_lines.push(MalformedLine {
+                        line_number,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
 
+        timeline.entries.sort_by_key(|entry| entry.date);
+        Ok(timeline)
+    }
+
+    /// Renders the timeline as CSV with the header
+    /// `date,asset_id,event_type,amount,description`.
+    ///
+    /// Dates are ISO-8601; descriptions are quoted when they contain commas,
+    /// quotes, or line breaks.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("date,asset_id,event_type,amount,description\n");
+        for entry in &self.entries {
+            csv.push_str(&format!(
+                "{},{},{},{},{}\n",
+                entry.date,
+                entry.asset_id,
+                event_type_name(entry.transaction_type),
+                entry.amount,
+                zakat_core::csv::escape_field(entry.description.as_deref().unwrap_or("")),
+            ));
+        }
+        csv
+    }
+}
+
+fn event_type_name(transaction_type: TransactionType) -> &'static str {
+    match transaction_type {
+        TransactionType::Deposit => "deposit",
+        TransactionType::Withdrawal => "withdrawal",
+        TransactionType::Income => "income",
+        TransactionType::Expense => "expense",
+        TransactionType::Profit => "profit",
+        TransactionType::Loss => "loss",
+    }
+}
 
 /// Simulates a timeline of daily balances from ledger events.
 ///
@@ -198,7 +323,6 @@ pub fn simulate_timeline<P: HistoricalPriceProvider>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use super::super::events::TransactionType;
     use super::super::pricing::InMemoryPriceHistory;
     use rust_decimal_macros::dec;
     use zakat_core::types::WealthType;
@@ -236,4 +360,39 @@ mod tests {
         let days_below = timeline.iter().filter(|d| !d.is_above_nisab).count();
         assert_eq!(days_below, 4);
     }
+
+    #[test]
+    fn test_timeline_to_csv() {
+        let jan = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let mar = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let asset = crate::assets::LedgerAsset::new("Savings", jan, mar)
+            .with_event(LedgerEvent::new(mar, dec!(250.50), WealthType::Business, TransactionType::Withdrawal, Some("Rent, March".to_string())))
+            .with_event(LedgerEvent::new(jan, dec!(10000), WealthType::Business, TransactionType::Deposit, Some("Salary".to_string())));
+
+        let csv = Timeline::from_asset(&asset).to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "date,asset_id,event_type,amount,description");
+        assert_eq!(lines[1], format!("2024-01-15,{},deposit,10000,Salary", asset.id));
+        assert_eq!(lines[2], format!("2024-03-01,{},withdrawal,250.50,\"Rent, March\"", asset.id));
+        assert_eq!(lines.len(), 3);
+    }
+
+    const JSONL_FIXTURE: &str = r#"{"date":"2024-03-01","assetId":"6f1c1f4e-1b2a-4c55-9d43-0c1f5b7a9e01","transactionType":"withdrawal","amount":"250","description":"Rent"}
+{"date":"2024-02-01","assetId":"6f1c1f4e-1b2a-4c55-9d43-0c1f5b7a9e01","transactionType":"deposit","amount":"oops"
+
+{"date":"2024-01-01","assetId":"6f1c1f4e-1b2a-4c55-9d43-0c1f5b7a9e01","transactionType":"deposit","amount":"1000","description":null}
+"#;
+
+    #[test]
+    fn test_from_jsonl_reader_reports_bad_lines() {
+        let timeline = Timeline::from_jsonl_reader(std::io::Cursor::new(JSONL_FIXTURE)).unwrap();
+
+        assert_eq!(timeline.entries.len(), 2);
+        assert_eq!(timeline.entries[0].amount, dec!(1000));
+        assert_eq!(timeline.entries[1].transaction_type, TransactionType::Withdrawal);
+
+        assert_eq!(timeline.malformed_lines.len(), 1);
+        assert_eq!(timeline.malformed_lines[0].line_number, 2);
+    }
 }
@@ -0,0 +1,29 @@
+//! Price cache benchmarks.
+//!
+//! Run with `cargo bench -p zakat-providers --bench price_cache`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use zakat_providers::{CachedPriceProvider, PriceProvider, StaticPriceProvider};
+
+fn bench_cached_provider(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().expect("tokio runtime");
+    let mut group = c.benchmark_group("cached_price_provider");
+
+    // Warm cache with a long TTL: every call after the first is a hit.
+    let hit = CachedPriceProvider::new(StaticPriceProvider::new(85, 1).unwrap(), 3600);
+    runtime.block_on(hit.get_prices()).unwrap();
+    group.bench_function("hit", |b| {
+        b.iter(|| black_box(runtime.block_on(hit.get_prices()).unwrap()))
+    });
+
+    // Zero TTL: every call misses and goes to the inner provider.
+    let miss = CachedPriceProvider::new(StaticPriceProvider::new(85, 1).unwrap(), 0);
+    group.bench_function("miss", |b| {
+        b.iter(|| black_box(runtime.block_on(miss.get_prices()).unwrap()))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_cached_provider);
+criterion_main!(benches);
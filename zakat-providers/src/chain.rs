@@ -1,6 +1,6 @@
 //! Chain of Responsibility for Price Providers
 
-use crate::pricing::{PriceProvider, Prices};
+use crate::pricing::{PriceProvider, PricedQuote, Prices};
 use zakat_core::types::{ZakatError, ErrorDetails};
 use async_trait::async_trait;
 
@@ -27,6 +27,10 @@ impl MultiSourcePriceProvider {
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl PriceProvider for MultiSourcePriceProvider {
     async fn get_prices(&self) -> Result<Prices, ZakatError> {
+        self.get_quote().await.map(|quote| quote.prices)
+    }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
         if self.providers.is_empty() {
              return Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
                 code: zakat_core::types::ZakatErrorCode::ConfigError,
@@ -40,8 +44,8 @@ impl PriceProvider for MultiSourcePriceProvider {
         let mut last_error = None;
 
         for (idx, provider) in self.providers.iter().enumerate() {
-            match provider.get_prices().await {
-                Ok(prices) => return Ok(prices),
+            match provider.get_quote().await {
+                Ok(quote) => return Ok(quote),
                 Err(e) => {
                     tracing::warn!(
                         "Provider {} ({}) failed: {}. Trying next...", 
@@ -54,7 +58,7 @@ impl PriceProvider for MultiSourcePriceProvider {
             }
         }
 
-        Err(last_error.unwrap_or_else(|| ZakatError::NetworkError("All providers failed".to_string())))
+        Err(last_error.unwrap_or_else(|| ZakatError::network("All providers failed".to_string())))
     }
 
     fn name(&self) -> &str {
@@ -63,23 +67,30 @@ impl PriceProvider for MultiSourcePriceProvider {
 }
 
 /// GoldApi.io Provider (Skeleton)
+#[deprecated(note = "Use `GoldApiPriceProvider`, which fetches live gold and silver prices")]
 pub struct GoldApiProvider {
     api_key: String,
 }
 
+#[allow(deprecated)]
 impl GoldApiProvider {
     pub fn new(api_key: String) -> Self {
         Self { api_key }
     }
 }
 
+#[allow(deprecated)]
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl PriceProvider for GoldApiProvider {
     async fn get_prices(&self) -> Result<Prices, ZakatError> {
         // Mock implementation
         tracing::info!("Mock fetching from GoldApi with key: {}", self.api_key);
-        Err(ZakatError::NetworkError("GoldAPI not implemented yet".to_string()))
+        Err(ZakatError::NetworkError {
+            message: "GoldAPI not implemented yet".to_string(),
+            status: None,
+            retryable: false,
+        })
     }
 
     fn name(&self) -> &str {
@@ -104,7 +115,11 @@ impl PriceProvider for MetalPriceProvider {
     async fn get_prices(&self) -> Result<Prices, ZakatError> {
         // Mock implementation
         tracing::info!("Mock fetching from MetalPriceAPI with key: {}", self.api_key);
-        Err(ZakatError::NetworkError("MetalPriceAPI not implemented yet".to_string()))
+        Err(ZakatError::NetworkError {
+            message: "MetalPriceAPI not implemented yet".to_string(),
+            status: None,
+            retryable: false,
+        })
     }
 
     fn name(&self) -> &str {
@@ -129,11 +144,12 @@ mod tests {
     impl PriceProvider for MockProvider {
         async fn get_prices(&self) -> Result<Prices, ZakatError> {
             if self.should_fail {
-                Err(ZakatError::NetworkError(format!("{} failed", self.name)))
+                Err(ZakatError::network(format!("{} failed", self.name)))
             } else {
                 Ok(Prices {
                     gold_per_gram: self.price,
                     silver_per_gram: Decimal::ZERO,
+                    ..Default::default()
                 })
             }
         }
@@ -1,63 +1,128 @@
 //! File System Cache for Offline Support
 
 #[cfg(not(target_arch = "wasm32"))]
-use crate::pricing::{PriceProvider, Prices};
+use crate::pricing::{PriceProvider, PricedQuote, Prices};
 #[cfg(not(target_arch = "wasm32"))]
 use zakat_core::types::ZakatError;
 #[cfg(not(target_arch = "wasm32"))]
 use async_trait::async_trait;
 #[cfg(not(target_arch = "wasm32"))]
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 #[cfg(not(target_arch = "wasm32"))]
 use serde::{Serialize, Deserialize};
+#[cfg(not(target_arch = "wasm32"))]
+use rust_decimal::Decimal;
+
+/// Decimal places kept for cached prices, so fresh and cached values compare cleanly.
+#[cfg(not(target_arch = "wasm32"))]
+const CACHE_PRICE_SCALE: u32 = 4;
 
 #[cfg(not(target_arch = "wasm32"))]
 #[derive(Serialize, Deserialize)]
 struct CachedData {
     timestamp: u64,
     prices: Prices,
+    /// FX rate already applied to `prices`; absent in caches written before it was stored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    base_to_local: Option<Decimal>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn default_cache_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|mut p| {
+        p.push(".zakat");
+        p.push("prices.json");
+        p
+    })
+}
+
+/// Reads a cache file. Missing or corrupt files read as `None`.
+#[cfg(not(target_arch = "wasm32"))]
+fn read_cache(path: &Path) -> Option<CachedData> {
+    if !path.exists() { return None; }
+
+    let file = std::fs::File::open(path).ok()?;
+    let reader = std::io::BufReader::new(file);
+    match serde_json::from_reader(reader) {
+        Ok(cached) => Some(cached),
+        Err(e) => {
+            tracing::warn!("Ignoring unreadable price cache {:?}: {}", path, e);
+            None
+        }
+    }
 }
 
+/// Returns the prices last written by [`FileSystemPriceCache`], however old.
+///
+/// Useful as the "previous" side of [`Prices::delta`] before a fresh fetch.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn last_cached_prices() -> Option<Prices> {
+    read_cache(&default_cache_path()?).map(|cached| cached.prices.normalized(CACHE_PRICE_SCALE))
+}
+
+/// Wraps a provider and keeps its last prices in `~/.zakat/prices.json` for `ttl`.
+///
+/// The file stores the fetch time alongside the prices; a missing, expired or
+/// corrupt file falls through to the inner provider.
 #[cfg(not(target_arch = "wasm32"))]
 pub struct FileSystemPriceCache<P> {
     inner: P,
     ttl: Duration,
+    path: Option<PathBuf>,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 impl<P> FileSystemPriceCache<P> {
     pub fn new(inner: P, ttl: Duration) -> Self {
-        Self { inner, ttl }
+        Self { inner, ttl, path: default_cache_path() }
     }
 
-    fn get_cache_path() -> Option<PathBuf> {
-        dirs::home_dir().map(|mut p| {
-            p.push(".zakat");
-            p.push("prices.json");
-            p
-        })
+    /// Stores the cache at `path` instead of `~/.zakat/prices.json`.
+    pub fn with_cache_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
     }
 
-    fn load_cache_with_ttl(&self) -> Option<Prices> {
-        let path = Self::get_cache_path()?;
-        if !path.exists() { return None; }
+    /// When the cached prices were fetched, or `None` if there is no readable cache.
+    pub fn cached_at(&self) -> Option<SystemTime> {
+        let cached = read_cache(self.path.as_deref()?)?;
+        Some(UNIX_EPOCH + Duration::from_secs(cached.timestamp))
+    }
 
-        let file = std::fs::File::open(&path).ok()?;
-        let reader = std::io::BufReader::new(file);
-        let cached: CachedData = serde_json::from_reader(reader).ok()?;
+    /// True when there is no readable cache or it is older than the TTL,
+    /// i.e. the next `get_prices` call will hit the inner provider.
+    pub fn is_stale(&self) -> bool {
+        match self.cached_at().and_then(|at| SystemTime::now().duration_since(at).ok()) {
+            Some(age) => age > self.ttl,
+            None => true,
+        }
+    }
 
-        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
-        if now.saturating_sub(cached.timestamp) > self.ttl.as_secs() {
-            return None; // Expired
+    /// Deletes the cache file so the next `get_prices` call fetches fresh prices.
+    pub fn invalidate(&self) {
+        if let Some(path) = &self.path
+            && path.exists()
+            && let Err(e) = std::fs::remove_file(path)
+        {
+            tracing::warn!("Could not remove price cache {:?}: {}", path, e);
         }
+    }
 
-        Some(cached.prices)
+    fn load_cache_with_ttl(&self) -> Option<PricedQuote> {
+        if self.is_stale() {
+            return None; // Missing or expired
+        }
+        let cached = read_cache(self.path.as_deref()?)?;
+        Some(PricedQuote {
+            prices: cached.prices.normalized(CACHE_PRICE_SCALE),
+            base_to_local: cached.base_to_local,
+        })
     }
 
-    fn save_cache(prices: &Prices) {
-        let Some(path) = Self::get_cache_path() else { return };
+    fn save_cache(&self, quote: &PricedQuote) {
+        let Some(path) = &self.path else { return };
         
         // Ensure directory exists
         if let Some(parent) = path.parent() {
@@ -67,10 +132,11 @@ impl<P> FileSystemPriceCache<P> {
         let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
         let data = CachedData {
             timestamp: now,
-            prices: prices.clone(),
+            prices: quote.prices.normalized(CACHE_PRICE_SCALE),
+            base_to_local: quote.base_to_local,
         };
 
-        if let Ok(file) = std::fs::File::create(&path) {
+        if let Ok(file) = std::fs::File::create(path) {
             let _ = serde_json::to_writer(file, &data);
         }
     }
@@ -80,18 +146,22 @@ impl<P> FileSystemPriceCache<P> {
 #[async_trait]
 impl<P: PriceProvider + Send + Sync> PriceProvider for FileSystemPriceCache<P> {
     async fn get_prices(&self) -> Result<Prices, ZakatError> {
+        self.get_quote().await.map(|quote| quote.prices)
+    }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
         // 1. Try Cache
-        if let Some(prices) = self.load_cache_with_ttl() {
+        if let Some(quote) = self.load_cache_with_ttl() {
             tracing::info!("Loaded prices from local file cache");
-            return Ok(prices);
+            return Ok(quote);
         }
 
         // 2. Try Inner
-        match self.inner.get_prices().await {
-            Ok(prices) => {
+        match self.inner.get_quote().await {
+            Ok(quote) => {
                 // 3. Save on success
-                Self::save_cache(&prices);
-                Ok(prices)
+                self.save_cache(&quote);
+                Ok(quote)
             }
             Err(e) => {
                 // 4. If inner fails, maybe return expired cache?
@@ -112,3 +182,30 @@ impl<P: PriceProvider + Send + Sync> PriceProvider for FileSystemPriceCache<P> {
         "FileSystemPriceCache"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pricing::StaticPriceProvider;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_corrupt_cache_falls_back_and_can_be_invalidated() {
+        let path = std::env::temp_dir().join(format!("zakat_prices_{}.json", std::process::id()));
+        std::fs::write(&path, "{ not json").unwrap();
+
+        let cache = FileSystemPriceCache::new(StaticPriceProvider::new(dec!(90), dec!(1)).unwrap(), Duration::from_secs(3600))
+            .with_cache_path(&path);
+        assert!(cache.is_stale(), "a corrupt file counts as no cache");
+        assert_eq!(cache.cached_at(), None);
+
+        let prices = cache.get_prices().await.unwrap();
+        assert_eq!(prices.gold_per_gram, dec!(90));
+        assert!(cache.cached_at().is_some(), "the fetch is written back with its timestamp");
+        assert!(!cache.is_stale());
+
+        cache.invalidate();
+        assert!(cache.is_stale());
+        assert!(!path.exists());
+    }
+}
@@ -6,14 +6,19 @@
 //! ## Features
 //!
 //! - `live-pricing` - Enable live API providers (e.g., Binance)
+//! - `async` - Enable `PriceSubscription` streams of periodic price updates
 //! - `force-dns-bypass` - Force use of hardcoded IPs for restricted networks
 
 mod pricing;
 mod chain;
 #[cfg(not(target_arch = "wasm32"))]
 mod fs_cache;
+#[cfg(all(feature = "async", not(target_arch = "wasm32")))]
+mod subscription;
 
 pub use pricing::*;
 pub use chain::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use fs_cache::*;
+#[cfg(all(feature = "async", not(target_arch = "wasm32")))]
+pub use subscription::*;
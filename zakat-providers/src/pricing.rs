@@ -19,18 +19,36 @@ use web_time::{Duration, Instant};
 
 use zakat_core::types::{ZakatError, InvalidInputDetails, ErrorDetails};
 use zakat_core::inputs::IntoZakatDecimal;
+use zakat_core::config::ZakatConfig;
 
 /// Represents current market prices for metals used in Zakat calculations.
-#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Prices {
-    /// Gold price per gram in local currency.
+    /// Gold price per gram in `currency`.
     pub gold_per_gram: Decimal,
-    /// Silver price per gram in local currency.
+    /// Silver price per gram in `currency`.
     pub silver_per_gram: Decimal,
+    /// ISO currency code the prices are quoted in (e.g. "USD", "IDR").
+    #[serde(default = "default_price_currency")]
+    pub currency: String,
+}
+
+fn default_price_currency() -> String {
+    "USD".to_string()
+}
+
+impl Default for Prices {
+    fn default() -> Self {
+        Self {
+            gold_per_gram: Decimal::ZERO,
+            silver_per_gram: Decimal::ZERO,
+            currency: default_price_currency(),
+        }
+    }
 }
 
 impl Prices {
-    /// Creates a new Prices instance.
+    /// Creates a new Prices instance quoted in USD; see [`Prices::with_currency`].
     pub fn new(
         gold_per_gram: impl IntoZakatDecimal,
         silver_per_gram: impl IntoZakatDecimal,
@@ -51,6 +69,158 @@ impl Prices {
         Ok(Self {
             gold_per_gram: gold,
             silver_per_gram: silver,
+            currency: default_price_currency(),
+        })
+    }
+
+    /// Labels these prices as quoted in `code`, without converting them.
+    pub fn with_currency(mut self, code: impl Into<String>) -> Self {
+        self.currency = code.into();
+        self
+    }
+
+    /// Applies these prices to `config`, recording their currency.
+    ///
+    /// If `config` uses a different currency, its validation fails until the
+    /// prices are converted (see `ZakatConfig::convert_prices`).
+    pub fn apply_to(&self, config: ZakatConfig) -> ZakatConfig {
+        config
+            .with_gold_price(self.gold_per_gram)
+            .with_silver_price(self.silver_per_gram)
+            .with_price_currency(self.currency.clone())
+    }
+
+    /// Plausible, non-zero prices for examples, tests and last-resort fallbacks.
+    ///
+    /// **Not real market data.** Gold is 85/g and silver 1/g, chosen so Nisab
+    /// figures are easy to follow. `Prices::default()` is all zeros, which makes
+    /// every asset look exempt; use this instead when no live source is available.
+    pub fn demo() -> Self {
+        Self {
+            gold_per_gram: Decimal::from(85),
+            silver_per_gram: Decimal::ONE,
+            currency: default_price_currency(),
+        }
+    }
+
+    /// Returns a copy with both metal prices rounded to `scale` decimal places.
+    ///
+    /// Providers report prices at different scales (`65.1` vs `65.1000`);
+    /// normalizing gives identical serialized and displayed values.
+    pub fn normalized(&self, scale: u32) -> Prices {
+        use rust_decimal::RoundingStrategy;
+        let normalize = |value: Decimal| {
+            let mut rounded = value.round_dp_with_strategy(scale, RoundingStrategy::MidpointAwayFromZero);
+            rounded.rescale(scale);
+            rounded
+        };
+        Prices {
+            gold_per_gram: normalize(self.gold_per_gram),
+            silver_per_gram: normalize(self.silver_per_gram),
+            currency: self.currency.clone(),
+        }
+    }
+
+    /// Percentage change of each metal price since `previous`.
+    ///
+    /// A metal whose previous price is zero has no meaningful change and
+    /// reports `None`. Prices quoted in different currencies cannot be
+    /// compared, so the whole delta is `None` when the currencies differ.
+    pub fn delta(&self, previous: &Prices) -> Option<PriceDelta> {
+        if self.currency != previous.currency {
+            return None;
+        }
+        let pct = |current: Decimal, before: Decimal| {
+            if before.is_zero() {
+                return None;
+            }
+            (current - before)
+                .checked_div(before)
+                .and_then(|ratio| ratio.checked_mul(Decimal::ONE_HUNDRED))
+        };
+        Some(PriceDelta {
+            gold_pct: pct(self.gold_per_gram, previous.gold_per_gram),
+            silver_pct: pct(self.silver_per_gram, previous.silver_per_gram),
+        })
+    }
+}
+
+/// Percentage change between two [`Prices`], from [`Prices::delta`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PriceDelta {
+    /// Gold change in percent (e.g. `2` for +2%), `None` if there was no previous price.
+    pub gold_pct: Option<Decimal>,
+    /// Silver change in percent, `None` if there was no previous price.
+    pub silver_pct: Option<Decimal>,
+}
+
+/// Metal prices together with the currency they are quoted in.
+///
+/// Providers such as Binance quote in USD(T); `base_to_local` records the FX
+/// rate applied when the quote was converted into the user's local currency.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PricedQuote {
+    /// Prices per gram, labelled with their currency in `prices.currency`.
+    pub prices: Prices,
+    /// Base-to-local FX rate already applied to `prices`, if converted.
+    pub base_to_local: Option<Decimal>,
+}
+
+impl PricedQuote {
+    /// Wraps prices quoted directly in `currency_code`.
+    pub fn new(prices: Prices, currency_code: impl Into<String>) -> Self {
+        Self {
+            prices: prices.with_currency(currency_code),
+            base_to_local: None,
+        }
+    }
+
+    /// ISO currency code of the quoted prices (e.g. "USD", "IDR").
+    pub fn currency_code(&self) -> &str {
+        &self.prices.currency
+    }
+}
+
+impl Prices {
+    /// Converts these prices into another currency using a base-to-local FX rate.
+    ///
+    /// For example, USD prices with `rate = 16_000` and `new_code = "IDR"` yield
+    /// per-gram prices in Rupiah. The rate must be positive.
+    pub fn convert_to(&self, rate: impl IntoZakatDecimal, new_code: &str) -> Result<Prices, ZakatError> {
+        self.in_currency(rate, new_code).map(|quote| quote.prices)
+    }
+
+    /// Like [`Prices::convert_to`], but keeps the applied rate in a [`PricedQuote`].
+    pub fn in_currency(
+        &self,
+        rate: impl IntoZakatDecimal,
+        code: impl Into<String>,
+    ) -> Result<PricedQuote, ZakatError> {
+        let rate = rate.into_zakat_decimal()?;
+        let code = code.into();
+        if rate <= Decimal::ZERO {
+            return Err(ZakatError::InvalidInput(Box::new(InvalidInputDetails {
+                field: "base_to_local".to_string(),
+                value: rate.to_string(),
+                reason_key: "error-prices-negative".to_string(),
+                suggestion: Some("The exchange rate must be a positive value.".to_string()),
+                ..Default::default()
+            })));
+        }
+        let convert = |value: Decimal| {
+            zakat_core::math::ZakatDecimal::new(value)
+                .with_context("Prices::in_currency")
+                .checked_mul(rate)
+                .map(|converted| *converted)
+        };
+
+        Ok(PricedQuote {
+            prices: Prices {
+                gold_per_gram: convert(self.gold_per_gram)?,
+                silver_per_gram: convert(self.silver_per_gram)?,
+                currency: code,
+            },
+            base_to_local: Some(rate),
         })
     }
 }
@@ -71,6 +241,14 @@ impl Prices {
 pub trait PriceProvider: Send + Sync {
     /// Fetches current metal prices.
     async fn get_prices(&self) -> Result<Prices, ZakatError>;
+
+    /// Fetches prices along with their currency.
+    ///
+    /// Defaults to the `get_prices()` result in its own `currency`; providers
+    /// that convert to another currency should override this.
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
+        self.get_prices().await.map(|prices| PricedQuote { prices, base_to_local: None })
+    }
     
     /// Returns a name for this provider (used in logging).
     fn name(&self) -> &str {
@@ -83,6 +261,14 @@ pub trait PriceProvider: Send + Sync {
 pub trait PriceProvider {
     /// Fetches current metal prices.
     async fn get_prices(&self) -> Result<Prices, ZakatError>;
+
+    /// Fetches prices along with their currency.
+    ///
+    /// Defaults to the `get_prices()` result in its own `currency`; providers
+    /// that convert to another currency should override this.
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
+        self.get_prices().await.map(|prices| PricedQuote { prices, base_to_local: None })
+    }
     
     /// Returns a name for this provider (used in logging).
     fn name(&self) -> &str {
@@ -218,7 +404,11 @@ impl HistoricalPriceProvider for StaticHistoricalPriceProvider {
         } else if let Some(d) = &self.default_price {
             Ok(d.clone())
         } else {
-            Err(ZakatError::NetworkError(format!("No historical price found for {}", date)))
+            Err(ZakatError::NetworkError {
+                message: format!("No historical price found for {}", date),
+                status: None,
+                retryable: false,
+            })
         }
     }
 }
@@ -232,7 +422,11 @@ impl HistoricalPriceProvider for StaticHistoricalPriceProvider {
         } else if let Some(d) = &self.default_price {
             Ok(d.clone())
         } else {
-            Err(ZakatError::NetworkError(format!("No historical price found for {}", date)))
+            Err(ZakatError::NetworkError {
+                message: format!("No historical price found for {}", date),
+                status: None,
+                retryable: false,
+            })
         }
     }
 }
@@ -291,6 +485,10 @@ impl Default for FailoverPriceProvider {
 #[async_trait::async_trait]
 impl PriceProvider for FailoverPriceProvider {
     async fn get_prices(&self) -> Result<Prices, ZakatError> {
+        self.get_quote().await.map(|quote| quote.prices)
+    }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
         if self.providers.is_empty() {
             return Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
                 code: zakat_core::types::ZakatErrorCode::ConfigError,
@@ -304,8 +502,8 @@ impl PriceProvider for FailoverPriceProvider {
         let mut last_error: Option<ZakatError> = None;
         
         for (index, provider) in self.providers.iter().enumerate() {
-            match provider.get_prices().await {
-                Ok(prices) => {
+            match provider.get_quote().await {
+                Ok(quote) => {
                     if index > 0 {
                         tracing::info!(
                             "Price fetch succeeded using fallback provider '{}' (attempt {})",
@@ -313,7 +511,7 @@ impl PriceProvider for FailoverPriceProvider {
                             index + 1
                         );
                     }
-                    return Ok(prices);
+                    return Ok(quote);
                 }
                 Err(e) => {
                     tracing::warn!(
@@ -330,7 +528,7 @@ impl PriceProvider for FailoverPriceProvider {
         
         // All providers failed - return the last error
         Err(last_error.unwrap_or_else(|| {
-            ZakatError::NetworkError("All price providers failed".to_string())
+            ZakatError::network("All price providers failed".to_string())
         }))
     }
     
@@ -372,8 +570,8 @@ impl PriceProvider for FailoverPriceProvider {
 pub struct BestEffortPriceProvider<P: PriceProvider> {
     primary: P,
     fallback: Prices,
-    /// Optional: Cache the last successfully fetched prices from primary
-    last_known_good: Arc<RwLock<Option<Prices>>>,
+    /// Optional: Cache the last successfully fetched quote from primary
+    last_known_good: Arc<RwLock<Option<PricedQuote>>>,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -409,13 +607,17 @@ impl<P: PriceProvider> BestEffortPriceProvider<P> {
 #[async_trait::async_trait]
 impl<P: PriceProvider + Send + Sync> PriceProvider for BestEffortPriceProvider<P> {
     async fn get_prices(&self) -> Result<Prices, ZakatError> {
-        match self.primary.get_prices().await {
-            Ok(prices) => {
+        self.get_quote().await.map(|quote| quote.prices)
+    }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
+        match self.primary.get_quote().await {
+            Ok(quote) => {
                 // Cache this as the last known good
                 if let Ok(mut guard) = self.last_known_good.write() {
-                    *guard = Some(prices.clone());
+                    *guard = Some(quote.clone());
                 }
-                Ok(prices)
+                Ok(quote)
             }
             Err(e) => {
                 tracing::warn!(
@@ -438,7 +640,7 @@ impl<P: PriceProvider + Send + Sync> PriceProvider for BestEffortPriceProvider<P
                     self.fallback.gold_per_gram,
                     self.fallback.silver_per_gram
                 );
-                Ok(self.fallback.clone())
+                Ok(PricedQuote::new(self.fallback.clone(), self.fallback.currency.clone()))
             }
         }
     }
@@ -453,7 +655,7 @@ impl<P: PriceProvider + Send + Sync> PriceProvider for BestEffortPriceProvider<P
 pub struct BestEffortPriceProvider<P: PriceProvider> {
     primary: P,
     fallback: Prices,
-    last_known_good: Arc<RwLock<Option<Prices>>>,
+    last_known_good: Arc<RwLock<Option<PricedQuote>>>,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -482,12 +684,16 @@ impl<P: PriceProvider> BestEffortPriceProvider<P> {
 #[async_trait::async_trait(?Send)]
 impl<P: PriceProvider> PriceProvider for BestEffortPriceProvider<P> {
     async fn get_prices(&self) -> Result<Prices, ZakatError> {
-        match self.primary.get_prices().await {
-            Ok(prices) => {
+        self.get_quote().await.map(|quote| quote.prices)
+    }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
+        match self.primary.get_quote().await {
+            Ok(quote) => {
                 if let Ok(mut guard) = self.last_known_good.write() {
-                    *guard = Some(prices.clone());
+                    *guard = Some(quote.clone());
                 }
-                Ok(prices)
+                Ok(quote)
             }
             Err(_e) => {
                 // Try cached prices first
@@ -496,7 +702,7 @@ impl<P: PriceProvider> PriceProvider for BestEffortPriceProvider<P> {
                         return Ok(cached.clone());
                     }
                 }
-                Ok(self.fallback.clone())
+                Ok(PricedQuote::new(self.fallback.clone(), self.fallback.currency.clone()))
             }
         }
     }
@@ -544,6 +750,10 @@ impl Default for FailoverPriceProvider {
 #[async_trait::async_trait(?Send)]
 impl PriceProvider for FailoverPriceProvider {
     async fn get_prices(&self) -> Result<Prices, ZakatError> {
+        self.get_quote().await.map(|quote| quote.prices)
+    }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
         if self.providers.is_empty() {
             return Err(ZakatError::ConfigurationError(Box::new(ErrorDetails {
                 code: zakat_core::types::ZakatErrorCode::ConfigError,
@@ -557,8 +767,8 @@ impl PriceProvider for FailoverPriceProvider {
         let mut last_error: Option<ZakatError> = None;
         
         for provider in &self.providers {
-            match provider.get_prices().await {
-                Ok(prices) => return Ok(prices),
+            match provider.get_quote().await {
+                Ok(quote) => return Ok(quote),
                 Err(e) => {
                     last_error = Some(e);
                 }
@@ -566,7 +776,7 @@ impl PriceProvider for FailoverPriceProvider {
         }
         
         Err(last_error.unwrap_or_else(|| {
-            ZakatError::NetworkError("All price providers failed".to_string())
+            ZakatError::network("All price providers failed".to_string())
         }))
     }
     
@@ -581,7 +791,7 @@ impl PriceProvider for FailoverPriceProvider {
 #[derive(Debug, Clone)]
 pub struct CachedPriceProvider<P> {
     inner: P,
-    cache: Arc<RwLock<Option<(Instant, Prices)>>>,
+    cache: Arc<RwLock<Option<(Instant, PricedQuote)>>>,
     ttl: Duration,
 }
 
@@ -604,23 +814,27 @@ impl<P> CachedPriceProvider<P> {
 #[async_trait::async_trait]
 impl<P: PriceProvider + Send + Sync> PriceProvider for CachedPriceProvider<P> {
     async fn get_prices(&self) -> Result<Prices, ZakatError> {
+        self.get_quote().await.map(|quote| quote.prices)
+    }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
         // fast path: check read lock
         if let Ok(guard) = self.cache.read() {
-            if let Some((timestamp, prices)) = &*guard {
+            if let Some((timestamp, quote)) = &*guard {
                 if timestamp.elapsed() < self.ttl {
-                    return Ok(prices.clone());
+                    return Ok(quote.clone());
                 }
             }
         }
 
         // Slow path: fetch and update
-        let new_prices = self.inner.get_prices().await?;
+        let new_quote = self.inner.get_quote().await?;
         
         if let Ok(mut guard) = self.cache.write() {
-            *guard = Some((Instant::now(), new_prices.clone()));
+            *guard = Some((Instant::now(), new_quote.clone()));
         }
 
-        Ok(new_prices)
+        Ok(new_quote)
     }
 }
 
@@ -628,23 +842,253 @@ impl<P: PriceProvider + Send + Sync> PriceProvider for CachedPriceProvider<P> {
 #[async_trait::async_trait(?Send)]
 impl<P: PriceProvider> PriceProvider for CachedPriceProvider<P> {
     async fn get_prices(&self) -> Result<Prices, ZakatError> {
+        self.get_quote().await.map(|quote| quote.prices)
+    }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
         // fast path: check read lock
         if let Ok(guard) = self.cache.read() {
-            if let Some((timestamp, prices)) = &*guard {
+            if let Some((timestamp, quote)) = &*guard {
                 if timestamp.elapsed() < self.ttl {
-                    return Ok(prices.clone());
+                    return Ok(quote.clone());
                 }
             }
         }
 
         // Slow path: fetch and update
-        let new_prices = self.inner.get_prices().await?;
+        let new_quote = self.inner.get_quote().await?;
         
         if let Ok(mut guard) = self.cache.write() {
-            *guard = Some((Instant::now(), new_prices.clone()));
+            *guard = Some((Instant::now(), new_quote.clone()));
+        }
+
+        Ok(new_quote)
+    }
+}
+
+/// How fresh the prices returned by an [`OfflineFirstPriceProvider`] are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceFreshness {
+    /// Served from a cache entry still within its TTL.
+    Cached,
+    /// Fetched from the inner provider just now.
+    Fetched,
+    /// The inner provider failed, so the last cached prices were served past their TTL.
+    Stale,
+}
+
+/// An offline-first decorator that never fails while it has *some* prices.
+///
+/// Lookup order:
+/// 1. Return the cached prices while they are within the TTL.
+/// 2. Otherwise fetch from the inner provider and refresh the cache.
+/// 3. If the fetch fails, return the last cached prices however old, tagged
+///    [`PriceFreshness::Stale`], and log a warning.
+///
+/// It only errors when the fetch fails and nothing has ever been cached.
+/// Use [`OfflineFirstPriceProvider::with_cached_prices`] to seed the cache from
+/// prices persisted by a previous session.
+#[derive(Debug, Clone)]
+pub struct OfflineFirstPriceProvider<P> {
+    inner: P,
+    cache: Arc<RwLock<Option<OfflineCacheEntry>>>,
+    ttl: Duration,
+}
+
+/// A cached quote with the instant it was fetched, or `None` when seeded.
+type OfflineCacheEntry = (Option<Instant>, PricedQuote);
+
+impl<P> OfflineFirstPriceProvider<P> {
+    /// Creates a new OfflineFirstPriceProvider with an empty cache.
+    ///
+    /// # Arguments
+    /// * `inner` - The price provider to decorate.
+    /// * `ttl_seconds` - How long fetched prices count as fresh, in seconds.
+    pub fn new(inner: P, ttl_seconds: u64) -> Self {
+        Self {
+            inner,
+            cache: Arc::new(RwLock::new(None)),
+            ttl: Duration::from_secs(ttl_seconds),
+        }
+    }
+
+    /// Seeds the cache with previously persisted prices.
+    ///
+    /// Seeded prices have no known age, so they are never served as fresh:
+    /// a fetch is always attempted first and the seed is only used as the
+    /// stale fallback.
+    pub fn with_cached_prices(self, prices: Prices) -> Self {
+        if let Ok(mut guard) = self.cache.write() {
+            let currency = prices.currency.clone();
+            *guard = Some((None, PricedQuote::new(prices, currency)));
+        }
+        self
+    }
+
+    /// Returns the cached prices, if any, regardless of age.
+    pub fn cached_prices(&self) -> Option<Prices> {
+        self.cached_quote().map(|quote| quote.prices)
+    }
+
+    fn cached_quote(&self) -> Option<PricedQuote> {
+        self.cache
+            .read()
+            .ok()
+            .and_then(|guard| guard.as_ref().map(|(_, quote)| quote.clone()))
+    }
+
+    fn fresh_cached(&self) -> Option<PricedQuote> {
+        let guard = self.cache.read().ok()?;
+        match &*guard {
+            Some((Some(timestamp), quote)) if timestamp.elapsed() < self.ttl => Some(quote.clone()),
+            _ => None,
+        }
+    }
+
+    fn store(&self, quote: &PricedQuote) {
+        if let Ok(mut guard) = self.cache.write() {
+            *guard = Some((Some(Instant::now()), quote.clone()));
+        }
+    }
+}
+
+impl<P: PriceProvider> OfflineFirstPriceProvider<P> {
+    /// Fetches prices and reports where they came from.
+    ///
+    /// Returns the inner provider's error only when there is no cached
+    /// fallback at all.
+    pub async fn get_prices_with_freshness(&self) -> Result<(Prices, PriceFreshness), ZakatError> {
+        self.get_quote_with_freshness()
+            .await
+            .map(|(quote, freshness)| (quote.prices, freshness))
+    }
+
+    /// Like [`Self::get_prices_with_freshness`], but keeps the quote's
+    /// currency and FX rate.
+    pub async fn get_quote_with_freshness(&self) -> Result<(PricedQuote, PriceFreshness), ZakatError> {
+        if let Some(quote) = self.fresh_cached() {
+            return Ok((quote, PriceFreshness::Cached));
         }
 
-        Ok(new_prices)
+        match self.inner.get_quote().await {
+            Ok(quote) => {
+                self.store(&quote);
+                Ok((quote, PriceFreshness::Fetched))
+            }
+            Err(e) => match self.cached_quote() {
+                Some(quote) => {
+                    tracing::warn!(
+                        "Price provider '{}' failed: {}. Serving stale cached prices.",
+                        self.inner.name(),
+                        e
+                    );
+                    Ok((quote, PriceFreshness::Stale))
+                }
+                None => Err(e),
+            },
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait::async_trait]
+impl<P: PriceProvider + Send + Sync> PriceProvider for OfflineFirstPriceProvider<P> {
+    async fn get_prices(&self) -> Result<Prices, ZakatError> {
+        self.get_prices_with_freshness().await.map(|(prices, _)| prices)
+    }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
+        self.get_quote_with_freshness().await.map(|(quote, _)| quote)
+    }
+
+    fn name(&self) -> &str {
+        "OfflineFirstPriceProvider"
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[async_trait::async_trait(?Send)]
+impl<P: PriceProvider> PriceProvider for OfflineFirstPriceProvider<P> {
+    async fn get_prices(&self) -> Result<Prices, ZakatError> {
+        self.get_prices_with_freshness().await.map(|(prices, _)| prices)
+    }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
+        self.get_quote_with_freshness().await.map(|(quote, _)| quote)
+    }
+
+    fn name(&self) -> &str {
+        "OfflineFirstPriceProvider"
+    }
+}
+
+/// A decorator that retries transient failures of a single provider with
+/// exponential backoff, where [`FailoverPriceProvider`] would move on.
+///
+/// Only retryable [`ZakatError::NetworkError`]s (transport failures, 408, 429
+/// and 5xx) are retried, waiting `base_delay`, then twice that, and so on.
+/// Configuration, input and non-retryable HTTP errors (e.g. 401) fail at once.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct RetryingPriceProvider<P> {
+    inner: P,
+    max_attempts: u32,
+    base_delay: Duration,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<P> RetryingPriceProvider<P> {
+    /// Wraps `inner` with 3 attempts and a 500ms base delay.
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+
+    /// Sets the total number of attempts, including the first (at least 1).
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry; each later retry doubles it.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait::async_trait]
+impl<P: PriceProvider + Send + Sync> PriceProvider for RetryingPriceProvider<P> {
+    async fn get_prices(&self) -> Result<Prices, ZakatError> {
+        self.get_quote().await.map(|quote| quote.prices)
+    }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
+        let mut delay = self.base_delay;
+        let mut attempt = 1;
+        loop {
+            match self.inner.get_quote().await {
+                Ok(quote) => return Ok(quote),
+                Err(e) if e.is_retryable() && attempt < self.max_attempts => {
+                    tracing::warn!(
+                        "Price provider '{}' failed (attempt {}/{}): {}. Retrying in {:?}...",
+                        self.inner.name(), attempt, self.max_attempts, e, delay
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay = delay.checked_mul(2).unwrap_or(delay);
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        "RetryingPriceProvider"
     }
 }
 
@@ -670,6 +1114,49 @@ impl Default for NetworkConfig {
     }
 }
 
+/// 1 Troy Ounce = 31.1034768 Grams
+#[cfg(any(feature = "live-pricing", target_arch = "wasm32"))]
+const OUNCE_TO_GRAM: Decimal = rust_decimal_macros::dec!(31.1034768);
+
+/// Base URL of the goldapi.io REST API; quotes live at `/{metal}/{currency}`.
+#[cfg(any(feature = "live-pricing", target_arch = "wasm32"))]
+const GOLDAPI_BASE_URL: &str = "https://www.goldapi.io/api";
+
+/// Extracts the per-gram price from a goldapi.io quote body.
+///
+/// goldapi.io quotes `price` per troy ounce, e.g.
+/// `{"metal":"XAU","currency":"USD","price":2345.6}`. Rejected requests may come
+/// back as `{"error":"..."}`, which is reported as a non-retryable network error.
+#[cfg(any(feature = "live-pricing", target_arch = "wasm32"))]
+fn parse_goldapi_quote(body: &str) -> Result<Decimal, ZakatError> {
+    let value: serde_json::Value = serde_json::from_str(body)
+        .map_err(|e| ZakatError::malformed_response(format!("Failed to parse GoldAPI response: {}", e)))?;
+
+    if let Some(error) = value.get("error").and_then(|e| e.as_str()) {
+        return Err(ZakatError::NetworkError {
+            message: format!("GoldAPI error: {}", error),
+            status: None,
+            retryable: false,
+        });
+    }
+
+    let price = value
+        .get("price")
+        .filter(|p| p.is_number())
+        .ok_or_else(|| ZakatError::malformed_response("GoldAPI response has no price"))?;
+
+    let price_per_ounce = Decimal::from_str_exact(&price.to_string())
+        .map_err(|e| ZakatError::CalculationError(Box::new(ErrorDetails {
+            code: zakat_core::types::ZakatErrorCode::CalculationError,
+            reason_key: "error-calculation-failed".to_string(),
+            args: Some(std::collections::HashMap::from([("details".to_string(), format!("Failed to parse price decimal: {}", e))])),
+            suggestion: Some("The price API returned an invalid number format.".to_string()),
+            ..Default::default()
+        })))?;
+
+    Ok(price_per_ounce / OUNCE_TO_GRAM)
+}
+
 // =============================================================================
 // Native Implementation (using reqwest)
 // =============================================================================
@@ -724,6 +1211,26 @@ impl BinancePriceProvider {
         }
     }
     
+    /// How long to wait before retrying a failed response, or `None` if `status`
+    /// is not retryable (see [`ZakatError::is_retryable`]).
+    ///
+    /// 429 honours `Retry-After` (capped at 60s, 60s if unparseable); other
+    /// retryable statuses use the exponential `backoff`.
+    fn retry_wait(
+        status: reqwest::StatusCode,
+        retry_after: Option<&str>,
+        backoff: std::time::Duration,
+    ) -> Option<std::time::Duration> {
+        if !ZakatError::from_http_status(status.as_u16(), "").is_retryable() {
+            return None;
+        }
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let seconds = retry_after.and_then(|s| s.parse::<u64>().ok()).unwrap_or(60);
+            return Some(std::time::Duration::from_secs(seconds.min(60)));
+        }
+        Some(backoff)
+    }
+
     /// 3-tier DNS resolution: System DNS -> DoH -> Fail
     fn resolve_with_fallback(config: &NetworkConfig) -> Option<std::net::IpAddr> {
         // If user provided an explicit IP, use it directly
@@ -823,9 +1330,6 @@ impl PriceProvider for BinancePriceProvider {
             tracing::warn!("Circuit breaker open - too many failures, using cached/fallback data recommended");
         }
         
-        // 1 Troy Ounce = 31.1034768 Grams
-        const OUNCE_TO_GRAM: rust_decimal::Decimal = rust_decimal_macros::dec!(31.1034768);
-        
         // Fetch Gold Price (PAXG/USDT)
         // Fetch Gold Price (PAXG/USDT)
         let url = "https://api.binance.com/api/v3/ticker/price?symbol=PAXGUSDT";
@@ -837,31 +1341,35 @@ impl PriceProvider for BinancePriceProvider {
         let response = loop {
             attempts += 1;
             match self.client.get(url).send().await {
-                Ok(resp) => {
-                    if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
-                        let retry_after = resp.headers()
-                            .get(reqwest::header::RETRY_AFTER)
-                            .and_then(|val| val.to_str().ok())
-                            .and_then(|s| s.parse::<u64>().ok())
-                            .unwrap_or(60); // Default 60s if parse fails
-                        
-                        let wait_time = std::time::Duration::from_secs(retry_after.min(60)); // Cap at 60s
-                        tracing::warn!("Binance 429 Too Many Requests. Waiting {:?} before retry...", wait_time);
-                        tokio::time::sleep(wait_time).await;
-                        // Don't count as an attempt failure necessarily, or just continue loop? 
-                        // Instructions say "parse Retry-After... sleep... before retrying".
-                        // Logic below will consume an attempt if we continue.
-                        // Let's decrement attempts so we don't exhaust retries on forced waits, 
-                        // OR just respect the loop. Let's start fresh retry.
-                        continue;
-                    }
+                Ok(resp) if resp.status().is_success() => {
                     self.record_success();
                     break resp;
                 }
+                Ok(resp) => {
+                    let status = resp.status();
+                    let retry_after = resp.headers()
+                        .get(reqwest::header::RETRY_AFTER)
+                        .and_then(|val| val.to_str().ok());
+
+                    match Self::retry_wait(status, retry_after, backoff) {
+                        Some(wait_time) if attempts <= max_retries => {
+                            tracing::warn!("Binance API returned {} (attempt {}/{}). Retrying in {:?}...", status, attempts, max_retries + 1, wait_time);
+                            tokio::time::sleep(wait_time).await;
+                            backoff = backoff.checked_mul(2).unwrap_or(backoff);
+                        }
+                        _ => {
+                            self.record_failure();
+                            return Err(ZakatError::from_http_status(
+                                status.as_u16(),
+                                format!("Binance API returned {}", status),
+                            ));
+                        }
+                    }
+                }
                 Err(e) => {
                     if attempts > max_retries {
                         self.record_failure();
-                        return Err(ZakatError::NetworkError(format!("Binance API error after {} attempts: {}", attempts, e)));
+                        return Err(ZakatError::network(format!("Binance API error after {} attempts: {}", attempts, e)));
                     }
                     
                     tracing::warn!("Binance API request failed (attempt {}/{}): {}. Retrying in {:?}...", attempts, max_retries + 1, e, backoff);
@@ -873,7 +1381,7 @@ impl PriceProvider for BinancePriceProvider {
             
         let ticker: BinanceTicker = response.json()
             .await
-            .map_err(|e| ZakatError::NetworkError(format!("Failed to parse Binance response: {}", e)))?;
+            .map_err(|e| ZakatError::malformed_response(format!("Failed to parse Binance response: {}", e)))?;
             
         let price_per_ounce = rust_decimal::Decimal::from_str_exact(&ticker.price)
             .map_err(|e| ZakatError::CalculationError(Box::new(ErrorDetails { 
@@ -891,8 +1399,82 @@ impl PriceProvider for BinancePriceProvider {
         Ok(Prices {
             gold_per_gram,
             silver_per_gram: rust_decimal::Decimal::ZERO,
+            currency: "USD".to_string(), // PAXG is quoted in USDT
+        })
+    }
+}
+
+/// A price provider that fetches live gold and silver prices from [goldapi.io](https://www.goldapi.io).
+///
+/// Requires an API key. Prices are quoted per troy ounce in the chosen base
+/// currency and converted to per-gram; `get_quote` labels them with that currency.
+#[cfg(all(feature = "live-pricing", not(target_arch = "wasm32")))]
+pub struct GoldApiPriceProvider {
+    client: reqwest::Client,
+    api_key: String,
+    currency: String,
+}
+
+#[cfg(all(feature = "live-pricing", not(target_arch = "wasm32")))]
+impl GoldApiPriceProvider {
+    /// Creates a provider quoting in `currency` (e.g. "USD", "IDR").
+    pub fn new(api_key: impl Into<String>, currency: impl Into<String>, config: &NetworkConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            client,
+            api_key: api_key.into(),
+            currency: currency.into().to_uppercase(),
+        }
+    }
+
+    /// Fetches the per-gram price of `metal` ("XAU" or "XAG").
+    async fn fetch_per_gram(&self, metal: &str) -> Result<Decimal, ZakatError> {
+        let url = format!("{}/{}/{}", GOLDAPI_BASE_URL, metal, self.currency);
+
+        let response = self.client
+            .get(&url)
+            .header("x-access-token", &self.api_key)
+            .send()
+            .await
+            .map_err(|e| ZakatError::network(format!("GoldAPI request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(ZakatError::from_http_status(
+                response.status().as_u16(),
+                format!("GoldAPI returned {} for {}", response.status(), metal),
+            ));
+        }
+
+        let body = response.text()
+            .await
+            .map_err(|e| ZakatError::network(format!("Failed to read GoldAPI response: {}", e)))?;
+
+        parse_goldapi_quote(&body)
+    }
+}
+
+#[cfg(all(feature = "live-pricing", not(target_arch = "wasm32")))]
+#[async_trait::async_trait]
+impl PriceProvider for GoldApiPriceProvider {
+    async fn get_prices(&self) -> Result<Prices, ZakatError> {
+        Ok(Prices {
+            gold_per_gram: self.fetch_per_gram("XAU").await?,
+            silver_per_gram: self.fetch_per_gram("XAG").await?,
+            currency: self.currency.clone(),
         })
     }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
+        self.get_prices().await.map(|prices| PricedQuote::new(prices, self.currency.clone()))
+    }
+
+    fn name(&self) -> &str {
+        "GoldApiPriceProvider"
+    }
 }
 
 // =============================================================================
@@ -934,20 +1516,17 @@ impl PriceProvider for BinancePriceProvider {
     async fn get_prices(&self) -> Result<Prices, ZakatError> {
         use gloo_net::http::Request;
         
-        // 1 Troy Ounce = 31.1034768 Grams
-        const OUNCE_TO_GRAM: rust_decimal::Decimal = rust_decimal_macros::dec!(31.1034768);
-        
         // Fetch Gold Price (PAXG/USDT)
         let url = "https://api.binance.com/api/v3/ticker/price?symbol=PAXGUSDT";
         
         let response = Request::get(url)
             .send()
             .await
-            .map_err(|e| ZakatError::NetworkError(format!("Binance API error: {}", e)))?;
+            .map_err(|e| ZakatError::network(format!("Binance API error: {}", e)))?;
             
         let ticker: BinanceTickerWasm = response.json()
             .await
-            .map_err(|e| ZakatError::NetworkError(format!("Failed to parse Binance response: {}", e)))?;
+            .map_err(|e| ZakatError::malformed_response(format!("Failed to parse Binance response: {}", e)))?;
             
         let price_per_ounce = rust_decimal::Decimal::from_str_exact(&ticker.price)
             .map_err(|e| ZakatError::CalculationError(Box::new(ErrorDetails { 
@@ -963,15 +1542,127 @@ impl PriceProvider for BinancePriceProvider {
         Ok(Prices {
             gold_per_gram,
             silver_per_gram: rust_decimal::Decimal::ZERO,
+            currency: "USD".to_string(), // PAXG is quoted in USDT
         })
     }
 }
 
+/// A price provider that fetches live gold and silver prices from goldapi.io (WASM version).
+///
+/// Uses browser's Fetch API through gloo-net; the request timeout is left to the browser.
+#[cfg(target_arch = "wasm32")]
+pub struct GoldApiPriceProvider {
+    api_key: String,
+    currency: String,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl GoldApiPriceProvider {
+    /// Creates a provider quoting in `currency` (e.g. "USD", "IDR").
+    pub fn new(api_key: impl Into<String>, currency: impl Into<String>, _config: &NetworkConfig) -> Self {
+        Self {
+            api_key: api_key.into(),
+            currency: currency.into().to_uppercase(),
+        }
+    }
+
+    /// Fetches the per-gram price of `metal` ("XAU" or "XAG").
+    async fn fetch_per_gram(&self, metal: &str) -> Result<Decimal, ZakatError> {
+        use gloo_net::http::Request;
+
+        let url = format!("{}/{}/{}", GOLDAPI_BASE_URL, metal, self.currency);
+
+        let response = Request::get(&url)
+            .header("x-access-token", &self.api_key)
+            .send()
+            .await
+            .map_err(|e| ZakatError::network(format!("GoldAPI request failed: {}", e)))?;
+
+        if !response.ok() {
+            return Err(ZakatError::from_http_status(
+                response.status(),
+                format!("GoldAPI returned {} for {}", response.status(), metal),
+            ));
+        }
+
+        let body = response.text()
+            .await
+            .map_err(|e| ZakatError::network(format!("Failed to read GoldAPI response: {}", e)))?;
+
+        parse_goldapi_quote(&body)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[async_trait::async_trait(?Send)]
+impl PriceProvider for GoldApiPriceProvider {
+    async fn get_prices(&self) -> Result<Prices, ZakatError> {
+        Ok(Prices {
+            gold_per_gram: self.fetch_per_gram("XAU").await?,
+            silver_per_gram: self.fetch_per_gram("XAG").await?,
+            currency: self.currency.clone(),
+        })
+    }
+
+    async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
+        self.get_prices().await.map(|prices| PricedQuote::new(prices, self.currency.clone()))
+    }
+
+    fn name(&self) -> &str {
+        "GoldApiPriceProvider"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rust_decimal_macros::dec;
 
+    #[test]
+    fn test_price_delta_in_percent_with_zero_guard() {
+        let previous = Prices::new(dec!(100), dec!(0.80)).unwrap();
+        let current = Prices::new(dec!(102), dec!(0.78)).unwrap();
+
+        let delta = current.delta(&previous).unwrap();
+        assert_eq!(delta.gold_pct, Some(dec!(2)));
+        assert_eq!(delta.silver_pct, Some(dec!(-2.5)));
+
+        let unpriced = Prices::new(dec!(100), Decimal::ZERO).unwrap();
+        let delta = current.delta(&unpriced).unwrap();
+        assert_eq!(delta.gold_pct, Some(dec!(2)));
+        assert_eq!(delta.silver_pct, None);
+
+        let other_currency = previous.with_currency("IDR");
+        assert_eq!(current.delta(&other_currency), None);
+    }
+
+    #[test]
+    fn test_prices_normalized_ignores_trailing_zeros() {
+        let cached = Prices::new(dec!(65.1), dec!(0.8)).unwrap();
+        let fresh = Prices::new(dec!(65.1000), dec!(0.80000)).unwrap();
+
+        let (a, b) = (cached.normalized(4), fresh.normalized(4));
+        assert_eq!(a, b);
+        assert_eq!(a.gold_per_gram.to_string(), b.gold_per_gram.to_string());
+        assert_eq!(a.gold_per_gram.to_string(), "65.1000");
+    }
+
+    #[test]
+    fn test_demo_prices_are_positive_and_reach_nisab() {
+        use zakat_core::prelude::*;
+
+        let demo = Prices::demo();
+        assert!(demo.gold_per_gram > Decimal::ZERO);
+        assert!(demo.silver_per_gram > Decimal::ZERO);
+
+        let config = ZakatConfig::new()
+            .with_gold_price(demo.gold_per_gram)
+            .with_silver_price(demo.silver_per_gram);
+        let details = BusinessZakat::cash_only(10_000).hawl(true).calculate_zakat(&config).unwrap();
+        assert!(details.is_payable);
+        assert!(details.zakat_due > Decimal::ZERO);
+    }
+
     #[test]
     fn test_prices_creation() {
         let prices = Prices::new(65, 1).unwrap();
@@ -1003,6 +1694,16 @@ mod tests {
         let prices2 = cached_provider.get_prices().await.unwrap();
         assert_eq!(prices2.gold_per_gram, dec!(100));
     }
+
+    #[tokio::test]
+    async fn test_default_quote_keeps_the_prices_currency() {
+        let prices = Prices::new(1_500_000, 20_000).unwrap().with_currency("IDR");
+        let cached = CachedPriceProvider::new(StaticPriceProvider::from_prices(prices), 60);
+
+        let quote = cached.get_quote().await.unwrap();
+        assert_eq!(quote.prices.currency, "IDR");
+        assert_eq!(quote.base_to_local, None);
+    }
     
     // =============================================================================
     // Failover Price Provider Tests
@@ -1025,7 +1726,7 @@ mod tests {
     #[async_trait::async_trait]
     impl PriceProvider for MockFailingProvider {
         async fn get_prices(&self) -> Result<Prices, ZakatError> {
-            Err(ZakatError::NetworkError(format!("{} failed", self.name)))
+            Err(ZakatError::network(format!("{} failed", self.name)))
         }
         
         fn name(&self) -> &str {
@@ -1033,6 +1734,77 @@ mod tests {
         }
     }
     
+    /// A mock provider that fails with `error` for its first `failures` calls.
+    #[cfg(not(target_arch = "wasm32"))]
+    struct MockFlakyProvider {
+        failures: u32,
+        error: fn() -> ZakatError,
+        calls: std::sync::atomic::AtomicU32,
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl MockFlakyProvider {
+        fn new(failures: u32, error: fn() -> ZakatError) -> Self {
+            Self { failures, error, calls: std::sync::atomic::AtomicU32::new(0) }
+        }
+
+        fn calls(&self) -> u32 {
+            self.calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[async_trait::async_trait]
+    impl PriceProvider for Arc<MockFlakyProvider> {
+        async fn get_prices(&self) -> Result<Prices, ZakatError> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if call < self.failures {
+                return Err((self.error)());
+            }
+            Prices::new(dec!(90), dec!(1))
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_retrying_provider_recovers_from_transient_errors() {
+        let flaky = Arc::new(MockFlakyProvider::new(2, || ZakatError::from_http_status(503, "Service Unavailable")));
+        let retrying = RetryingPriceProvider::new(flaky.clone())
+            .with_max_attempts(3)
+            .with_base_delay(Duration::from_millis(1));
+
+        let prices = retrying.get_prices().await.unwrap();
+        assert_eq!(prices.gold_per_gram, dec!(90));
+        assert_eq!(flaky.calls(), 3);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_retrying_provider_gives_up_after_max_attempts() {
+        let flaky = Arc::new(MockFlakyProvider::new(5, || ZakatError::from_http_status(429, "Too Many Requests")));
+        let retrying = RetryingPriceProvider::new(flaky.clone())
+            .with_max_attempts(3)
+            .with_base_delay(Duration::from_millis(1));
+
+        let err = retrying.get_prices().await.unwrap_err();
+        assert!(matches!(err, ZakatError::NetworkError { status: Some(429), .. }));
+        assert_eq!(flaky.calls(), 3);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_retrying_provider_does_not_retry_permanent_errors() {
+        let invalid = Arc::new(MockFlakyProvider::new(1, || ZakatError::InvalidInput(Box::default())));
+        let retrying = RetryingPriceProvider::new(invalid.clone()).with_base_delay(Duration::from_millis(1));
+        assert!(retrying.get_prices().await.is_err());
+        assert_eq!(invalid.calls(), 1);
+
+        let misconfigured = Arc::new(MockFlakyProvider::new(1, || ZakatError::ConfigurationError(Box::default())));
+        let retrying = RetryingPriceProvider::new(misconfigured.clone()).with_base_delay(Duration::from_millis(1));
+        assert!(retrying.get_prices().await.is_err());
+        assert_eq!(misconfigured.calls(), 1);
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     #[tokio::test]
     async fn test_failover_provider_uses_first_successful() {
@@ -1078,7 +1850,7 @@ mod tests {
         let result = failover.get_prices().await;
         assert!(result.is_err());
         
-        if let Err(ZakatError::NetworkError(msg)) = result {
+        if let Err(ZakatError::NetworkError { message: msg, .. }) = result {
             assert!(msg.contains("API2")); // Last provider's error
         } else {
             panic!("Expected NetworkError");
@@ -1142,6 +1914,239 @@ mod tests {
         // Cache should be populated
         let guard = provider.last_known_good.read().unwrap();
         assert!(guard.is_some());
-        assert_eq!(guard.as_ref().unwrap().gold_per_gram, dec!(120));
+        assert_eq!(guard.as_ref().unwrap().prices.gold_per_gram, dec!(120));
+    }
+
+    // =============================================================================
+    // Offline-First Price Provider Tests
+    // =============================================================================
+
+    /// A mock provider that can be switched offline and counts its calls.
+    #[cfg(not(target_arch = "wasm32"))]
+    struct MockToggleProvider {
+        prices: Prices,
+        online: std::sync::atomic::AtomicBool,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl MockToggleProvider {
+        fn new(gold: i64) -> Arc<Self> {
+            Arc::new(Self {
+                prices: Prices::new(gold, 1).unwrap(),
+                online: std::sync::atomic::AtomicBool::new(true),
+                calls: std::sync::atomic::AtomicUsize::new(0),
+            })
+        }
+
+        fn set_online(&self, online: bool) {
+            self.online.store(online, std::sync::atomic::Ordering::SeqCst);
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[async_trait::async_trait]
+    impl PriceProvider for Arc<MockToggleProvider> {
+        async fn get_prices(&self) -> Result<Prices, ZakatError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if self.online.load(std::sync::atomic::Ordering::SeqCst) {
+                Ok(self.prices.clone())
+            } else {
+                Err(ZakatError::network("offline".to_string()))
+            }
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_offline_first_serves_fresh_cache_without_fetching() {
+        let inner = MockToggleProvider::new(100);
+        let provider = OfflineFirstPriceProvider::new(inner.clone(), 3600);
+
+        let (_, first) = provider.get_prices_with_freshness().await.unwrap();
+        let (prices, second) = provider.get_prices_with_freshness().await.unwrap();
+
+        assert_eq!(first, PriceFreshness::Fetched);
+        assert_eq!(second, PriceFreshness::Cached);
+        assert_eq!(prices.gold_per_gram, dec!(100));
+        assert_eq!(inner.calls(), 1, "Fresh cache hit must not call the inner provider");
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_offline_first_fetch_success_updates_cache() {
+        let inner = MockToggleProvider::new(100);
+        let stale = Prices::new(50, 1).unwrap();
+        let provider = OfflineFirstPriceProvider::new(inner.clone(), 3600).with_cached_prices(stale);
+
+        let (prices, freshness) = provider.get_prices_with_freshness().await.unwrap();
+
+        assert_eq!(freshness, PriceFreshness::Fetched);
+        assert_eq!(prices.gold_per_gram, dec!(100));
+        assert_eq!(provider.cached_prices().unwrap().gold_per_gram, dec!(100));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_offline_first_fetch_failure_returns_stale_cache() {
+        let inner = MockToggleProvider::new(100);
+        let provider = OfflineFirstPriceProvider::new(inner.clone(), 0);
+
+        provider.get_prices().await.unwrap();
+        inner.set_online(false);
+
+        let (prices, freshness) = provider.get_prices_with_freshness().await.unwrap();
+        assert_eq!(freshness, PriceFreshness::Stale);
+        assert_eq!(prices.gold_per_gram, dec!(100));
+        assert_eq!(inner.calls(), 2);
+
+        let empty = OfflineFirstPriceProvider::new(MockFailingProvider::new("Offline"), 3600);
+        assert!(empty.get_prices().await.is_err(), "No cache at all should still error");
+    }
+
+    #[test]
+    fn test_prices_in_currency_converts_usd_quote_to_idr() {
+        let usd = Prices::new(dec!(65.5), dec!(0.8)).unwrap();
+
+        let idr = usd.in_currency(dec!(16000), "IDR").unwrap();
+
+        assert_eq!(idr.currency_code(), "IDR");
+        assert_eq!(idr.base_to_local, Some(dec!(16000)));
+        assert_eq!(idr.prices.gold_per_gram, dec!(1048000));
+        assert_eq!(idr.prices.silver_per_gram, dec!(12800));
+        assert!(usd.in_currency(0, "IDR").is_err());
+    }
+
+    #[test]
+    fn test_usd_prices_feed_idr_portfolio_only_through_a_converter() {
+        use zakat_core::portfolio::PortfolioStatus;
+        use zakat_core::prelude::*;
+
+        let usd = Prices::new(dec!(65), dec!(0.8)).unwrap();
+        assert_eq!(usd.convert_to(dec!(16000), "IDR").unwrap().currency, "IDR");
+
+        let config = usd.apply_to(ZakatConfig::new().with_currency_code("IDR"));
+        let portfolio = ZakatPortfolio::new().add(BusinessZakat::cash_only(10_000_000).hawl(true));
+
+        // 10M IDR against a USD Nisab (85g x 65 = 5,525) would be meaningless: reject it.
+        let rejected = portfolio.calculate_total(&config);
+        assert_eq!(rejected.status, PortfolioStatus::Failed);
+        assert!(matches!(
+            &rejected.failures()[0],
+            PortfolioItemResult::Failure { error: ZakatError::ConfigurationError(details), .. }
+                if details.reason_key == "error-config-currency-mismatch"
+        ));
+
+        let usd_to_idr = |from: &str, to: &str| (from == "USD" && to == "IDR").then_some(dec!(16000));
+        let converted = portfolio.calculate_total_converted(&config, &usd_to_idr).unwrap();
+        // Nisab = 85g x 65 x 16,000 = 88,400,000 IDR, so 10M IDR is exempt.
+        assert_eq!(converted.successes()[0].nisab_threshold, dec!(88400000));
+        assert!(!converted.successes()[0].is_payable);
+
+        let no_rates = |_: &str, _: &str| None;
+        assert!(matches!(
+            portfolio.calculate_total_converted(&config, &no_rates),
+            Err(ZakatError::ConfigurationError(_))
+        ));
+    }
+
+    /// A provider that converts a USD quote into local currency.
+    #[cfg(not(target_arch = "wasm32"))]
+    struct MockLocalQuoteProvider;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[async_trait::async_trait]
+    impl PriceProvider for MockLocalQuoteProvider {
+        async fn get_prices(&self) -> Result<Prices, ZakatError> {
+            self.get_quote().await.map(|quote| quote.prices)
+        }
+
+        async fn get_quote(&self) -> Result<PricedQuote, ZakatError> {
+            Prices::new(65, 1)?.in_currency(16000, "IDR")
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_failover_provider_preserves_fx_rate() {
+        let failover = FailoverPriceProvider::new()
+            .add_provider(MockFailingProvider::new("Down"))
+            .add_provider(MockLocalQuoteProvider);
+
+        let quote = failover.get_quote().await.unwrap();
+
+        assert_eq!(quote.currency_code(), "IDR");
+        assert_eq!(quote.base_to_local, Some(dec!(16000)));
+        assert_eq!(quote.prices.gold_per_gram, dec!(1040000));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_wrappers_preserve_fx_rate() {
+        let cached = CachedPriceProvider::new(MockLocalQuoteProvider, 3600);
+        let offline = OfflineFirstPriceProvider::new(MockLocalQuoteProvider, 3600);
+        let best_effort = BestEffortPriceProvider::new(MockLocalQuoteProvider, Prices::demo());
+        let chain = crate::chain::MultiSourcePriceProvider::new().with_provider(MockLocalQuoteProvider);
+
+        for quote in [
+            cached.get_quote().await.unwrap(),
+            // Second call is served from the cache.
+            cached.get_quote().await.unwrap(),
+            offline.get_quote().await.unwrap(),
+            best_effort.get_quote().await.unwrap(),
+            chain.get_quote().await.unwrap(),
+        ] {
+            assert_eq!(quote.currency_code(), "IDR");
+            assert_eq!(quote.base_to_local, Some(dec!(16000)));
+            assert_eq!(quote.prices.gold_per_gram, dec!(1040000));
+        }
+    }
+
+    #[cfg(feature = "live-pricing")]
+    #[test]
+    fn test_goldapi_quote_converts_ounce_to_gram() {
+        let body = r#"{"timestamp":1700000000,"metal":"XAU","currency":"USD","price":3110.34768,"price_gram_24k":100.0}"#;
+        assert_eq!(parse_goldapi_quote(body).unwrap(), dec!(100));
+
+        let silver = r#"{"metal":"XAG","currency":"USD","price":31.1034768}"#;
+        assert_eq!(parse_goldapi_quote(silver).unwrap(), dec!(1));
+    }
+
+    #[cfg(feature = "live-pricing")]
+    #[test]
+    fn test_goldapi_error_bodies_are_network_errors() {
+        let rejected = parse_goldapi_quote(r#"{"error":"Invalid API Key"}"#).unwrap_err();
+        assert!(matches!(&rejected, ZakatError::NetworkError { message, .. } if message.contains("Invalid API Key")));
+        assert!(!rejected.is_retryable(), "A bad API key will not fix itself on retry");
+
+        let no_price = parse_goldapi_quote(r#"{"metal":"XAU"}"#).unwrap_err();
+        assert!(matches!(no_price, ZakatError::NetworkError { .. }));
+        assert!(!no_price.is_retryable(), "A malformed body will not change on retry");
+        let not_json = parse_goldapi_quote("<html>").unwrap_err();
+        assert!(matches!(not_json, ZakatError::NetworkError { .. }));
+        assert!(!not_json.is_retryable());
+    }
+
+    #[cfg(all(feature = "live-pricing", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_binance_retries_only_retryable_statuses() {
+        use reqwest::StatusCode;
+        use std::time::Duration;
+
+        let backoff = Duration::from_millis(500);
+        let wait = |status, retry_after| BinancePriceProvider::retry_wait(status, retry_after, backoff);
+
+        assert_eq!(wait(StatusCode::SERVICE_UNAVAILABLE, None), Some(backoff));
+        assert_eq!(wait(StatusCode::BAD_GATEWAY, None), Some(backoff));
+        assert_eq!(wait(StatusCode::REQUEST_TIMEOUT, None), Some(backoff));
+        assert_eq!(wait(StatusCode::TOO_MANY_REQUESTS, Some("5")), Some(Duration::from_secs(5)));
+        assert_eq!(wait(StatusCode::TOO_MANY_REQUESTS, Some("3600")), Some(Duration::from_secs(60)));
+
+        assert_eq!(wait(StatusCode::UNAUTHORIZED, None), None);
+        assert_eq!(wait(StatusCode::NOT_FOUND, None), None);
     }
 }
@@ -0,0 +1,87 @@
+//! Price Subscriptions
+//!
+//! Turns any [`PriceProvider`] into a stream of periodic price updates, so live
+//! dashboards can react to new prices instead of polling.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::Stream;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use zakat_core::types::ZakatError;
+
+use crate::pricing::{PriceProvider, Prices};
+
+/// Periodically polls a provider and yields each result as a [`Stream`] item.
+///
+/// The first fetch happens immediately, then once per `interval`. Errors are
+/// yielded rather than ending the stream, so a transient network failure does
+/// not stop later updates. Dropping the subscription stops the background task.
+///
+/// Requires a running tokio runtime.
+pub struct PriceSubscription {
+    receiver: mpsc::Receiver<Result<Prices, ZakatError>>,
+    task: JoinHandle<()>,
+}
+
+impl PriceSubscription {
+    /// Spawns the polling task for `provider` on the current tokio runtime.
+    pub fn new(provider: Arc<dyn PriceProvider>, interval: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel(1);
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                let update = provider.get_prices().await;
+                if let Err(e) = &update {
+                    tracing::warn!("Price subscription ({}) fetch failed: {}", provider.name(), e);
+                }
+                if sender.send(update).await.is_err() {
+                    break; // Subscriber dropped
+                }
+            }
+        });
+        Self { receiver, task }
+    }
+}
+
+impl Stream for PriceSubscription {
+    type Item = Result<Prices, ZakatError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for PriceSubscription {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pricing::StaticPriceProvider;
+    use futures::StreamExt;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_subscription_yields_periodic_ticks() {
+        let provider = Arc::new(StaticPriceProvider::new(dec!(85), dec!(1)).unwrap());
+        let subscription = PriceSubscription::new(provider, Duration::from_millis(20));
+
+        let ticks: Vec<_> = tokio::time::timeout(Duration::from_secs(2), subscription.take(2).collect())
+            .await
+            .expect("two ticks should arrive well within the timeout");
+
+        assert_eq!(ticks.len(), 2);
+        for tick in ticks {
+            assert_eq!(tick.unwrap().gold_per_gram, dec!(85));
+        }
+    }
+}
@@ -4,7 +4,7 @@
 
 use async_trait::async_trait;
 use std::path::PathBuf;
-use zakat_core::types::{ZakatError, InvalidInputDetails};
+use zakat_core::types::{ZakatError, ErrorDetails, InvalidInputDetails};
 use zakat_ledger::events::LedgerEvent;
 
 /// Trait for persisting Zakat Ledger events.
@@ -17,6 +17,23 @@ pub trait LedgerStore: Send + Sync {
     async fn load_events(&self) -> Result<Vec<LedgerEvent>, ZakatError>;
 }
 
+/// A local storage failure (file or database), reported by `source`.
+///
+/// These are not network errors: retrying will not help and a server should
+/// answer 500 rather than 502.
+pub(crate) fn storage_error(source: &str, context: &str, error: &str) -> ZakatError {
+    ZakatError::CalculationError(Box::new(ErrorDetails {
+        code: zakat_core::types::ZakatErrorCode::CalculationError,
+        reason_key: "error-storage".to_string(),
+        args: Some(std::collections::HashMap::from([
+            ("context".to_string(), context.to_string()),
+            ("error".to_string(), error.to_string()),
+        ])),
+        source_label: Some(source.to_string()),
+        ..Default::default()
+    }))
+}
+
 /// A simple JSON file-based implementation of LedgerStore.
 pub struct JsonFileStore {
     path: PathBuf,
@@ -44,7 +61,7 @@ impl LedgerStore for JsonFileStore {
             ..Default::default()
         })))?;
         
-        tokio::fs::write(&self.path, json).await.map_err(|e: std::io::Error| ZakatError::NetworkError(format!("IO Error: {}", e)))?;
+        tokio::fs::write(&self.path, json).await.map_err(|e| storage_error("JsonFileStore", "IO error", &e.to_string()))?;
         Ok(())
     }
 
@@ -53,7 +70,7 @@ impl LedgerStore for JsonFileStore {
             return Ok(Vec::new());
         }
         
-        let content = tokio::fs::read_to_string(&self.path).await.map_err(|e: std::io::Error| ZakatError::NetworkError(format!("IO Error: {}", e)))?;
+        let content = tokio::fs::read_to_string(&self.path).await.map_err(|e| storage_error("JsonFileStore", "IO error", &e.to_string()))?;
         
         if content.trim().is_empty() {
             return Ok(Vec::new());
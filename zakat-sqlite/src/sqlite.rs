@@ -8,7 +8,7 @@ use sqlx::{sqlite::SqlitePoolOptions, Row, SqlitePool};
 
 use zakat_core::types::{InvalidInputDetails, WealthType, ZakatError};
 use zakat_ledger::events::{LedgerEvent, TransactionType};
-use crate::persistence::LedgerStore;
+use crate::persistence::{storage_error, LedgerStore};
 
 /// A SQLite-backed implementation of `LedgerStore`.
 ///
@@ -24,13 +24,13 @@ impl SqliteStore {
     /// * `db_url` - SQLite connection URL (e.g., `"sqlite::memory:"` or `"sqlite:ledger.db?mode=rwc"`)
     ///
     /// # Errors
-    /// Returns `ZakatError::NetworkError` if connection or migration fails.
+    /// Returns `ZakatError::CalculationError` if connection or migration fails.
     pub async fn new(db_url: &str) -> Result<Self, ZakatError> {
         let pool = SqlitePoolOptions::new()
             .max_connections(5)
             .connect(db_url)
             .await
-            .map_err(|e| ZakatError::NetworkError(format!("SQLite connection error: {}", e)))?;
+            .map_err(|e| storage_error("SqliteStore", "SQLite connection error", &e.to_string()))?;
 
         let store = Self { pool };
         store.migrate().await?;
@@ -50,13 +50,13 @@ impl SqliteStore {
         sqlx::query("CREATE TABLE IF NOT EXISTS _migrations (version INTEGER PRIMARY KEY)")
             .execute(&self.pool)
             .await
-            .map_err(|e| ZakatError::NetworkError(format!("Migration init error: {}", e)))?;
+            .map_err(|e| storage_error("SqliteStore", "Migration init error", &e.to_string()))?;
 
         // 2. Get current version
         let current_version: Option<i32> = sqlx::query_scalar("SELECT MAX(version) FROM _migrations")
             .fetch_optional(&self.pool)
             .await
-            .map_err(|e| ZakatError::NetworkError(format!("Migration version check error: {}", e)))?;
+            .map_err(|e| storage_error("SqliteStore", "Migration version check error", &e.to_string()))?;
             
         let version = current_version.unwrap_or(0);
 
@@ -76,12 +76,12 @@ impl SqliteStore {
             )
             .execute(&self.pool)
             .await
-            .map_err(|e| ZakatError::NetworkError(format!("Migration v1 error: {}", e)))?;
+            .map_err(|e| storage_error("SqliteStore", "Migration v1 error", &e.to_string()))?;
             
             sqlx::query("INSERT INTO _migrations (version) VALUES (1)")
                 .execute(&self.pool)
                 .await
-                .map_err(|e| ZakatError::NetworkError(format!("Migration v1 version update error: {}", e)))?;
+                .map_err(|e| storage_error("SqliteStore", "Migration v1 version update error", &e.to_string()))?;
         }
 
         Ok(())
@@ -120,7 +120,7 @@ impl LedgerStore for SqliteStore {
         .bind(&event.description)
         .execute(&self.pool)
         .await
-        .map_err(|e| ZakatError::NetworkError(format!("SQLite insert error: {}", e)))?;
+        .map_err(|e| storage_error("SqliteStore", "SQLite insert error", &e.to_string()))?;
 
         Ok(())
     }
@@ -135,7 +135,7 @@ impl LedgerStore for SqliteStore {
         )
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| ZakatError::NetworkError(format!("SQLite query error: {}", e)))?;
+        .map_err(|e| storage_error("SqliteStore", "SQLite query error", &e.to_string()))?;
 
         let mut events = Vec::with_capacity(rows.len());
 
@@ -236,6 +236,19 @@ mod tests {
         assert_eq!(loaded[0].description, event.description);
     }
 
+    #[tokio::test]
+    async fn test_storage_failures_are_not_network_errors() {
+        let missing = std::env::temp_dir().join("zakat-no-such-dir").join("ledger.db");
+        let err = match SqliteStore::new(&format!("sqlite:{}", missing.display())).await {
+            Ok(_) => panic!("opening a database in a missing directory should fail"),
+            Err(e) => e,
+        };
+
+        assert!(matches!(err, ZakatError::CalculationError(_)));
+        assert!(!err.is_retryable());
+        assert_eq!(err.http_status(), 500);
+    }
+
     #[tokio::test]
     async fn test_sqlite_store_ordered_by_date() {
         let store = SqliteStore::new("sqlite::memory:")
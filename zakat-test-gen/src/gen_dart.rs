@@ -123,6 +123,14 @@ fn generate_dart_test(file: &mut File, case: &TestCase, indent: &str) -> io::Res
         return Ok(());
     }
 
+    // DartZakatConfig does not take Nisab weight overrides yet
+    if case.config.has_nisab_override() {
+        writeln!(file, "{}test('{}', () {{", indent, description)?;
+        writeln!(file, "{}}}, skip: 'Nisab weight overrides not yet supported');", indent)?;
+        writeln!(file)?;
+        return Ok(());
+    }
+
     // Skip non-Hanafi madhab for gold cases
     if case.asset_type == AssetType::Gold && case.config.madhab != "hanafi" {
         writeln!(file, "{}test('{}', () {{", indent, description)?;
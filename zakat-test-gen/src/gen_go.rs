@@ -93,6 +93,15 @@ fn generate_go_test_function(file: &mut File, case: &TestCase) -> io::Result<()>
         return Ok(());
     }
 
+    // The Go config does not take Nisab weight overrides yet
+    if case.config.has_nisab_override() {
+        writeln!(file, "func Test{}(t *testing.T) {{", func_name)?;
+        writeln!(file, "\tt.Skip(\"Nisab weight overrides not yet supported\")")?;
+        writeln!(file, "}}")?;
+        writeln!(file)?;
+        return Ok(());
+    }
+
     // Skip non-Hanafi madhab for gold/silver cases
     if (case.asset_type == AssetType::Gold || case.asset_type == AssetType::Silver)
         && case.config.madhab != "hanafi"
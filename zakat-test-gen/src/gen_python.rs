@@ -115,6 +115,13 @@ fn generate_python_test_method(file: &mut File, case: &TestCase) -> io::Result<(
         return Ok(());
     }
 
+    // The Python config does not take Nisab weight overrides yet
+    if case.config.has_nisab_override() {
+        writeln!(file, "        self.skipTest(\"Nisab weight overrides not yet supported\")")?;
+        writeln!(file)?;
+        return Ok(());
+    }
+
     // Generate config
     writeln!(file, "        # Configuration")?;
     writeln!(file, "        config = zakatrs.ZakatConfig(")?;
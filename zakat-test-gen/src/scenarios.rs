@@ -112,14 +112,8 @@ fn business_happy_path() -> Vec<TestCase> {
     ]
 }
 
-fn generate_business_case(
-    id: &str,
-    description: &str,
-    category: TestCategory,
-    config: TestConfig,
-    input: TestInput,
-) -> TestCase {
-    // Build the actual Rust config
+/// Builds the Rust config a test case runs against.
+fn build_zakat_config(config: &TestConfig) -> ZakatConfig {
     let madhab = match config.madhab.as_str() {
         "shafi" => Madhab::Shafi,
         "maliki" => Madhab::Maliki,
@@ -130,11 +124,31 @@ fn generate_business_case(
     let gold_price: Decimal = config.gold_price_per_gram.parse().unwrap_or(dec!(0));
     let silver_price: Decimal = config.silver_price_per_gram.parse().unwrap_or(dec!(0));
 
-    let zakat_config = ZakatConfig::new()
+    let mut zakat_config = ZakatConfig::new()
         .with_madhab(madhab)
         .with_gold_price(gold_price)
         .with_silver_price(silver_price);
 
+    // Jurisdictions with other Nisab weights (unset keeps 85g / 595g)
+    if let Some(grams) = config.nisab_gold_grams.as_deref().and_then(|g| g.parse::<Decimal>().ok()) {
+        zakat_config = zakat_config.with_gold_nisab(grams);
+    }
+    if let Some(grams) = config.nisab_silver_grams.as_deref().and_then(|g| g.parse::<Decimal>().ok()) {
+        zakat_config = zakat_config.with_silver_nisab(grams);
+    }
+    zakat_config
+}
+
+fn generate_business_case(
+    id: &str,
+    description: &str,
+    category: TestCategory,
+    config: TestConfig,
+    input: TestInput,
+) -> TestCase {
+    // Build the actual Rust config
+    let zakat_config = build_zakat_config(&config);
+
     // Build the business asset
     let cash: Decimal = input.fields.get("cash_on_hand")
         .and_then(|v| v.as_str())
@@ -264,20 +278,7 @@ fn generate_gold_case(
     config: TestConfig,
     input: TestInput,
 ) -> TestCase {
-    let madhab = match config.madhab.as_str() {
-        "shafi" => Madhab::Shafi,
-        "maliki" => Madhab::Maliki,
-        "hanbali" => Madhab::Hanbali,
-        _ => Madhab::Hanafi,
-    };
-
-    let gold_price: Decimal = config.gold_price_per_gram.parse().unwrap_or(dec!(0));
-    let silver_price: Decimal = config.silver_price_per_gram.parse().unwrap_or(dec!(0));
-
-    let zakat_config = ZakatConfig::new()
-        .with_madhab(madhab)
-        .with_gold_price(gold_price)
-        .with_silver_price(silver_price);
+    let zakat_config = build_zakat_config(&config);
 
     let weight: Decimal = input.fields.get("weight_grams")
         .and_then(|v| v.as_str())
@@ -377,20 +378,7 @@ fn generate_silver_case(
     config: TestConfig,
     input: TestInput,
 ) -> TestCase {
-    let madhab = match config.madhab.as_str() {
-        "shafi" => Madhab::Shafi,
-        "maliki" => Madhab::Maliki,
-        "hanbali" => Madhab::Hanbali,
-        _ => Madhab::Hanafi,
-    };
-
-    let gold_price: Decimal = config.gold_price_per_gram.parse().unwrap_or(dec!(0));
-    let silver_price: Decimal = config.silver_price_per_gram.parse().unwrap_or(dec!(0));
-
-    let zakat_config = ZakatConfig::new()
-        .with_madhab(madhab)
-        .with_gold_price(gold_price)
-        .with_silver_price(silver_price);
+    let zakat_config = build_zakat_config(&config);
 
     let weight: Decimal = input.fields.get("weight_grams")
         .and_then(|v| v.as_str())
@@ -614,6 +602,32 @@ fn configuration_tests() -> Vec<TestCase> {
                 .field("receivables", "0")
                 .hawl(true),
         ),
+
+        // 7.5 tola gold Nisab (87.48g) used by some national bodies
+        generate_gold_case(
+            "config_004",
+            "Gold 87g below 87.48g jurisdiction nisab - exempt",
+            TestCategory::Configuration,
+            TestConfig::standard("100", "1").with_nisab_grams(Some("87.48"), None),
+            TestInput::new()
+                .field("weight_grams", "87")
+                .field("purity", "24")
+                .field("usage", "investment")
+                .hawl(true),
+        ),
+
+        // 52.5 tola silver Nisab (612.36g)
+        generate_silver_case(
+            "config_005",
+            "Silver 600g below 612.36g jurisdiction nisab - exempt",
+            TestCategory::Configuration,
+            TestConfig::standard("100", "1").with_nisab_grams(None, Some("612.36")),
+            TestInput::new()
+                .field("weight_grams", "600")
+                .field("purity", "1000")
+                .field("usage", "investment")
+                .hawl(true),
+        ),
     ]
 }
 
@@ -633,6 +647,8 @@ fn error_tests() -> Vec<TestCase> {
                 silver_price_per_gram: "1".to_string(),
                 madhab: "hanafi".to_string(),
                 nisab_standard: None,
+                nisab_gold_grams: None,
+                nisab_silver_grams: None,
                 currency_code: "USD".to_string(),
             };
 
@@ -92,6 +92,12 @@ pub struct TestConfig {
     /// Nisab standard override (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nisab_standard: Option<String>,
+    /// Gold Nisab weight override in grams (85 when unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nisab_gold_grams: Option<String>,
+    /// Silver Nisab weight override in grams (595 when unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nisab_silver_grams: Option<String>,
     /// Currency code
     #[serde(default = "default_currency")]
     pub currency_code: String,
@@ -208,6 +214,8 @@ impl TestConfig {
             silver_price_per_gram: silver_price.to_string(),
             madhab: "hanafi".to_string(),
             nisab_standard: None,
+            nisab_gold_grams: None,
+            nisab_silver_grams: None,
             currency_code: "USD".to_string(),
         }
     }
@@ -217,6 +225,18 @@ impl TestConfig {
         self.madhab = madhab.to_string();
         self
     }
+
+    /// Overrides the gold and/or silver Nisab weights in grams.
+    pub fn with_nisab_grams(mut self, gold: Option<&str>, silver: Option<&str>) -> Self {
+        self.nisab_gold_grams = gold.map(str::to_string);
+        self.nisab_silver_grams = silver.map(str::to_string);
+        self
+    }
+
+    /// True when the case uses non-default Nisab weights.
+    pub fn has_nisab_override(&self) -> bool {
+        self.nisab_gold_grams.is_some() || self.nisab_silver_grams.is_some()
+    }
 }
 
 impl TestInput {
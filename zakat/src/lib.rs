@@ -93,6 +93,21 @@ mod extended {
             }
         }
 
+        fn validate_input(&self) -> Result<(), zakat_core::types::ZakatError> {
+            match self {
+                ExtendedPortfolioItem::Business(asset) => CalculateZakat::validate_input(asset),
+                ExtendedPortfolioItem::Income(asset) => CalculateZakat::validate_input(asset),
+                ExtendedPortfolioItem::Livestock(asset) => CalculateZakat::validate_input(asset),
+                ExtendedPortfolioItem::Agriculture(asset) => CalculateZakat::validate_input(asset),
+                ExtendedPortfolioItem::Investment(asset) => CalculateZakat::validate_input(asset),
+                ExtendedPortfolioItem::Mining(asset) => CalculateZakat::validate_input(asset),
+                ExtendedPortfolioItem::PreciousMetals(asset) => CalculateZakat::validate_input(asset),
+                ExtendedPortfolioItem::Fitrah(asset) => CalculateZakat::validate_input(asset),
+                ExtendedPortfolioItem::Custom(asset) => CalculateZakat::validate_input(asset),
+                ExtendedPortfolioItem::Ledger(asset) => CalculateZakat::validate_input(asset),
+            }
+        }
+
         fn get_label(&self) -> Option<String> {
             match self {
                 ExtendedPortfolioItem::Business(asset) => CalculateZakat::get_label(asset),
@@ -122,6 +137,36 @@ mod extended {
                 ExtendedPortfolioItem::Ledger(asset) => CalculateZakat::get_id(asset),
             }
         }
+
+        fn requires_prices(&self) -> bool {
+            match self {
+                ExtendedPortfolioItem::Business(asset) => CalculateZakat::requires_prices(asset),
+                ExtendedPortfolioItem::Income(asset) => CalculateZakat::requires_prices(asset),
+                ExtendedPortfolioItem::Livestock(asset) => CalculateZakat::requires_prices(asset),
+                ExtendedPortfolioItem::Agriculture(asset) => CalculateZakat::requires_prices(asset),
+                ExtendedPortfolioItem::Investment(asset) => CalculateZakat::requires_prices(asset),
+                ExtendedPortfolioItem::Mining(asset) => CalculateZakat::requires_prices(asset),
+                ExtendedPortfolioItem::PreciousMetals(asset) => CalculateZakat::requires_prices(asset),
+                ExtendedPortfolioItem::Fitrah(asset) => CalculateZakat::requires_prices(asset),
+                ExtendedPortfolioItem::Custom(asset) => CalculateZakat::requires_prices(asset),
+                ExtendedPortfolioItem::Ledger(asset) => CalculateZakat::requires_prices(asset),
+            }
+        }
+
+        fn zakat_rate(&self, config: &zakat_core::config::ZakatConfig) -> rust_decimal::Decimal {
+            match self {
+                ExtendedPortfolioItem::Business(asset) => CalculateZakat::zakat_rate(asset, config),
+                ExtendedPortfolioItem::Income(asset) => CalculateZakat::zakat_rate(asset, config),
+                ExtendedPortfolioItem::Livestock(asset) => CalculateZakat::zakat_rate(asset, config),
+                ExtendedPortfolioItem::Agriculture(asset) => CalculateZakat::zakat_rate(asset, config),
+                ExtendedPortfolioItem::Investment(asset) => CalculateZakat::zakat_rate(asset, config),
+                ExtendedPortfolioItem::Mining(asset) => CalculateZakat::zakat_rate(asset, config),
+                ExtendedPortfolioItem::PreciousMetals(asset) => CalculateZakat::zakat_rate(asset, config),
+                ExtendedPortfolioItem::Fitrah(asset) => CalculateZakat::zakat_rate(asset, config),
+                ExtendedPortfolioItem::Custom(asset) => CalculateZakat::zakat_rate(asset, config),
+                ExtendedPortfolioItem::Ledger(asset) => CalculateZakat::zakat_rate(asset, config),
+            }
+        }
     }
 
     // Conversions from individual types to ExtendedPortfolioItem
@@ -159,7 +204,7 @@ pub use zakat_core::types::{ZakatDetails, ZakatError, WealthType};
 pub use zakat_core::portfolio::ZakatPortfolio;
 pub use zakat_core::assets::PortfolioItem;
 pub use zakat_core::madhab::{ZakatStrategy, ZakatRules};
-pub use zakat_core::inputs::{IntoZakatDecimal, InputLocale, LocalizedInput, with_locale};
+pub use zakat_core::inputs::{IntoZakatDecimal, InputLocale, LocalizedInput, with_locale, parse_decimal_with_separators};
 
 // Re-export i18n types
 #[cfg(feature = "i18n")]
@@ -189,6 +234,7 @@ pub mod wasm_helpers {
     use wasm_bindgen::prelude::*;
     use serde::{Deserialize, Serialize};
     use rust_decimal::Decimal;
+    use zakat_core::types::FfiZakatError;
 
 
 
@@ -266,6 +312,8 @@ pub mod wasm_helpers {
         silver_price_per_gram: String,
         madhab: Option<String>,
         currency_code: Option<String>,
+        nisab_gold_grams: Option<String>,
+        nisab_silver_grams: Option<String>,
     }
 
     impl WasmTestConfig {
@@ -291,6 +339,13 @@ pub mod wasm_helpers {
                      _ => {} 
                  }
              }
+
+             if let Some(grams) = self.nisab_gold_grams.as_deref().and_then(|g| Decimal::from_str(g).ok()) {
+                 config = config.with_gold_nisab(grams);
+             }
+             if let Some(grams) = self.nisab_silver_grams.as_deref().and_then(|g| Decimal::from_str(g).ok()) {
+                 config = config.with_silver_nisab(grams);
+             }
              config
         }
     }
@@ -308,7 +363,7 @@ pub mod wasm_helpers {
         let req: WasmRequest = match serde_wasm_bindgen::from_value(val) {
             Ok(r) => r,
             Err(e) => {
-                return Err(JsValue::from_str(&format!("Deserialization error: {}", e)));
+                return Err(FfiZakatError::invalid_field("request", format!("Deserialization error: {}", e)).into());
             }
         };
         let config = req.config.to_core();
@@ -350,7 +405,7 @@ pub mod wasm_helpers {
                 }
             },
             _ => {
-                return Err(JsValue::from_str(&format!("Unknown asset_type: {}", req.asset_type)));
+                return Err(FfiZakatError::invalid_field("asset_type", format!("Unknown asset_type: {}", req.asset_type)).into());
             }
         };
 
@@ -363,9 +418,7 @@ pub mod wasm_helpers {
                  };
                  Ok(serde_wasm_bindgen::to_value(&response)?)
             },
-            Err(e) => {
-                Err(JsValue::from_str(&e.to_string()))
-            }
+            Err(e) => Err(e.into()),
         }
     }
     
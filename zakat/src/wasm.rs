@@ -4,6 +4,16 @@ use gloo_net::http::Request;
 use std::pin::Pin;
 use std::future::Future;
 
+/// Runs when the WASM module is instantiated.
+///
+/// With the `panic-hook` feature, Rust panics are logged to `console.error`
+/// with their message instead of surfacing as an opaque "unreachable" trap.
+#[wasm_bindgen(start)]
+pub fn start() {
+    #[cfg(feature = "panic-hook")]
+    console_error_panic_hook::set_once();
+}
+
 #[wasm_bindgen]
 pub struct WasmResourceLoader {
     base_url: String,
@@ -119,15 +119,13 @@ impl DartZakatConfig {
     /// Get the gold nisab threshold in currency.
     #[frb(sync)]
     pub fn gold_nisab(&self) -> FrbDecimal {
-        let threshold = self.inner.gold_price_per_gram * self.inner.get_nisab_gold_grams();
-        FrbDecimal { value: threshold }
+        FrbDecimal { value: self.inner.nisab_band().0 }
     }
     
     /// Get the silver nisab threshold in currency.
     #[frb(sync)]
     pub fn silver_nisab(&self) -> FrbDecimal {
-        let threshold = self.inner.silver_price_per_gram * self.inner.get_nisab_silver_grams();
-        FrbDecimal { value: threshold }
+        FrbDecimal { value: self.inner.nisab_band().1 }
     }
     
     /// Get the monetary nisab threshold (lower of gold/silver).
@@ -998,6 +998,14 @@ func TestConfig003(t *testing.T) {
 	t.Log("Test case config_003 defined - awaiting UniFFI bindings")
 }
 
+func TestConfig004(t *testing.T) {
+	t.Skip("Nisab weight overrides not yet supported")
+}
+
+func TestConfig005(t *testing.T) {
+	t.Skip("Nisab weight overrides not yet supported")
+}
+
 // TestError001 tests: Zero gold price triggers config error
 func TestError001(t *testing.T) {
 	// Configuration